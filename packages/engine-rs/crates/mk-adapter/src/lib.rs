@@ -15,7 +15,7 @@ use mk_engine::scoring::{calculate_category_base_points, calculate_final_scores}
 use mk_engine::undo::UndoStack;
 use mk_features::EncodedStep;
 use mk_types::enums::Hero;
-use mk_types::legal_action::{LegalAction, LegalActionSet};
+use mk_types::legal_action::{EmptyReason, LegalAction, LegalActionSet};
 use mk_types::scoring::AchievementCategory;
 use mk_types::state::{GameState, PlayerFlags};
 
@@ -330,6 +330,7 @@ impl GameAdapter for MkAdapter {
                         actions: vec![],
                         epoch: action_set.epoch + 1,
                         player_idx: 0,
+                        empty_reason: Some(EmptyReason::GameEnded),
                     },
                     game_ended: true,
                     panicked: true,
@@ -354,6 +355,7 @@ impl GameAdapter for MkAdapter {
                         actions: vec![],
                         epoch: action_set.epoch + 1,
                         player_idx: 0,
+                        empty_reason: Some(EmptyReason::GameEnded),
                     },
                     game_ended: true,
                     panicked: true,
@@ -167,6 +167,32 @@ pub fn discard_enemy_token(piles: &mut EnemyTokenPiles, token_id: &EnemyTokenId,
     get_discard_pile_mut(piles, color).push(token_id.clone());
 }
 
+/// Take a discarded token of the given enemy back out of its color's discard pile.
+pub fn take_discarded_enemy_token(
+    piles: &mut EnemyTokenPiles,
+    enemy_id: &str,
+    color: EnemyColor,
+) -> Option<EnemyTokenId> {
+    let discard = get_discard_pile_mut(piles, color);
+    let pos = discard
+        .iter()
+        .position(|t| enemy_id_from_token(t) == enemy_id)?;
+    Some(discard.remove(pos))
+}
+
+/// Take a token of the given enemy out of its color's draw pile.
+pub fn take_enemy_token_from_draw(
+    piles: &mut EnemyTokenPiles,
+    enemy_id: &str,
+    color: EnemyColor,
+) -> Option<EnemyTokenId> {
+    let draw = get_draw_pile_mut(piles, color);
+    let pos = draw
+        .iter()
+        .position(|t| enemy_id_from_token(t) == enemy_id)?;
+    Some(draw.remove(pos))
+}
+
 // =============================================================================
 // Internal helpers
 // =============================================================================
@@ -287,6 +313,20 @@ mod tests {
         assert_eq!(reshuffled.unwrap(), token);
     }
 
+    #[test]
+    fn take_discarded_enemy_token_removes_matching_token() {
+        let mut rng = RngState::new(42);
+        let mut piles = create_enemy_token_piles(&mut rng);
+        piles.gray_discard = vec![
+            EnemyTokenId::from("swordsmen_1"),
+            EnemyTokenId::from("guardsmen_2"),
+        ];
+        let taken = take_discarded_enemy_token(&mut piles, "guardsmen", EnemyColor::Gray);
+        assert_eq!(taken.unwrap().as_str(), "guardsmen_2");
+        assert_eq!(piles.gray_discard, vec![EnemyTokenId::from("swordsmen_1")]);
+        assert!(take_discarded_enemy_token(&mut piles, "guardsmen", EnemyColor::Gray).is_none());
+    }
+
     #[test]
     fn rampaging_enemy_color_mapping() {
         assert_eq!(rampaging_enemy_color(RampagingEnemyType::OrcMarauder), EnemyColor::Green);
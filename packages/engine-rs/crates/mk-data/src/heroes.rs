@@ -132,6 +132,63 @@ pub fn build_starting_deck(hero: Hero) -> Vec<CardId> {
     deck
 }
 
+// =============================================================================
+// Hero catalog
+// =============================================================================
+
+/// Static description of a playable hero, for frontends and bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeroInfo {
+    pub hero: Hero,
+    /// Lowercase identifier, matching the `Hero` serde name (e.g. `"arythea"`).
+    pub id: &'static str,
+    /// Display name (e.g. `"Arythea"`).
+    pub name: &'static str,
+}
+
+impl HeroInfo {
+    /// The hero's 16-card starting deck (unshuffled).
+    pub fn starting_deck(&self) -> Vec<CardId> {
+        build_starting_deck(self.hero)
+    }
+
+    /// Hero-specific cards that replace standard cards in the starting deck.
+    pub fn unique_cards(&self) -> Vec<&'static str> {
+        hero_replacements(self.hero).iter().map(|r| r.to).collect()
+    }
+}
+
+const ALL_HEROES: [HeroInfo; 7] = [
+    HeroInfo { hero: Hero::Arythea, id: "arythea", name: "Arythea" },
+    HeroInfo { hero: Hero::Tovak, id: "tovak", name: "Tovak" },
+    HeroInfo { hero: Hero::Goldyx, id: "goldyx", name: "Goldyx" },
+    HeroInfo { hero: Hero::Norowas, id: "norowas", name: "Norowas" },
+    HeroInfo { hero: Hero::Wolfhawk, id: "wolfhawk", name: "Wolfhawk" },
+    HeroInfo { hero: Hero::Krang, id: "krang", name: "Krang" },
+    HeroInfo { hero: Hero::Braevalar, id: "braevalar", name: "Braevalar" },
+];
+
+/// All playable heroes, in `Hero` declaration order.
+pub fn all_heroes() -> &'static [HeroInfo] {
+    &ALL_HEROES
+}
+
+/// Look up a hero by its identifier (case-insensitive).
+pub fn hero_by_id(id: &str) -> Option<Hero> {
+    ALL_HEROES
+        .iter()
+        .find(|info| info.id.eq_ignore_ascii_case(id))
+        .map(|info| info.hero)
+}
+
+/// Catalog entry for a hero.
+pub fn hero_info(hero: Hero) -> &'static HeroInfo {
+    ALL_HEROES
+        .iter()
+        .find(|info| info.hero == hero)
+        .expect("every Hero variant is in ALL_HEROES")
+}
+
 /// Get the 3 starting crystal colors for a hero (for dummy player).
 ///
 /// Returns a BTreeMap of crystal counts per basic mana color.
@@ -195,6 +252,30 @@ mod tests {
         assert!(deck.iter().any(|c| c.as_str() == "krang_ruthless_coercion"));
     }
 
+    #[test]
+    fn all_heroes_lists_seven_distinct_heroes() {
+        let heroes = all_heroes();
+        assert_eq!(heroes.len(), 7);
+        let names: std::collections::BTreeSet<_> = heroes.iter().map(|h| h.name).collect();
+        assert_eq!(names.len(), 7, "display names should be distinct");
+        let variants: std::collections::BTreeSet<_> =
+            heroes.iter().map(|h| format!("{:?}", h.hero)).collect();
+        assert_eq!(variants.len(), 7, "each Hero variant appears once");
+    }
+
+    #[test]
+    fn hero_ids_match_serde_names_and_round_trip() {
+        for info in all_heroes() {
+            assert_eq!(serde_json::to_string(&info.hero).unwrap(), format!("\"{}\"", info.id));
+            assert_eq!(hero_by_id(info.id), Some(info.hero));
+            assert_eq!(hero_by_id(info.name), Some(info.hero));
+            assert_eq!(hero_info(info.hero), info);
+            assert_eq!(info.starting_deck().len(), 16);
+            assert!(!info.unique_cards().is_empty());
+        }
+        assert_eq!(hero_by_id("nobody"), None);
+    }
+
     #[test]
     fn all_heroes_have_16_cards() {
         for hero in [
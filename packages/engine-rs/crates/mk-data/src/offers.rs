@@ -137,12 +137,20 @@ pub fn create_spell_deck_and_offer(rng: &mut RngState) -> (Vec<CardId>, Vec<Card
 pub fn take_from_offer(offer: &mut Vec<CardId>, deck: &mut Vec<CardId>, card_id: &str) {
     if let Some(pos) = offer.iter().position(|c| c.as_str() == card_id) {
         offer.remove(pos);
-        // Replenish from deck top (index 0); new card goes at end (oldest), matching TS
-        if !deck.is_empty() {
-            let new_card = deck.remove(0);
-            offer.push(new_card);
-        }
+        replenish_offer(offer, deck);
+    }
+}
+
+/// Refill one offer slot after a card was taken: draw the top of the deck
+/// (index 0) and append it at the end of the offer (oldest position), matching TS.
+/// Returns the drawn card, or None if the deck is empty (the offer stays smaller).
+pub fn replenish_offer(offer: &mut Vec<CardId>, deck: &mut Vec<CardId>) -> Option<CardId> {
+    if deck.is_empty() {
+        return None;
     }
+    let new_card = deck.remove(0);
+    offer.push(new_card.clone());
+    Some(new_card)
 }
 
 /// End-of-round offer refresh: remove the oldest card (last index),
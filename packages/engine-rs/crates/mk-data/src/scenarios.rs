@@ -36,6 +36,7 @@ pub fn first_reconnaissance() -> ScenarioConfig {
         fame_per_level_crossed: 0,
         extra_source_dice: 0,
         extra_unit_offer_slots: 0,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::AllUsed,
         dummy_tactic_order: DummyTacticOrder::AfterHumans,
         end_trigger: ScenarioEndTrigger::CityRevealed,
@@ -81,6 +82,7 @@ pub fn first_reconnaissance_2p() -> ScenarioConfig {
         fame_per_level_crossed: 0,
         extra_source_dice: 0,
         extra_unit_offer_slots: 0,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::RemoveTwo,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityRevealed,
@@ -118,6 +120,7 @@ pub fn first_reconnaissance_3p() -> ScenarioConfig {
         fame_per_level_crossed: 0,
         extra_source_dice: 0,
         extra_unit_offer_slots: 0,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::RemoveOne,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityRevealed,
@@ -155,6 +158,7 @@ pub fn first_reconnaissance_4p() -> ScenarioConfig {
         fame_per_level_crossed: 0,
         extra_source_dice: 0,
         extra_unit_offer_slots: 0,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::None,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityRevealed,
@@ -212,6 +216,7 @@ pub fn full_conquest_2p() -> ScenarioConfig {
         fame_per_level_crossed: 0,
         extra_source_dice: 0,
         extra_unit_offer_slots: 0,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::RemoveTwo,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityConquered,
@@ -249,6 +254,7 @@ pub fn full_conquest_3p() -> ScenarioConfig {
         fame_per_level_crossed: 0,
         extra_source_dice: 0,
         extra_unit_offer_slots: 0,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::RemoveOne,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityConquered,
@@ -286,6 +292,7 @@ pub fn full_conquest_4p() -> ScenarioConfig {
         fame_per_level_crossed: 0,
         extra_source_dice: 0,
         extra_unit_offer_slots: 0,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::None,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityConquered,
@@ -343,6 +350,7 @@ pub fn blitz_conquest_2p() -> ScenarioConfig {
         fame_per_level_crossed: 1,
         extra_source_dice: 1,
         extra_unit_offer_slots: 1,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::RemoveTwo,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityConquered,
@@ -380,6 +388,7 @@ pub fn blitz_conquest_3p() -> ScenarioConfig {
         fame_per_level_crossed: 1,
         extra_source_dice: 1,
         extra_unit_offer_slots: 1,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::RemoveOne,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityConquered,
@@ -418,6 +427,7 @@ pub fn blitz_conquest_4p() -> ScenarioConfig {
         fame_per_level_crossed: 1,
         extra_source_dice: 1,
         extra_unit_offer_slots: 1,
+        variants: Vec::new(),
         tactic_removal_mode: TacticRemovalMode::None,
         dummy_tactic_order: DummyTacticOrder::None,
         end_trigger: ScenarioEndTrigger::CityConquered,
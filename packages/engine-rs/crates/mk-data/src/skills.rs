@@ -1305,7 +1305,7 @@ pub fn get_skill_description(id: &str) -> Option<String> {
         "goldyx_universal_power" => Some("Consume mana for color-matched bonuses"),
         "goldyx_source_opening" => Some("Reroll and swap Source dice"),
         "norowas_bonds_of_loyalty" => Some("Extra command token (passive)"),
-        "norowas_prayer_of_weather" => Some("Gain mana matching time of day"),
+        "norowas_prayer_of_weather" => Some("Terrain costs -2 this turn (min 1)"),
         "wolfhawk_know_your_prey" => Some("Negate an enemy ability"),
         "wolfhawk_dueling" => Some("Auto-block one enemy"),
         "wolfhawk_wolfs_howl" => Some("Recruit at -3 influence discount"),
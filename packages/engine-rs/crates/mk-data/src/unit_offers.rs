@@ -77,6 +77,18 @@ pub fn take_from_unit_offer(offer: &mut Vec<UnitId>, unit_id: &str) -> bool {
     }
 }
 
+/// Refill one unit offer slot after a unit was taken as a site reward: draw the
+/// top of `deck` (index 0) and append it to the offer.
+/// Returns the drawn unit, or None if the deck is empty (the offer stays smaller).
+pub fn replenish_unit_offer(offer: &mut Vec<UnitId>, deck: &mut Vec<UnitId>) -> Option<UnitId> {
+    if deck.is_empty() {
+        return None;
+    }
+    let unit = deck.remove(0);
+    offer.push(unit.clone());
+    Some(unit)
+}
+
 /// End-of-round offer refresh with separate regular/elite decks.
 ///
 /// Returns old offer units to correct deck (elite→elite_deck, regular→regular_deck).
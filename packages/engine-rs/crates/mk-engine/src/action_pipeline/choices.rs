@@ -116,6 +116,22 @@ pub(super) fn apply_resolve_training(
                 ));
             }
 
+            // Only action cards can be thrown away (wounds and spells have no AA color match)
+            let is_action = mk_data::cards::get_card(player.hand[selection_index].as_str())
+                .is_some_and(|def| {
+                    use mk_types::enums::DeedCardType;
+                    matches!(
+                        def.card_type,
+                        DeedCardType::BasicAction | DeedCardType::AdvancedAction
+                    )
+                });
+            if !is_action {
+                player.pending.active = Some(ActivePending::Training(pending));
+                return Err(ApplyError::InternalError(
+                    "ResolveTraining: selected card is not an action card".to_string(),
+                ));
+            }
+
             let card_id = player.hand.remove(selection_index);
             let card_color = mk_data::cards::get_card_color(card_id.as_str());
             player.removed_cards.push(card_id);
@@ -146,7 +162,7 @@ pub(super) fn apply_resolve_training(
                     .position(|id| *id == aa_id)
                     .unwrap();
                 state.offers.advanced_actions.remove(offer_idx);
-                effect_queue::replenish_aa_offer(state);
+                crate::offers::replenish_aa_offer(state);
                 let player = &mut state.players[player_idx];
                 match pending.mode {
                     mk_types::pending::EffectMode::Powered => player.hand.push(aa_id),
@@ -184,7 +200,7 @@ pub(super) fn apply_resolve_training(
             let aa_id = pending.available_offer_cards[selection_index].clone();
             if let Some(offer_idx) = state.offers.advanced_actions.iter().position(|id| *id == aa_id) {
                 state.offers.advanced_actions.remove(offer_idx);
-                effect_queue::replenish_aa_offer(state);
+                crate::offers::replenish_aa_offer(state);
             }
 
             let player = &mut state.players[player_idx];
@@ -305,7 +321,7 @@ pub(super) fn resolve_book_of_wisdom_selection(
             // Remove AA from offer, add to player's discard
             if let Some(idx) = state.offers.advanced_actions.iter().position(|id| id == selected_id) {
                 state.offers.advanced_actions.remove(idx);
-                effect_queue::replenish_aa_offer(state);
+                crate::offers::replenish_aa_offer(state);
             }
             state.players[player_idx].discard.push(selected_id.clone());
         }
@@ -313,7 +329,7 @@ pub(super) fn resolve_book_of_wisdom_selection(
             // Remove spell from offer, add to top of player's deck
             if let Some(idx) = state.offers.spells.iter().position(|id| id == selected_id) {
                 state.offers.spells.remove(idx);
-                effect_queue::replenish_spell_offer(state);
+                crate::offers::replenish_spell_offer(state);
             }
             state.players[player_idx].deck.insert(0, selected_id.clone());
             // Powered mode also grants a crystal of the discarded card's color
@@ -550,6 +566,9 @@ pub(super) fn apply_resolve_maximal_effect(
                 events: Vec::new(),
             })
         }
+        effect_queue::DrainResult::ResolutionLimitExceeded => Err(ApplyError::InternalError(
+            effect_queue::DrainResult::resolution_limit_message(),
+        )),
     }
 }
 
@@ -584,8 +603,26 @@ pub(super) fn apply_resolve_meditation(
                 ));
             }
             let card_id = player.discard[selection_index].clone();
+            let invalid = if card_id.as_str() == effect_queue::WOUND_CARD_ID {
+                Some("wounds cannot be selected")
+            } else if pending.selected_discard_indices.contains(&selection_index) {
+                Some("card already selected")
+            } else if pending.selected_card_ids.len() >= 3 {
+                Some("selection already full")
+            } else {
+                None
+            };
+            if let Some(reason) = invalid {
+                state.players[player_idx].pending.active =
+                    Some(ActivePending::Meditation(pending));
+                return Err(ApplyError::InternalError(format!(
+                    "ResolveMeditation: {}",
+                    reason
+                )));
+            }
             let mut new_pending = pending;
             new_pending.selected_card_ids.push(card_id);
+            new_pending.selected_discard_indices.push(selection_index);
 
             // If we've selected 3, auto-transition to PlaceCards
             if new_pending.selected_card_ids.len() >= 3 {
@@ -597,20 +634,28 @@ pub(super) fn apply_resolve_meditation(
         MeditationPhase::PlaceCards => {
             // Place the first selected card on top or bottom of deck
             let on_top = place_on_top.unwrap_or(true);
+            let discard_pos = pending.selected_card_ids.first().and_then(|card_id| {
+                state.players[player_idx].discard.iter().position(|c| c == card_id)
+            });
+            let Some(discard_pos) = discard_pos else {
+                state.players[player_idx].pending.active =
+                    Some(ActivePending::Meditation(pending));
+                return Err(ApplyError::InternalError(
+                    "ResolveMeditation: selected card no longer in discard".to_string(),
+                ));
+            };
             let mut new_pending = pending;
-            let card_id = new_pending.selected_card_ids.remove(0);
+            new_pending.selected_card_ids.remove(0);
+            // Discard indices shift as cards leave; they only matter while selecting.
+            new_pending.selected_discard_indices.clear();
 
-            // Remove from discard
+            // Move from discard to deck (index 0 is the top, drawn first)
             let player = &mut state.players[player_idx];
-            if let Some(pos) = player.discard.iter().position(|c| c == &card_id) {
-                player.discard.remove(pos);
-            }
-
-            // Place on deck
+            let card_id = player.discard.remove(discard_pos);
             if on_top {
-                player.deck.push(card_id);
-            } else {
                 player.deck.insert(0, card_id);
+            } else {
+                player.deck.push(card_id);
             }
 
             // If more cards to place, keep pending
@@ -876,15 +921,32 @@ pub(super) fn apply_resolve_crystal_joy_reclaim(
     player_idx: usize,
     discard_index: Option<usize>,
 ) -> Result<ApplyResult, ApplyError> {
-    // Clear pending
-    state.players[player_idx].pending.active = None;
+    let pending = match state.players[player_idx].pending.active.take() {
+        Some(ActivePending::CrystalJoyReclaim(p)) => p,
+        other => {
+            state.players[player_idx].pending.active = other;
+            return Err(ApplyError::InternalError(
+                "ResolveCrystalJoyReclaim: no CrystalJoyReclaim pending".into(),
+            ));
+        }
+    };
 
     if let Some(idx) = discard_index {
-        // Move the selected card from discard to hand
-        if idx < state.players[player_idx].discard.len() {
-            let card = state.players[player_idx].discard.remove(idx);
-            state.players[player_idx].hand.push(card);
+        let eligible = state.players[player_idx]
+            .discard
+            .get(idx)
+            .is_some_and(|c| end_turn::crystal_joy_can_reclaim(pending.version, c));
+        if !eligible {
+            state.players[player_idx].pending.active =
+                Some(ActivePending::CrystalJoyReclaim(pending));
+            return Err(ApplyError::InternalError(format!(
+                "ResolveCrystalJoyReclaim: discard index {} is not reclaimable",
+                idx
+            )));
         }
+        // Move the selected card from discard to hand
+        let card = state.players[player_idx].discard.remove(idx);
+        state.players[player_idx].hand.push(card);
     }
     // Skip: no card moved
 
@@ -909,6 +971,21 @@ pub(super) fn apply_resolve_steady_tempo(
         }
     };
 
+    // Basic placement goes under the deck, so there must be a deck to go under
+    if place
+        && version == mk_types::pending::EffectMode::Basic
+        && state.players[player_idx].deck.is_empty()
+    {
+        state.players[player_idx].pending.active = Some(
+            mk_types::pending::ActivePending::SteadyTempoDeckPlacement(
+                mk_types::pending::PendingSteadyTempoDeckPlacement { version },
+            ),
+        );
+        return Err(ApplyError::InternalError(
+            "ResolveSteadyTempo: basic placement needs a non-empty deck".into(),
+        ));
+    }
+
     if place {
         // Remove steady_tempo from play_area and place on deck
         if let Some(idx) = state.players[player_idx]
@@ -941,33 +1018,34 @@ pub(super) fn apply_resolve_banner_protection(
     player_idx: usize,
     remove_all: bool,
 ) -> Result<ApplyResult, ApplyError> {
-    // Clear pending
-    state.players[player_idx].pending.active = None;
+    match state.players[player_idx].pending.active.take() {
+        Some(ActivePending::BannerProtectionChoice) => {}
+        other => {
+            state.players[player_idx].pending.active = other;
+            return Err(ApplyError::InternalError(
+                "ResolveBannerProtection: no BannerProtectionChoice pending".into(),
+            ));
+        }
+    }
 
     if remove_all {
-        let wounds = state.players[player_idx].wounds_received_this_turn;
-
-        // Remove wounds from hand
+        // Only this turn's wounds; anything already healed is not thrown away twice
+        let wounds = end_turn::banner_protection_wounds(&state.players[player_idx]);
+        let player = &mut state.players[player_idx];
+        let mut thrown = 0;
         for _ in 0..wounds.hand {
-            if let Some(idx) = state.players[player_idx]
-                .hand
-                .iter()
-                .position(|c| c.as_str() == "wound")
-            {
-                state.players[player_idx].hand.remove(idx);
+            if let Some(idx) = player.hand.iter().position(|c| c.as_str() == effect_queue::WOUND_CARD_ID) {
+                player.hand.remove(idx);
+                thrown += 1;
             }
         }
-
-        // Remove wounds from discard
         for _ in 0..wounds.discard {
-            if let Some(idx) = state.players[player_idx]
-                .discard
-                .iter()
-                .position(|c| c.as_str() == "wound")
-            {
-                state.players[player_idx].discard.remove(idx);
+            if let Some(idx) = player.discard.iter().position(|c| c.as_str() == effect_queue::WOUND_CARD_ID) {
+                player.discard.remove(idx);
+                thrown += 1;
             }
         }
+        crate::effect_queue::return_wounds_to_pile(state, thrown);
 
         // Banner is destroyed after use — remove from play area
         // (Banner of Protection artifact card goes to removed_cards)
@@ -36,8 +36,9 @@ pub(super) fn apply_declare_block(
             .find(|(_, e)| e.instance_id == *enemy_instance_id)
             .ok_or_else(|| ApplyError::InternalError("DeclareBlock: enemy not found".into()))?;
 
-        let def = get_enemy(enemy.enemy_id.as_str())
-            .ok_or_else(|| ApplyError::InternalError("DeclareBlock: unknown enemy".into()))?;
+        let def = get_enemy(enemy.enemy_id.as_str()).ok_or_else(|| {
+            ApplyError::InternalError(format!("DeclareBlock: unknown enemy id '{}'", enemy.enemy_id.as_str()))
+        })?;
 
         let city_color = combat_resolution::effective_city_color_for_enemy(combat, enemy);
         let player = &state.players[player_idx];
@@ -52,6 +53,12 @@ pub(super) fn apply_declare_block(
     };
 
     // Phase 2: Mutate
+    state.combat.as_mut().unwrap().combat_log.push(CombatLogEntry::BlockDeclared {
+        enemy_instance_id: enemy_instance_id.clone(),
+        attack_index: attack_index as u32,
+        success: block_success,
+    });
+
     if block_success {
         let combat = state.combat.as_mut().unwrap();
         let def = get_enemy(combat.enemies[enemy_idx].enemy_id.as_str()).unwrap();
@@ -155,9 +162,12 @@ pub(super) fn apply_burning_shield_on_block(
 
             if !has_fire_resist && !has_arcane_immune {
                 enemy.is_defeated = true;
+                let idx = combat.enemies.iter()
+                    .position(|e| e.instance_id == *blocked_enemy_id)
+                    .unwrap();
+                log_enemy_defeated(combat, idx);
                 if !is_summoned {
-                    state.players[player_idx].fame += def.fame;
-                    state.combat.as_mut().unwrap().fame_gained += def.fame;
+                    credit_enemy_fame(state, player_idx, idx, def.fame);
                 }
             }
         }
@@ -342,7 +352,10 @@ pub(super) fn apply_declare_attack_inner(
                 })?;
 
             let def = get_enemy(enemy.enemy_id.as_str()).ok_or_else(|| {
-                ApplyError::InternalError("DeclareAttack: unknown enemy".into())
+                ApplyError::InternalError(format!(
+                    "DeclareAttack: unknown enemy id '{}'",
+                    enemy.enemy_id.as_str()
+                ))
             })?;
 
             target_indices.push(idx);
@@ -538,13 +551,19 @@ pub(super) fn apply_declare_attack_inner(
         }
     }
 
+    state.combat.as_mut().unwrap().combat_log.push(CombatLogEntry::AttackDeclared {
+        target_instance_ids: target_instance_ids.to_vec(),
+        attack_type,
+        success: result.success,
+    });
+
     // Phase 3: Apply mutations on success
     if result.success {
         let combat = state.combat.as_mut().unwrap();
         for &idx in &target_indices {
             combat.enemies[idx].is_defeated = true;
+            log_enemy_defeated(combat, idx);
         }
-        combat.fame_gained += result.fame_gained;
 
         // Collect summoned status for FamePerEnemyDefeated check
         let defeated_summoned_flags: Vec<bool> = target_indices.iter().map(|&idx| {
@@ -552,41 +571,49 @@ pub(super) fn apply_declare_attack_inner(
         }).collect();
 
         let player = &mut state.players[player_idx];
-        player.fame += result.fame_gained;
         player.enemies_defeated_this_turn += target_count as u32;
         player.reputation = (player.reputation as i32 + result.reputation_delta)
             .clamp(-7, 7) as i8;
 
-        // Hook: FamePerEnemyDefeated bonus (Banner of Glory, Sword of Justice)
-        let bonus_fame = count_fame_per_enemy_bonus(
-            &state.active_modifiers,
-            &state.players[player_idx].id,
-            &defeated_summoned_flags,
-        );
-        if bonus_fame > 0 {
-            state.players[player_idx].fame += bonus_fame;
-            state.combat.as_mut().unwrap().fame_gained += bonus_fame;
+        // Base fame plus the FamePerEnemyDefeated bonus (Banner of Glory,
+        // Sword of Justice), credited per enemy so spoils can be split later.
+        for (&idx, &is_summoned) in target_indices.iter().zip(&defeated_summoned_flags) {
+            let enemy_id = state.combat.as_ref().unwrap().enemies[idx].enemy_id.clone();
+            let base_fame = if is_summoned {
+                0
+            } else {
+                get_enemy(enemy_id.as_str()).map(|d| d.fame).unwrap_or(0)
+            };
+            let bonus_fame = count_fame_per_enemy_bonus(
+                &state.active_modifiers,
+                &state.players[player_idx].id,
+                &[is_summoned],
+            );
+            credit_enemy_fame(state, player_idx, idx, base_fame + bonus_fame);
         }
 
-        // Hook: ScoutFameBonus — +fame if a peeked enemy was defeated
-        let scout_bonus = count_scout_fame_bonus(
-            &state.active_modifiers,
-            &state.players[player_idx].id,
-            &target_indices.iter().map(|&idx| {
-                state.combat.as_ref().unwrap().enemies[idx].enemy_id.as_str().to_string()
-            }).collect::<Vec<_>>(),
-        );
-        if scout_bonus > 0 {
-            state.players[player_idx].fame += scout_bonus;
-            state.combat.as_mut().unwrap().fame_gained += scout_bonus;
+        // Hook: ScoutFameBonus — +fame if a peeked enemy was defeated,
+        // credited to the first peeked enemy among the targets.
+        let player_id = state.players[player_idx].id.clone();
+        let scout_target = target_indices.iter().find_map(|&idx| {
+            let enemy_id = state.combat.as_ref().unwrap().enemies[idx].enemy_id.as_str().to_string();
+            let bonus = count_scout_fame_bonus(&state.active_modifiers, &player_id, &[enemy_id]);
+            (bonus > 0).then_some((idx, bonus))
+        });
+        if let Some((idx, scout_bonus)) = scout_target {
+            credit_enemy_fame(state, player_idx, idx, scout_bonus);
         }
 
         // Hook: SoulHarvesterCrystalTracking — award crystals per defeated enemy
         resolve_soul_harvester_crystals(state, player_idx, &defeated_summoned_flags);
 
         // Hook: Track ranged/siege phase defeats for BowPhaseFameTracking
-        if state.combat.as_ref().unwrap().phase == CombatPhase::RangedSiege {
-            state.combat.as_mut().unwrap().ranged_siege_defeats += target_count as u32;
+        let combat = state.combat.as_mut().unwrap();
+        if combat.phase == CombatPhase::RangedSiege {
+            for &idx in &target_indices {
+                let instance_id = combat.enemies[idx].instance_id.clone();
+                combat.ranged_siege_defeated.push(instance_id);
+            }
         }
     }
 
@@ -821,6 +848,11 @@ pub(super) fn resolve_summons(state: &mut GameState) -> Result<(), ApplyError> {
     let combat = state.combat.as_mut().unwrap();
 
     for summoned in summoned_enemies {
+        combat.combat_log.push(CombatLogEntry::EnemySummoned {
+            summoner_instance_id: CombatInstanceId::from(summoned.summoner_instance_id.as_str()),
+            enemy_instance_id: CombatInstanceId::from(summoned.instance_id.as_str()),
+            enemy_id: EnemyId::from(summoned.enemy_id.as_str()),
+        });
         combat.enemies.push(CombatEnemy {
             instance_id: CombatInstanceId::from(summoned.instance_id),
             enemy_id: EnemyId::from(summoned.enemy_id),
@@ -872,24 +904,23 @@ pub(super) fn apply_end_combat_phase(
 
             // Hook: BowPhaseFameTracking — award fame for enemies defeated in ranged/siege phase
             {
-                let ranged_defeats = state.combat.as_ref().unwrap().ranged_siege_defeats;
-                if ranged_defeats > 0 {
+                let ranged_defeated = state.combat.as_ref().unwrap().ranged_siege_defeated.clone();
+                if !ranged_defeated.is_empty() {
                     let player_id = state.players[player_idx].id.clone();
-                    let bonus_fame: u32 = state.active_modifiers.iter()
-                        .filter(|m| {
-                            matches!(&m.effect, mk_types::modifier::ModifierEffect::BowPhaseFameTracking { .. })
-                                && m.created_by_player_id == player_id
-                        })
+                    let fame_per_enemy: u32 = state.active_modifiers.iter()
+                        .filter(|m| m.created_by_player_id == player_id)
                         .map(|m| match &m.effect {
                             mk_types::modifier::ModifierEffect::BowPhaseFameTracking { fame_per_enemy } => {
-                                fame_per_enemy * ranged_defeats
+                                *fame_per_enemy
                             }
                             _ => 0,
                         })
                         .sum();
-                    if bonus_fame > 0 {
-                        state.players[player_idx].fame += bonus_fame;
-                        state.combat.as_mut().unwrap().fame_gained += bonus_fame;
+                    for instance_id in &ranged_defeated {
+                        let combat = state.combat.as_ref().unwrap();
+                        if let Some(idx) = combat.enemies.iter().position(|e| e.instance_id == *instance_id) {
+                            credit_enemy_fame(state, player_idx, idx, fame_per_enemy);
+                        }
                     }
                 }
             }
@@ -929,16 +960,17 @@ pub(super) fn apply_end_combat_phase(
 
                 // Award fame and mark defeated
                 for idx in &defeat_indices {
-                    let enemy = &state.combat.as_ref().unwrap().enemies[*idx];
+                    let combat = state.combat.as_mut().unwrap();
+                    combat.enemies[*idx].is_defeated = true;
+                    log_enemy_defeated(combat, *idx);
+                    let enemy = &combat.enemies[*idx];
                     let enemy_id_str = enemy.enemy_id.as_str().to_string();
                     let is_summoned = enemy.summoned_by_instance_id.is_some();
                     if let Some(def) = get_enemy(&enemy_id_str) {
                         if !is_summoned {
-                            state.players[player_idx].fame += def.fame;
-                            state.combat.as_mut().unwrap().fame_gained += def.fame;
+                            credit_enemy_fame(state, player_idx, *idx, def.fame);
                         }
                     }
-                    state.combat.as_mut().unwrap().enemies[*idx].is_defeated = true;
                 }
             }
 
@@ -1050,6 +1082,7 @@ pub(super) fn apply_auto_damage(
     struct DamageInfo {
         enemy_idx: usize,
         attack_index: usize,
+        damage: u32,
         wounds: u32,
         is_poison: bool,
         is_paralyze: bool,
@@ -1137,6 +1170,7 @@ pub(super) fn apply_auto_damage(
                 damage_entries.push(DamageInfo {
                     enemy_idx,
                     attack_index,
+                    damage: reduced_damage,
                     wounds,
                     is_poison,
                     is_paralyze,
@@ -1206,16 +1240,12 @@ pub(super) fn apply_auto_damage(
     }
 
     // Add wound cards to hand
-    let player = &mut state.players[player_idx];
-    for _ in 0..total_wounds_to_hand {
-        player.hand.push(CardId::from("wound"));
-    }
+    crate::effect_queue::add_wounds_to_hand(state, player_idx, total_wounds_to_hand);
 
     // Poison: add extra wounds to discard
-    for _ in 0..total_wounds_to_discard {
-        player.discard.push(CardId::from("wound"));
-    }
+    crate::effect_queue::add_wounds_to_discard(state, player_idx, total_wounds_to_discard);
 
+    let player = &mut state.players[player_idx];
     player.wounds_received_this_turn.hand += total_wounds_to_hand;
     player.wounds_received_this_turn.discard += total_wounds_to_discard;
 
@@ -1251,6 +1281,13 @@ pub(super) fn apply_auto_damage(
                 enemy.attacks_damage_assigned[entry.attack_index] = true;
             }
             enemy.damage_assigned = true;
+            let enemy_instance_id = enemy.instance_id.clone();
+            combat.combat_log.push(CombatLogEntry::DamageAssigned {
+                enemy_instance_id,
+                attack_index: entry.attack_index as u32,
+                damage: entry.damage,
+                recipient: DamageRecipient::Hero { wounds: entry.wounds },
+            });
         }
     }
 
@@ -1367,16 +1404,12 @@ pub(super) fn apply_assign_damage_to_hero(
     let is_paralyze = combat_resolution::has_ability(def, EnemyAbilityType::Paralyze);
 
     // Apply wounds to hero
-    let player = &mut state.players[player_idx];
-    for _ in 0..wounds {
-        player.hand.push(CardId::from("wound"));
-    }
+    crate::effect_queue::add_wounds_to_hand(state, player_idx, wounds);
     if is_poison {
-        for _ in 0..wounds {
-            player.discard.push(CardId::from("wound"));
-        }
+        crate::effect_queue::add_wounds_to_discard(state, player_idx, wounds);
     }
 
+    let player = &mut state.players[player_idx];
     player.wounds_received_this_turn.hand += wounds;
     if is_poison {
         player.wounds_received_this_turn.discard += wounds;
@@ -1409,6 +1442,13 @@ pub(super) fn apply_assign_damage_to_hero(
     // Mark attack as assigned
     combat.enemies[enemy_index].attacks_damage_assigned[attack_index] = true;
     combat.enemies[enemy_index].damage_assigned = true;
+    let enemy_instance_id = combat.enemies[enemy_index].instance_id.clone();
+    combat.combat_log.push(CombatLogEntry::DamageAssigned {
+        enemy_instance_id,
+        attack_index: attack_index as u32,
+        damage: reduced_damage,
+        recipient: DamageRecipient::Hero { wounds },
+    });
 
     // Track paralyze for end of assignment
     if is_paralyze && wounds > 0 {
@@ -1491,10 +1531,11 @@ pub(super) fn apply_assign_damage_to_unit(
     })?;
 
     // Collect unit's resistances: base from definition + granted by modifiers
+    let player_id = &state.players[player_idx].id;
     let unit_resistances: Vec<ResistanceElement> = {
         let mut resistances = Vec::new();
-        // Check for GrantResistances modifiers (e.g., from Altem Guardians)
-        for m in &state.active_modifiers {
+        // Check for the owner's GrantResistances modifiers (e.g., from Altem Guardians)
+        for m in state.active_modifiers.iter().filter(|m| m.created_by_player_id == *player_id) {
             if let mk_types::modifier::ModifierEffect::GrantResistances { resistances: granted } = &m.effect {
                 let scope_matches = matches!(&m.scope, mk_types::modifier::ModifierScope::AllUnits)
                     || matches!(&m.scope, mk_types::modifier::ModifierScope::SelfScope);
@@ -1522,6 +1563,7 @@ pub(super) fn apply_assign_damage_to_unit(
     );
 
     // Apply result to unit (with Banner of Fortitude intercept)
+    let mut unit_wounded = false;
     if damage_result.unit_destroyed {
         // Check fortitude before destruction — if wound would be negated, unit survives as wounded
         // Fortitude only prevents the wound step, not destruction from double-wound
@@ -1532,6 +1574,7 @@ pub(super) fn apply_assign_damage_to_unit(
             let negated = sites::try_negate_wound_with_fortitude(state, player_idx, unit_instance_id);
             if !negated {
                 state.players[player_idx].units[unit_idx].wounded = true;
+                unit_wounded = true;
             }
         }
         if damage_result.resistance_used {
@@ -1543,6 +1586,17 @@ pub(super) fn apply_assign_damage_to_unit(
     let combat = state.combat.as_mut().unwrap();
     combat.enemies[enemy_index].attacks_damage_assigned[attack_index] = true;
     combat.enemies[enemy_index].damage_assigned = true;
+    let enemy_instance_id = combat.enemies[enemy_index].instance_id.clone();
+    combat.combat_log.push(CombatLogEntry::DamageAssigned {
+        enemy_instance_id,
+        attack_index: attack_index as u32,
+        damage: effective_damage,
+        recipient: DamageRecipient::Unit {
+            unit_instance_id: unit_instance_id.clone(),
+            wounded: unit_wounded,
+            destroyed: damage_result.unit_destroyed,
+        },
+    });
 
     Ok(ApplyResult {
         needs_reenumeration: true,
@@ -1551,6 +1605,47 @@ pub(super) fn apply_assign_damage_to_unit(
     })
 }
 
+/// Append an `EnemyDefeated` log entry for the enemy at `idx`.
+///
+/// The entry starts at 0 fame; `record_enemy_fame` adds what is actually
+/// credited for the enemy, so log the defeat before crediting its fame.
+pub(super) fn log_enemy_defeated(combat: &mut CombatState, idx: usize) {
+    let enemy = &combat.enemies[idx];
+    let entry = CombatLogEntry::EnemyDefeated {
+        enemy_instance_id: enemy.instance_id.clone(),
+        enemy_id: enemy.enemy_id.clone(),
+        fame: 0,
+    };
+    combat.combat_log.push(entry);
+}
+
+/// Credit fame for defeating the enemy at `idx` to the player and the combat
+/// totals, recording it per enemy for cooperative assault spoils and on the
+/// enemy's `EnemyDefeated` log entry.
+fn credit_enemy_fame(state: &mut GameState, player_idx: usize, idx: usize, fame: u32) {
+    let combat = state.combat.as_mut().unwrap();
+    record_enemy_fame(&mut state.players[player_idx], combat, idx, fame);
+}
+
+/// Field-level form of `credit_enemy_fame`, for callers holding other borrows of the state.
+pub(super) fn record_enemy_fame(player: &mut PlayerState, combat: &mut CombatState, idx: usize, fame: u32) {
+    if fame == 0 {
+        return;
+    }
+    player.fame += fame;
+    combat.fame_gained += fame;
+    let instance_id = combat.enemies[idx].instance_id.clone();
+    *combat.fame_by_enemy.entry(instance_id.as_str().to_string()).or_insert(0) += fame;
+    let logged = combat.combat_log.iter_mut().rev().find_map(|entry| match entry {
+        CombatLogEntry::EnemyDefeated { enemy_instance_id, fame, .. } if *enemy_instance_id == instance_id => {
+            Some(fame)
+        }
+        _ => None,
+    });
+    if let Some(logged) = logged {
+        *logged += fame;
+    }
+}
 
 // =============================================================================
 // Combat modifier hook helpers
@@ -28,21 +28,22 @@ pub(super) fn end_combat(state: &mut GameState, player_idx: usize) {
                 .collect();
 
             if let Some(hex) = state.map.hexes.get_mut(&hex_key) {
-                // Remove enemies whose base ID matches a defeated enemy
-                // and discard their tokens to the appropriate color pile
+                // Remove one hex token per defeated enemy with a matching base ID
+                // (two identical garrison tokens with one survivor keep one token)
+                // and discard them to the appropriate color pile
+                let mut unmatched = defeated_enemy_ids;
                 let mut to_remove: Vec<(EnemyTokenId, EnemyColor)> = Vec::new();
 
-                for enemy in hex.enemies.iter() {
-                    let base_id = enemy_id_from_token(&enemy.token_id);
-                    if defeated_enemy_ids.contains(&base_id) {
-                        to_remove.push((enemy.token_id.clone(), enemy.color));
-                    }
-                }
-
-                // Remove from hex
                 hex.enemies.retain(|e| {
                     let base_id = enemy_id_from_token(&e.token_id);
-                    !defeated_enemy_ids.contains(&base_id)
+                    match unmatched.iter().position(|id| *id == base_id) {
+                        Some(pos) => {
+                            unmatched.swap_remove(pos);
+                            to_remove.push((e.token_id.clone(), e.color));
+                            false
+                        }
+                        None => true,
+                    }
                 });
 
                 // Discard tokens to color piles
@@ -95,6 +96,9 @@ pub(super) fn end_combat(state: &mut GameState, player_idx: usize) {
         }
     }
 
+    // Cooperative assault: credit fame for each participant's assigned enemies.
+    let coop_defeated = crate::cooperative_assault::settle_spoils(state, player_idx);
+
     // Conquest marking: if all required-for-conquest enemies defeated and hex has an unconquered site.
     // Rampaging enemies provoked during an assault have is_required_for_conquest=false,
     // so they don't need to be defeated for the site to be conquered.
@@ -122,17 +126,34 @@ pub(super) fn end_combat(state: &mut GameState, player_idx: usize) {
             if let Some(hex_coord) = combat.combat_hex_coord {
                 if let Some(hex) = state.map.hexes.get_mut(&hex_coord.key()) {
                     let is_city = hex.site.as_ref().map(|s| s.site_type == SiteType::City).unwrap_or(false);
+                    // Cooperative city assault: the participant with most defeated enemies leads.
+                    let owner_idx = coop_defeated
+                        .as_ref()
+                        .map(|counts| crate::cooperative_assault::city_leader(counts, player_idx))
+                        .unwrap_or(player_idx);
                     if let Some(ref mut site) = hex.site {
                         if !site.is_conquered {
                             site.is_conquered = true;
-                            site.owner = Some(state.players[player_idx].id.clone());
+                            site.owner = Some(state.players[owner_idx].id.clone());
                             conquered_site_type = Some(site.site_type);
                         }
                     }
-                    // Place shield tokens: cities get 1 per defeated enemy, other sites get 1
-                    let token_count = if is_city { defeated_count } else { 1 };
-                    for _ in 0..token_count {
-                        hex.shield_tokens.push(state.players[player_idx].id.clone());
+                    // Place shield tokens: cities get 1 per defeated enemy, other sites get 1.
+                    // In a cooperative assault each participant places one per enemy they defeated.
+                    match coop_defeated {
+                        Some(ref counts) if is_city => {
+                            for (&p_idx, &count) in counts {
+                                for _ in 0..count {
+                                    hex.shield_tokens.push(state.players[p_idx].id.clone());
+                                }
+                            }
+                        }
+                        _ => {
+                            let token_count = if is_city { defeated_count } else { 1 };
+                            for _ in 0..token_count {
+                                hex.shield_tokens.push(state.players[player_idx].id.clone());
+                            }
+                        }
                     }
                     // Clear remaining enemies from hex (all defeated)
                     hex.enemies.clear();
@@ -278,6 +299,20 @@ pub(super) fn end_combat(state: &mut GameState, player_idx: usize) {
         None
     };
 
+    // Puppet Master: defeated (non-summoned) enemies Krang could keep a token of.
+    let puppet_master_candidates: Vec<mk_types::ids::EnemyId> = state
+        .combat
+        .as_ref()
+        .map(|combat| {
+            combat
+                .enemies
+                .iter()
+                .filter(|e| e.is_defeated && e.summoned_by_instance_id.is_none())
+                .map(|e| e.enemy_id.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
     // Expire combat-duration modifiers
     expire_modifiers_combat(&mut state.active_modifiers);
 
@@ -300,6 +335,16 @@ pub(super) fn end_combat(state: &mut GameState, player_idx: usize) {
     if let Some(origin) = withdraw_to {
         state.players[player_idx].position = Some(origin);
     }
+
+    skills_complex::offer_puppet_master_keep(state, player_idx, puppet_master_candidates);
+
+    // Check for level-ups from fame gained during combat
+    crate::end_turn::process_level_ups_pub(state, player_idx);
+    if let Some(counts) = coop_defeated {
+        for &p_idx in counts.keys().filter(|&&idx| idx != player_idx) {
+            crate::end_turn::process_level_ups_pub(state, p_idx);
+        }
+    }
 }
 
 
@@ -411,7 +411,7 @@ pub(super) fn apply_resolve_hex_cost_reduction(
             terrain: mk_types::modifier::TerrainOrAll::Specific(terrain),
             amount: reduction,
             minimum: minimum_cost,
-            replace_cost: Some(minimum_cost),
+            replace_cost: None,
         },
         created_at_round: state.round,
         created_by_player_id: player_id,
@@ -473,7 +473,7 @@ pub(super) fn apply_resolve_terrain_cost_reduction(
             terrain: mk_types::modifier::TerrainOrAll::Specific(terrain),
             amount: reduction,
             minimum: minimum_cost,
-            replace_cost: Some(minimum_cost),
+            replace_cost: None,
         },
         created_at_round: state.round,
         created_by_player_id: player_id,
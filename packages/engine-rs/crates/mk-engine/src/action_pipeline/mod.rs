@@ -28,7 +28,7 @@ mod units;
 pub use combat_end::{expire_modifiers_turn_end, expire_modifiers_turn_start, expire_modifiers_round_end};
 pub use sites::try_negate_wound_with_fortitude;
 pub use units::apply_select_enemy_effects_pub;
-pub(crate) use units::apply_attack_with_modifiers;
+pub(crate) use units::{apply_attack_with_modifiers, is_live_combat_enemy};
 pub use skills::{
     apply_power_of_pain_pub, apply_i_dont_give_a_damn_pub,
     apply_who_needs_magic_pub, apply_universal_power_pub,
@@ -47,6 +47,7 @@ pub(crate) use skills_complex::{
     execute_forked_lightning_target,
     setup_know_your_prey_options, execute_know_your_prey_option,
     execute_puppet_master_select_token, execute_puppet_master_use_mode,
+    execute_puppet_master_keep,
     execute_shapeshift_card_select, execute_shapeshift_type_select,
     execute_regenerate,
 };
@@ -76,6 +77,10 @@ pub struct ApplyResult {
     /// Whether the game has ended.
     pub game_ended: bool,
     /// Events emitted by this action (for replay recording / activity feed).
+    ///
+    /// This is the observer channel: the server and CLI drive logs and
+    /// animations from these (enemy defeats, level ups, round ends, ...)
+    /// rather than diffing state.
     pub events: Vec<GameEvent>,
 }
 
@@ -357,7 +362,10 @@ pub fn apply_legal_action(
                 state,
                 player_idx,
                 Some(undo_stack),
-            );
+            )
+            .map_err(|e| {
+                ApplyError::InternalError(format!("enter_peaceful_moment_conversion failed: {:?}", e))
+            })?;
             ApplyResult {
                 needs_reenumeration: true,
                 game_ended: false,
@@ -940,6 +948,10 @@ pub fn apply_legal_action(
         }
     };
 
+    // Offers deferred behind another pending surface once the player is free again
+    skills_complex::promote_puppet_master_keep(state, player_idx);
+    crate::end_turn::promote_pity_wound_removal(state, player_idx);
+
     // Post-action event generation based on state deltas
     let post_position = state.players[player_idx].position;
     if post_position != pre_position {
@@ -1136,12 +1148,65 @@ pub fn apply_legal_action(
         });
     }
 
-    // Increment epoch after every action
+    // Increment epoch after every action. Handlers never touch the epoch —
+    // undo restores a snapshot but keeps the current one — so epochs only
+    // grow and an action set from before any action (or undo) stays stale.
+    debug_assert_eq!(state.action_epoch, expected_epoch, "handler for {action:?} changed action_epoch");
     state.action_epoch += 1;
 
     Ok(result)
 }
 
+/// Apply a legal action, recovering once from a stale action set.
+///
+/// If `expected_epoch` is stale, legal actions are re-enumerated and the same
+/// action is applied against the fresh epoch when it is still legal. Returns the
+/// apply result and whether a refresh happened. If the action is no longer legal,
+/// the original `StaleActionSet` error is returned.
+pub fn apply_or_refresh(
+    state: &mut GameState,
+    undo_stack: &mut UndoStack,
+    player_idx: usize,
+    action: &LegalAction,
+    expected_epoch: u64,
+) -> Result<(ApplyResult, bool), ApplyError> {
+    match apply_legal_action(state, undo_stack, player_idx, action, expected_epoch) {
+        Err(stale @ ApplyError::StaleActionSet { .. }) => {
+            let fresh = crate::legal_actions::enumerate_legal_actions_with_undo(
+                state, player_idx, undo_stack,
+            );
+            if !fresh.actions.contains(action) {
+                return Err(stale);
+            }
+            apply_legal_action(state, undo_stack, player_idx, action, fresh.epoch)
+                .map(|result| (result, true))
+        }
+        other => other.map(|result| (result, false)),
+    }
+}
+
+/// Undo every reversible action back to the last checkpoint.
+///
+/// Pipeline-level counterpart of `UndoStack::undo_to_checkpoint`.
+/// Returns `false` if there was nothing to undo.
+pub fn undo_to_checkpoint(state: &mut GameState, undo_stack: &mut UndoStack) -> bool {
+    let Some(restored) = undo_stack.undo_to_checkpoint() else {
+        return false;
+    };
+    restore_undo_snapshot(state, restored);
+    true
+}
+
+/// Replace `state` with a snapshot popped from the undo stack.
+///
+/// Like `LegalAction::Undo`, the restored state gets the next epoch instead of
+/// the snapshot's, so action sets enumerated before the rewind stay stale.
+pub fn restore_undo_snapshot(state: &mut GameState, restored: GameState) {
+    let epoch = state.action_epoch;
+    *state = restored;
+    state.action_epoch = epoch + 1;
+}
+
 /// Derive a human-readable label for a SubsetSelectionKind.
 fn subset_selection_kind_label(kind: &mk_types::pending::SubsetSelectionKind) -> String {
     use mk_types::pending::SubsetSelectionKind;
@@ -1570,6 +1635,9 @@ fn describe_choice_resolution(
         ChoiceResolution::SecretWaysLake | ChoiceResolution::SongOfWindLake => {
             Some(if choice_index == 0 { "Skip".to_string() } else { "Pay Blue mana for lake movement".to_string() })
         }
+        ChoiceResolution::PityWoundRemoval => {
+            Some(if choice_index == 0 { "Skip".to_string() } else { "Remove a wound".to_string() })
+        }
         ChoiceResolution::RitualOfPainDiscard { .. } => {
             Some(if choice_index == 0 { "Skip".to_string() } else { format!("Discard {} wound(s)", choice_index) })
         }
@@ -1585,6 +1653,17 @@ fn describe_choice_resolution(
                     .map(|eid| format!("Use {} token", eid.name))
             })
         }
+        ChoiceResolution::PuppetMasterKeepToken { enemy_ids } => {
+            if choice_index == 0 {
+                Some("Skip".to_string())
+            } else {
+                enemy_ids.get(choice_index - 1).map(|eid| {
+                    let name = mk_data::enemies::get_enemy(eid.as_str())
+                        .map_or(eid.as_str(), |d| d.name);
+                    format!("Keep {} token", name)
+                })
+            }
+        }
         ChoiceResolution::PuppetMasterUseMode { attack_value, attack_element, block_value, block_element, .. } => {
             Some(if choice_index == 0 {
                 format!("{} {:?} Attack", attack_value, attack_element)
@@ -361,6 +361,7 @@ pub(super) fn apply_interact_site(
     });
 
     player.flags.insert(PlayerFlags::HAS_TAKEN_ACTION_THIS_TURN);
+    crate::effect_queue::return_wounds_to_pile(state, healed);
 
     Ok(ApplyResult {
         needs_reenumeration: true,
@@ -1153,10 +1154,7 @@ pub(super) fn apply_select_reward(
             state.players[player_idx].units.push(new_unit);
 
             // Replenish unit offer from deck
-            if !state.decks.unit_deck.is_empty() {
-                let new_offer_unit = state.decks.unit_deck.remove(0);
-                state.offers.units.push(new_offer_unit);
-            }
+            crate::offers::replenish_unit_offer(state);
         }
         _ => {
             return Err(ApplyError::InternalError(format!(
@@ -1305,6 +1303,9 @@ pub(super) fn promote_site_reward(state: &mut GameState, player_idx: usize) {
             reward,
             reward_index: 0,
         });
+    } else {
+        // Rewards done — a Puppet Master keep offer may be waiting behind them.
+        super::skills_complex::promote_puppet_master_keep(state, player_idx);
     }
 }
 
@@ -1507,10 +1508,7 @@ pub(super) fn apply_disband_unit_for_reward(
     state.players[player_idx].units.push(new_unit);
 
     // Replenish unit offer from deck
-    if !state.decks.unit_deck.is_empty() {
-        let new_offer_unit = state.decks.unit_deck.remove(0);
-        state.offers.units.push(new_offer_unit);
-    }
+    crate::offers::replenish_unit_offer(state);
 
     // Promote next reward
     promote_site_reward(state, player_idx);
@@ -1529,25 +1527,29 @@ pub(super) fn apply_resolve_glade_wound(
 ) -> Result<ApplyResult, ApplyError> {
     let player = &mut state.players[player_idx];
 
-    match choice {
-        GladeWoundChoice::Hand => {
-            if let Some(idx) = player.hand.iter().position(|c| c.as_str() == "wound") {
-                player.hand.remove(idx);
-            }
-        }
-        GladeWoundChoice::Discard => {
-            if let Some(idx) = player.discard.iter().position(|c| c.as_str() == "wound") {
-                player.discard.remove(idx);
-            }
-        }
-        GladeWoundChoice::Skip => {
-            // Player chose to skip — no wound removed
-        }
-    }
+    let removed = match choice {
+        GladeWoundChoice::Hand => player
+            .hand
+            .iter()
+            .position(|c| c.as_str() == "wound")
+            .map(|idx| player.hand.remove(idx)),
+        GladeWoundChoice::Discard => player
+            .discard
+            .iter()
+            .position(|c| c.as_str() == "wound")
+            .map(|idx| player.discard.remove(idx)),
+        // Player chose to skip — no wound removed
+        GladeWoundChoice::Skip => None,
+    };
 
     // Clear pending
     player.pending.active = None;
 
+    // Removed wound returns to the wound pile
+    if removed.is_some() {
+        crate::effect_queue::return_wounds_to_pile(state, 1);
+    }
+
     Ok(ApplyResult {
         needs_reenumeration: true,
         game_ended: false,
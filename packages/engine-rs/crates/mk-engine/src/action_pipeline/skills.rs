@@ -117,6 +117,11 @@ pub(super) fn apply_use_skill(
         effect_queue::DrainResult::PendingSet => {
             // A custom pending was set directly (e.g., SelectCombatEnemy).
         }
+        effect_queue::DrainResult::ResolutionLimitExceeded => {
+            return Err(ApplyError::InternalError(
+                effect_queue::DrainResult::resolution_limit_message(),
+            ));
+        }
     }
 
     // Note: skills do NOT set HAS_TAKEN_ACTION_THIS_TURN or PLAYED_CARD_FROM_HAND_THIS_TURN
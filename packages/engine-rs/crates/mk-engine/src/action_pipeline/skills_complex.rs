@@ -4,7 +4,7 @@
 use mk_types::enums::*;
 use mk_types::ids::{CardId, SkillId};
 use mk_types::state::*;
-use mk_types::pending::ActivePending;
+use mk_types::pending::{ActivePending, DeferredPending};
 
 use crate::combat_resolution;
 
@@ -100,6 +100,114 @@ pub(super) fn setup_puppet_master_use_mode(
 }
 
 
+/// After combat, offer Krang the chance to keep one defeated enemy token.
+///
+/// Keeping is the "keep" half of the once-per-turn skill, so it is only offered
+/// while the skill is unused this turn and there is room for another token.
+/// Summoned enemies don't leave a token. If another pending is already active
+/// the offer is deferred and promoted once it (and any site rewards) resolve.
+pub(super) fn offer_puppet_master_keep(
+    state: &mut GameState,
+    player_idx: usize,
+    defeated: Vec<mk_types::ids::EnemyId>,
+) {
+    let skill_id = SkillId::from("krang_puppet_master");
+    let player = &mut state.players[player_idx];
+    if defeated.is_empty()
+        || !player.skills.contains(&skill_id)
+        || player.skill_cooldowns.used_this_turn.contains(&skill_id)
+        || player.kept_enemy_tokens.is_full()
+    {
+        return;
+    }
+    if player.pending.active.is_some() {
+        if !player.pending.deferred.is_full() {
+            player
+                .pending
+                .deferred
+                .push(DeferredPending::PuppetMasterKeep { enemy_ids: defeated });
+        }
+        return;
+    }
+
+    // Skip + one option per defeated enemy
+    let options = vec![mk_types::effect::CardEffect::Noop; defeated.len() + 1];
+    state.players[player_idx].pending.active =
+        Some(ActivePending::Choice(mk_types::pending::PendingChoice {
+            card_id: None,
+            skill_id: Some(skill_id),
+            unit_instance_id: None,
+            options,
+            continuation: vec![],
+            movement_bonus_applied: false,
+            resolution: mk_types::pending::ChoiceResolution::PuppetMasterKeepToken {
+                enemy_ids: defeated,
+            },
+        }));
+}
+
+/// Promote a deferred Puppet Master keep offer, if any, to active pending.
+///
+/// Waits while a pending is active or site rewards are still queued.
+pub(super) fn promote_puppet_master_keep(state: &mut GameState, player_idx: usize) {
+    let pending = &mut state.players[player_idx].pending;
+    if pending.active.is_some()
+        || pending.deferred.iter().any(|d| matches!(d, DeferredPending::Rewards(_)))
+    {
+        return;
+    }
+    let Some(pos) = pending
+        .deferred
+        .iter()
+        .position(|d| matches!(d, DeferredPending::PuppetMasterKeep { .. }))
+    else {
+        return;
+    };
+    if let DeferredPending::PuppetMasterKeep { enemy_ids } = pending.deferred.remove(pos) {
+        offer_puppet_master_keep(state, player_idx, enemy_ids);
+    }
+}
+
+pub(crate) fn execute_puppet_master_keep(
+    state: &mut GameState,
+    player_idx: usize,
+    skill_id: &SkillId,
+    enemy_ids: &[mk_types::ids::EnemyId],
+    choice_index: usize,
+) {
+    // Index 0 = skip
+    let Some(enemy_id) = choice_index.checked_sub(1).and_then(|i| enemy_ids.get(i)) else {
+        return;
+    };
+    let Some(def) = mk_data::enemies::get_enemy(enemy_id.as_str()) else {
+        return;
+    };
+
+    if state.players[player_idx].kept_enemy_tokens.is_full() {
+        return;
+    }
+
+    // The token leaves play with Krang instead of staying in the discard pile.
+    mk_data::enemy_piles::take_discarded_enemy_token(
+        &mut state.enemy_tokens,
+        enemy_id.as_str(),
+        def.color,
+    );
+
+    let player = &mut state.players[player_idx];
+    player.kept_enemy_tokens.push(KeptEnemyToken {
+        enemy_id: enemy_id.clone(),
+        name: def.name.to_string(),
+        attack: def.attack,
+        attack_element: def.attack_element,
+        armor: def.armor,
+    });
+    if !player.skill_cooldowns.used_this_turn.contains(skill_id) {
+        player.skill_cooldowns.used_this_turn.push(skill_id.clone());
+    }
+}
+
+
 /// Derive block element from enemy resistances.
 pub(super) fn derive_block_element_from_enemy(enemy_id: &str) -> Element {
     let def = mk_data::enemies::get_enemy(enemy_id);
@@ -625,7 +733,7 @@ pub(crate) fn execute_regenerate(
     let player = &mut state.players[player_idx];
     if let Some(pos) = player.hand.iter().position(|c| c.as_str() == "wound") {
         player.hand.remove(pos);
-        state.wound_pile_count = Some(state.wound_pile_count.unwrap_or(0) + 1);
+        crate::effect_queue::return_wounds_to_pile(state, 1);
     }
 
     // Check bonus draw: mana color matches bonus_color OR strictly lowest fame
@@ -987,7 +1095,7 @@ pub(crate) fn execute_invocation(
     if let Some(pos) = player.hand.iter().position(|c| *c == opt.card_id) {
         player.hand.remove(pos);
         if opt.is_wound {
-            state.wound_pile_count = Some(state.wound_pile_count.unwrap_or(0) + 1);
+            crate::effect_queue::return_wounds_to_pile(state, 1);
         } else {
             state.players[player_idx].discard.push(opt.card_id.clone());
         }
@@ -1108,7 +1216,7 @@ pub(super) fn apply_polarization(
 
     // 3. Source dice
     for die in &state.source.dice {
-        if die.is_depleted || die.taken_by_player_id.is_some() {
+        if !polarization_die_usable(state, player_idx, die) {
             continue;
         }
         // Basic → opposite
@@ -1187,6 +1295,19 @@ pub(super) fn apply_polarization(
 }
 
 
+/// Whether Polarization may convert `die`. Taking it counts as the turn's
+/// Source die. Polarization is what makes a Black die usable by day and a
+/// Gold die usable at night, so those time-of-day depletions don't rule it out.
+fn polarization_die_usable(state: &GameState, player_idx: usize, die: &SourceDie) -> bool {
+    let is_day = state.time_of_day == TimeOfDay::Day;
+    let converts_depleted = (die.color == ManaColor::Black && is_day)
+        || (die.color == ManaColor::Gold && !is_day);
+    crate::card_play::can_use_source(state, player_idx)
+        && die.taken_by_player_id.is_none()
+        && (!die.is_depleted || converts_depleted)
+}
+
+
 /// Execute polarization: consume source mana, gain target mana.
 pub(crate) fn execute_polarization(
     state: &mut GameState,
@@ -1246,6 +1367,9 @@ pub(crate) fn execute_polarization(
                 if let Some(die) = state.source.dice.iter_mut().find(|d| d.id == *die_id) {
                     die.taken_by_player_id = Some(player_id);
                     die.is_depleted = true;
+                    let player = &mut state.players[player_idx];
+                    player.used_die_ids.push(die_id.clone());
+                    player.flags.insert(PlayerFlags::USED_MANA_FROM_SOURCE);
                 }
             }
         }
@@ -1732,7 +1856,7 @@ pub(crate) fn setup_know_your_prey_options(
     enemy_instance_id: &str,
 ) {
     use mk_types::effect::CardEffect;
-    use mk_types::pending::{ChoiceResolution, KnowYourPreyApplyOption, PendingChoice};
+    use mk_types::pending::{ChoiceResolution, PendingChoice};
 
     let combat = state.combat.as_ref().unwrap();
     let enemy = combat
@@ -1740,59 +1864,9 @@ pub(crate) fn setup_know_your_prey_options(
         .iter()
         .find(|e| e.instance_id.as_str() == enemy_instance_id)
         .unwrap();
-    let enemy_def = mk_data::enemies::get_enemy(enemy.enemy_id.as_str());
-
-    let mut strip_options: Vec<KnowYourPreyApplyOption> = Vec::new();
-
-    if let Some(def) = enemy_def {
-        // Removable abilities
-        let removable = [
-            EnemyAbilityType::Assassination,
-            EnemyAbilityType::Brutal,
-            EnemyAbilityType::Paralyze,
-            EnemyAbilityType::Poison,
-            EnemyAbilityType::Swift,
-            EnemyAbilityType::Vampiric,
-            EnemyAbilityType::Elusive,
-            EnemyAbilityType::Fortified,
-        ];
-        for ability in &removable {
-            if def.abilities.contains(ability) {
-                strip_options.push(KnowYourPreyApplyOption::NullifyAbility {
-                    ability: *ability,
-                });
-            }
-        }
-
-        // Resistances
-        for r in def.resistances {
-            strip_options.push(KnowYourPreyApplyOption::RemoveResistance {
-                element: *r,
-            });
-        }
-
-        // Element conversions (attack element)
-        let attack_element = def.attack_element;
-        add_element_conversions(&mut strip_options, attack_element);
-
-        // Multi-attack element conversions
-        if let Some(attacks) = def.attacks {
-            for atk in attacks {
-                add_element_conversions(&mut strip_options, atk.element);
-            }
-        }
-    }
-
-    // Deduplicate conversions
-    strip_options.dedup_by(|a, b| {
-        match (a, b) {
-            (
-                KnowYourPreyApplyOption::ConvertElement { from: f1, to: t1 },
-                KnowYourPreyApplyOption::ConvertElement { from: f2, to: t2 },
-            ) => *f1 == *f2 && *t1 == *t2,
-            _ => false,
-        }
-    });
+    let strip_options = mk_data::enemies::get_enemy(enemy.enemy_id.as_str())
+        .map(know_your_prey_strip_options)
+        .unwrap_or_default();
 
     if strip_options.len() == 1 {
         // Auto-apply
@@ -1818,35 +1892,65 @@ pub(crate) fn setup_know_your_prey_options(
 }
 
 
+/// Abilities Know Your Prey can nullify.
+const KNOW_YOUR_PREY_ABILITIES: [EnemyAbilityType; 8] = [
+    EnemyAbilityType::Assassination,
+    EnemyAbilityType::Brutal,
+    EnemyAbilityType::Paralyze,
+    EnemyAbilityType::Poison,
+    EnemyAbilityType::Swift,
+    EnemyAbilityType::Vampiric,
+    EnemyAbilityType::Elusive,
+    EnemyAbilityType::Fortified,
+];
+
+
+/// Everything Know Your Prey can strip from an enemy, one option each:
+/// a nullifiable ability (including Fortified), a resistance, or a
+/// non-physical attack element (Fire/Ice → Physical, Cold Fire → Fire or Ice).
+/// Physical attacks and other abilities (e.g. Summon) offer nothing.
+pub(super) fn know_your_prey_strip_options(
+    def: &mk_data::enemies::EnemyDefinition,
+) -> Vec<mk_types::pending::KnowYourPreyApplyOption> {
+    use mk_types::pending::KnowYourPreyApplyOption;
+
+    let mut strip_options: Vec<KnowYourPreyApplyOption> = KNOW_YOUR_PREY_ABILITIES
+        .iter()
+        .filter(|ability| def.abilities.contains(ability))
+        .map(|ability| KnowYourPreyApplyOption::NullifyAbility { ability: *ability })
+        .collect();
+
+    for r in def.resistances {
+        strip_options.push(KnowYourPreyApplyOption::RemoveResistance { element: *r });
+    }
+
+    add_element_conversions(&mut strip_options, def.attack_element);
+    if let Some(attacks) = def.attacks {
+        for atk in attacks {
+            add_element_conversions(&mut strip_options, atk.element);
+        }
+    }
+
+    strip_options
+}
+
+
 pub(super) fn add_element_conversions(
     strip_options: &mut Vec<mk_types::pending::KnowYourPreyApplyOption>,
     element: Element,
 ) {
     use mk_types::pending::KnowYourPreyApplyOption;
-    match element {
-        Element::Fire => {
-            strip_options.push(KnowYourPreyApplyOption::ConvertElement {
-                from: Element::Fire,
-                to: Element::Physical,
-            });
-        }
-        Element::Ice => {
-            strip_options.push(KnowYourPreyApplyOption::ConvertElement {
-                from: Element::Ice,
-                to: Element::Physical,
-            });
-        }
-        Element::ColdFire => {
-            strip_options.push(KnowYourPreyApplyOption::ConvertElement {
-                from: Element::ColdFire,
-                to: Element::Fire,
-            });
-            strip_options.push(KnowYourPreyApplyOption::ConvertElement {
-                from: Element::ColdFire,
-                to: Element::Ice,
-            });
+    let targets: &[Element] = match element {
+        Element::Fire | Element::Ice => &[Element::Physical],
+        Element::ColdFire => &[Element::Fire, Element::Ice],
+        _ => &[], // Physical has no conversion
+    };
+    for to in targets {
+        let option = KnowYourPreyApplyOption::ConvertElement { from: element, to: *to };
+        // Multi-attack enemies may repeat an element
+        if !strip_options.contains(&option) {
+            strip_options.push(option);
         }
-        _ => {} // Physical has no conversion
     }
 }
 
@@ -1914,44 +2018,11 @@ pub(super) fn is_enemy_arcane_immune(enemy_id: &str) -> bool {
 }
 
 
+/// Whether Know Your Prey has anything to strip from this enemy
+/// (see `know_your_prey_strip_options`).
 pub(super) fn has_strippable_attributes(enemy_id: &str) -> bool {
-    let Some(def) = mk_data::enemies::get_enemy(enemy_id) else {
-        return false;
-    };
-
-    // Has removable abilities?
-    let removable = [
-        EnemyAbilityType::Assassination,
-        EnemyAbilityType::Brutal,
-        EnemyAbilityType::Paralyze,
-        EnemyAbilityType::Poison,
-        EnemyAbilityType::Swift,
-        EnemyAbilityType::Vampiric,
-        EnemyAbilityType::Elusive,
-        EnemyAbilityType::Fortified,
-    ];
-    if def.abilities.iter().any(|a| removable.contains(a)) {
-        return true;
-    }
-
-    // Has resistances?
-    if !def.resistances.is_empty() {
-        return true;
-    }
-
-    // Has non-physical attack element?
-    if !matches!(def.attack_element, Element::Physical) {
-        return true;
-    }
-
-    // Multi-attack non-physical?
-    if let Some(attacks) = def.attacks {
-        if attacks.iter().any(|a| !matches!(a.element, Element::Physical)) {
-            return true;
-        }
-    }
-
-    false
+    mk_data::enemies::get_enemy(enemy_id)
+        .is_some_and(|def| !know_your_prey_strip_options(def).is_empty())
 }
 
 
@@ -1986,7 +2057,7 @@ pub(crate) fn has_polarization_options(state: &GameState, player_idx: usize) ->
 
     // Check source dice
     for die in &state.source.dice {
-        if die.is_depleted || die.taken_by_player_id.is_some() { continue; }
+        if !polarization_die_usable(state, player_idx, die) { continue; }
         if is_basic(die.color) { return true; }
         if die.color == ManaColor::Black && is_day { return true; }
         if die.color == ManaColor::Gold && !is_day { return true; }
@@ -154,7 +154,7 @@ pub(crate) fn execute_ritual_of_pain_discard(
 ) {
     let skill_id = SkillId::from("arythea_ritual_of_pain");
 
-    // Discard `choice_index` wounds from hand
+    // Throw away `choice_index` wounds from hand (back to the wound pile)
     let mut wounds_to_remove = choice_index;
     let player = &mut state.players[player_idx];
     player.hand.retain(|c| {
@@ -165,6 +165,8 @@ pub(crate) fn execute_ritual_of_pain_discard(
             true
         }
     });
+    let thrown = (choice_index - wounds_to_remove) as u32;
+    crate::effect_queue::return_wounds_to_pile(state, thrown);
 
     place_skill_in_center(state, player_idx, &skill_id);
 }
@@ -650,6 +652,9 @@ pub(super) fn apply_master_of_chaos(
             Ok(ApplyResult { needs_reenumeration: true, game_ended: false, events: Vec::new() })
         }
         effect_queue::DrainResult::PendingSet => Ok(ApplyResult { needs_reenumeration: true, game_ended: false, events: Vec::new() }),
+        effect_queue::DrainResult::ResolutionLimitExceeded => Err(ApplyError::InternalError(
+            effect_queue::DrainResult::resolution_limit_message(),
+        )),
     }
 }
 
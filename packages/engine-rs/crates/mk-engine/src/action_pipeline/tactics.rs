@@ -92,8 +92,10 @@ pub(super) fn apply_select_tactic(
     state.current_tactic_selector = None;
 
     // Sort turn order by tactic number (lower goes first)
-    // Dummy player uses its auto-selected tactic for sorting
-    state.turn_order.sort_by_key(|pid| {
+    // Dummy player uses its auto-selected tactic for sorting.
+    // Tie-break: player id, so identical tactic numbers (dummy vs players,
+    // variant configs) always yield the same order regardless of prior order.
+    let tactic_number = |pid: &PlayerId| -> u8 {
         if let Some(p) = state.players.iter().find(|p| p.id == *pid) {
             p.selected_tactic
                 .as_ref()
@@ -108,7 +110,10 @@ pub(super) fn apply_select_tactic(
         } else {
             99
         }
-    });
+    };
+    state
+        .turn_order
+        .sort_by(|a, b| tactic_number(a).cmp(&tactic_number(b)).then_with(|| a.cmp(b)));
     state.current_player_index = 0;
 
     // If dummy is first in turn order, auto-execute their first turn
@@ -130,6 +135,13 @@ pub(super) fn apply_select_tactic(
     // On-pick tactic effects for the final selector
     apply_tactic_on_pick_effects(state, player_idx, tactic_id);
 
+    // Before-turn tactic setup for whoever takes the round's first turn
+    let first_id = state.turn_order[state.current_player_index as usize].clone();
+    if let Some(first_idx) = state.players.iter().position(|p| p.id == first_id) {
+        crate::end_turn::apply_sparing_power_before_turn(state, first_idx);
+        crate::end_turn::promote_pity_wound_removal(state, first_idx);
+    }
+
     Ok(ApplyResult {
         needs_reenumeration: true,
         game_ended: false,
@@ -169,14 +181,9 @@ pub(super) fn apply_tactic_on_pick_effects(
             }
         }
         "mana_steal" => {
-            let current_player_id = &state.players[player_idx].id;
-            // Available: unclaimed basic dice OR basic dice claimed by other players
-            let has_available = state.source.dice.iter().any(|d| {
-                !d.is_depleted
-                    && d.color.is_basic()
-                    && (d.taken_by_player_id.is_none()
-                        || d.taken_by_player_id.as_ref().is_some_and(|owner| owner != current_player_id))
-            });
+            // Available: unclaimed basic dice OR unspent basic dice claimed by other players
+            let has_available = (0..state.source.dice.len())
+                .any(|idx| crate::mana::check_mana_steal_target(state, player_idx, idx).is_ok());
             if has_available {
                 state.players[player_idx].pending.active =
                     Some(ActivePending::TacticDecision(PendingTacticDecision::ManaSteal));
@@ -208,9 +215,8 @@ pub(super) fn apply_resolve_tactic_decision(
     let mut events: Vec<GameEvent> = Vec::new();
     match data {
         TacticDecisionData::ManaSteal { die_index } => {
-            if *die_index >= state.source.dice.len() {
-                return Err(ApplyError::InternalError("ManaSteal: invalid die index".into()));
-            }
+            crate::mana::check_mana_steal_target(state, player_idx, *die_index)
+                .map_err(|e| ApplyError::InternalError(format!("ManaSteal: {}", e)))?;
 
             let die = &mut state.source.dice[*die_index];
             let die_id = die.id.clone();
@@ -236,30 +242,40 @@ pub(super) fn apply_resolve_tactic_decision(
         }
 
         TacticDecisionData::Preparation { deck_card_index } => {
-            // Validate against actual deck (not snapshot — snapshot was for enumeration)
             let player = &mut state.players[player_idx];
 
-            // Use the pending to get the snapshot card
-            let card_id = if let Some(ActivePending::TacticDecision(PendingTacticDecision::Preparation { deck_snapshot })) = &player.pending.active {
-                deck_snapshot.get(*deck_card_index).cloned()
-            } else {
-                None
+            // The snapshot drives enumeration; it must still describe the live deck
+            // so the index picks the exact copy the player chose.
+            let snapshot_matches = match &player.pending.active {
+                Some(ActivePending::TacticDecision(PendingTacticDecision::Preparation {
+                    deck_snapshot,
+                })) => {
+                    if *deck_card_index >= deck_snapshot.len() {
+                        return Err(ApplyError::InternalError(
+                            "Preparation: invalid deck card index".into(),
+                        ));
+                    }
+                    *deck_snapshot == player.deck
+                }
+                _ => {
+                    return Err(ApplyError::InternalError(
+                        "Preparation: no pending Preparation decision".into(),
+                    ));
+                }
             };
-
-            let card_id = card_id.ok_or_else(|| {
-                ApplyError::InternalError("Preparation: invalid deck card index".into())
-            })?;
-
-            // Remove first occurrence from actual deck
-            if let Some(pos) = player.deck.iter().position(|c| *c == card_id) {
-                player.deck.remove(pos);
-                events.push(GameEvent::CardGained {
-                    player_id: player.id.clone(),
-                    card_id: card_id.clone(),
-                });
-                player.hand.push(card_id);
+            if !snapshot_matches {
+                return Err(ApplyError::InternalError(
+                    "Preparation: deck changed since snapshot".into(),
+                ));
             }
 
+            let card_id = player.deck.remove(*deck_card_index);
+            events.push(GameEvent::CardGained {
+                player_id: player.id.clone(),
+                card_id: card_id.clone(),
+            });
+            player.hand.push(card_id);
+
             // Shuffle remaining deck
             let deck = &mut state.players[player_idx].deck;
             state.rng.shuffle(deck);
@@ -280,7 +296,7 @@ pub(super) fn apply_resolve_tactic_decision(
 
         TacticDecisionData::SparingPowerTake => {
             let player = &mut state.players[player_idx];
-            let stored: Vec<CardId> = player.tactic_state.sparing_power_stored.drain(..).collect();
+            let stored: Vec<CardId> = std::mem::take(&mut player.tactic_state.sparing_power_stored);
             for card_id in &stored {
                 events.push(GameEvent::CardGained {
                     player_id: player.id.clone(),
@@ -440,11 +456,15 @@ pub(super) fn finalize_subset_selection(
             }
             let draw_count = removed.len();
 
-            // Pool = removed + deck + discard
+            // Pool = removed + deck (+ discard for Rethink; Midnight Meditation
+            // only shuffles the chosen cards back into the deck)
+            let is_rethink = ss.kind == SubsetSelectionKind::Rethink;
             let mut pool: Vec<CardId> = Vec::new();
             pool.extend(removed);
             pool.append(&mut player.deck);
-            pool.append(&mut player.discard);
+            if is_rethink {
+                pool.append(&mut player.discard);
+            }
 
             // Shuffle pool
             state.rng.shuffle(&mut pool);
@@ -458,7 +478,6 @@ pub(super) fn finalize_subset_selection(
 
             // Remaining → deck
             player.deck = pool;
-            player.discard.clear();
         }
         SubsetSelectionKind::ManaSearch {
             rerollable_die_indices,
@@ -3,7 +3,8 @@
 use super::*;
 use super::skills::push_passive_skill_modifiers;
 use super::skills_complex::{
-    classify_basic_action_for_shapeshift, derive_block_element_from_enemy,
+    classify_basic_action_for_shapeshift, classify_effect_for_shapeshift,
+    derive_block_element_from_enemy,
     mark_dueling_unit_involvement, resolve_dueling_fame_bonus,
 };
 use crate::legal_actions::enumerate_legal_actions_with_undo;
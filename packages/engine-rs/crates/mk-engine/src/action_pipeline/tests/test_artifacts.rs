@@ -618,3 +618,50 @@ fn bow_phase_fame_fires_before_combat_modifier_expiry() {
     );
 }
 
+// =========================================================================
+// Artifact throw — free powered play destroys the artifact
+// =========================================================================
+
+#[test]
+fn throwing_artifact_resolves_powered_effect_and_removes_it_from_game() {
+    let mut state = setup_playing_game(vec!["endless_bag_of_gold"]);
+    let mut undo = UndoStack::new();
+    state.source.dice.clear();
+    // Influence cards are only playable while interacting.
+    state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+    let fame_before = state.players[0].fame;
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let throw = legal
+        .actions
+        .iter()
+        .find(|a| matches!(a, LegalAction::PlayCardPowered { card_id, mana_color: None, .. }
+            if card_id.as_str() == "endless_bag_of_gold"))
+        .expect("artifact throw needs no mana")
+        .clone();
+    apply_legal_action(&mut state, &mut undo, 0, &throw, legal.epoch).unwrap();
+
+    let player = &state.players[0];
+    assert_eq!(player.influence_points, 9);
+    assert_eq!(player.fame, fame_before + 3);
+    assert!(player.hand.is_empty());
+    assert!(player.play_area.is_empty(), "thrown artifact does not stay in play");
+    assert_eq!(player.removed_cards, vec![CardId::from("endless_bag_of_gold")]);
+}
+
+#[test]
+fn basic_artifact_play_keeps_the_artifact() {
+    let mut state = setup_playing_game(vec!["endless_bag_of_gold"]);
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::PlayCardBasic { hand_index: 0, card_id: CardId::from("endless_bag_of_gold") },
+        epoch,
+    ).unwrap();
+
+    let player = &state.players[0];
+    assert_eq!(player.influence_points, 4);
+    assert_eq!(player.play_area, vec![CardId::from("endless_bag_of_gold")]);
+    assert!(player.removed_cards.is_empty());
+}
@@ -109,6 +109,54 @@ fn unit_combat_bonus_no_modifier_baseline() {
     assert_eq!(state.players[0].combat_accumulator.attack.normal, 2);
 }
 
+#[test]
+fn coordinated_fire_adds_ranged_and_boosts_later_unit_attacks() {
+    use mk_types::ids::UnitInstanceId;
+
+    let (mut state, mut undo) = setup_combat_with_unit_and_bonus(&["prowlers"], 0, 0);
+    state.players[0].units.push(PlayerUnit {
+        instance_id: UnitInstanceId::from("unit_1"),
+        unit_id: mk_types::ids::UnitId::from("shocktroops"),
+        level: 2,
+        state: UnitState::Ready,
+        wounded: false,
+        used_resistance_this_combat: false,
+        used_ability_indices: vec![],
+        mana_token: None,
+    });
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::ActivateUnit {
+            unit_instance_id: UnitInstanceId::from("unit_1"),
+            ability_index: 0, // Coordinated Fire: Ranged 1, units +1 attack
+        },
+        epoch,
+    )
+    .unwrap();
+    assert_eq!(state.players[0].combat_accumulator.attack.ranged, 1);
+
+    state.combat.as_mut().unwrap().phase = CombatPhase::Attack;
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::ActivateUnit {
+            unit_instance_id: UnitInstanceId::from("unit_0"),
+            ability_index: 0, // Attack 2 Physical
+        },
+        epoch,
+    )
+    .unwrap();
+    // Peasants attack 2 (base) + 1 (coordinated fire) = 3
+    assert_eq!(state.players[0].combat_accumulator.attack.normal, 3);
+    assert_eq!(state.players[0].combat_accumulator.attack.ranged, 1);
+}
+
 // =========================================================================
 // Step 5: Banner assignment tests
 // =========================================================================
@@ -939,7 +987,7 @@ fn fortitude_damage_test(
         epoch,
     );
 
-    let is_wounded = state.players[0].units.get(0).map(|u| u.wounded).unwrap_or(true);
+    let is_wounded = state.players[0].units.first().map(|u| u.wounded).unwrap_or(true);
 
     (state, is_wounded)
 }
@@ -271,6 +271,143 @@ fn declare_attack_defeats_enemy_grants_fame() {
     assert_eq!(state.players[0].enemies_defeated_this_turn, 1);
 }
 
+#[test]
+fn resolving_a_killing_attack_emits_enemy_defeated_event() {
+    let mut state = setup_combat_game(&["prowlers"]); // armor 3, fame 2
+    state.combat.as_mut().unwrap().phase = CombatPhase::Attack;
+    state.players[0].combat_accumulator.attack.normal_elements.physical = 5;
+    let mut undo = UndoStack::new();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::SubsetSelect { index: 0 }, epoch).unwrap();
+    if state.players[0].pending.has_active() {
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &LegalAction::SubsetConfirm, epoch).unwrap();
+    }
+    let epoch = state.action_epoch;
+    let result = apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveAttack, epoch).unwrap();
+
+    let defeated: Vec<_> = result
+        .events
+        .iter()
+        .filter_map(|e| match e {
+            GameEvent::EnemyDefeated { player_id, enemy_id, fame_gained } => {
+                Some((player_id.clone(), enemy_id.as_str().to_string(), *fame_gained))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(defeated, vec![(state.players[0].id.clone(), "prowlers".to_string(), Some(2))]);
+}
+
+#[test]
+fn declare_attack_defeat_recorded_in_combat_log() {
+    let mut state = setup_combat_game(&["prowlers"]); // armor 3, fame 2
+    state.combat.as_mut().unwrap().phase = CombatPhase::Attack;
+    state.players[0].combat_accumulator.attack.normal_elements = ElementalValues {
+        physical: 5,
+        fire: 0,
+        ice: 0,
+        cold_fire: 0,
+    };
+
+    let mut undo = UndoStack::new();
+    execute_attack(&mut state, &mut undo, CombatType::Melee, 1);
+
+    let log = &state.combat.as_ref().unwrap().combat_log;
+    assert_eq!(
+        log.as_slice(),
+        &[
+            CombatLogEntry::AttackDeclared {
+                target_instance_ids: vec![CombatInstanceId::from("enemy_0")],
+                attack_type: CombatType::Melee,
+                success: true,
+            },
+            CombatLogEntry::EnemyDefeated {
+                enemy_instance_id: CombatInstanceId::from("enemy_0"),
+                enemy_id: mk_types::ids::EnemyId::from("prowlers"),
+                fame: 2,
+            },
+        ]
+    );
+
+    // Surfaced to the client view
+    let client = crate::client_state::to_client_state(&state, &state.players[0].id);
+    assert_eq!(client.combat.unwrap().combat_log, *log);
+}
+
+#[test]
+fn combat_log_defeat_fame_matches_credited_fame() {
+    let mut state = setup_combat_game(&["prowlers"]); // fame 2
+    state.combat.as_mut().unwrap().phase = CombatPhase::Attack;
+    state.players[0].combat_accumulator.attack.normal_elements.physical = 5;
+    let pid = state.players[0].id.clone();
+    state.active_modifiers.push(mk_types::modifier::ActiveModifier {
+        id: mk_types::ids::ModifierId::from("fame_bonus"),
+        source: mk_types::modifier::ModifierSource::Card {
+            card_id: CardId::from("banner_of_glory"),
+            player_id: pid.clone(),
+        },
+        duration: mk_types::modifier::ModifierDuration::Combat,
+        scope: mk_types::modifier::ModifierScope::AllUnits,
+        effect: mk_types::modifier::ModifierEffect::FamePerEnemyDefeated {
+            fame_per_enemy: 1,
+            exclude_summoned: false,
+        },
+        created_at_round: 1,
+        created_by_player_id: pid,
+    });
+
+    let mut undo = UndoStack::new();
+    execute_attack(&mut state, &mut undo, CombatType::Melee, 1);
+
+    let combat = state.combat.as_ref().unwrap();
+    assert_eq!(combat.fame_gained, 3);
+    assert!(combat.combat_log.contains(&CombatLogEntry::EnemyDefeated {
+        enemy_instance_id: CombatInstanceId::from("enemy_0"),
+        enemy_id: mk_types::ids::EnemyId::from("prowlers"),
+        fame: 3,
+    }));
+}
+
+#[test]
+fn combat_fame_crossing_two_skill_levels_queues_two_rewards() {
+    // Delphana Masters (fame 9) + Altem Mages (fame 8): 0 → 17 fame = level 4
+    let mut state = setup_combat_game(&["delphana_masters", "altem_mages"]);
+    assert_eq!(state.players[0].level, 1);
+    state.combat.as_mut().unwrap().phase = CombatPhase::Attack;
+    state.players[0].combat_accumulator.attack.normal_elements = ElementalValues {
+        physical: 50,
+        fire: 0,
+        ice: 0,
+        cold_fire: 0,
+    };
+
+    let mut undo = UndoStack::new();
+    execute_attack(&mut state, &mut undo, CombatType::Melee, 2);
+    assert_eq!(state.players[0].fame, 17);
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    assert!(state.combat.is_none());
+
+    let player = &state.players[0];
+    assert_eq!(player.level, 4);
+    assert_eq!(player.armor, 3);
+    assert_eq!(player.command_tokens, 2);
+    let rewards: Vec<u8> = player
+        .pending
+        .deferred
+        .iter()
+        .filter_map(|d| match d {
+            DeferredPending::LevelUpRewards(r) => Some(r.iter().map(|r| r.level)),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    assert_eq!(rewards, vec![2, 4]);
+}
+
 #[test]
 fn declare_attack_reputation_update() {
     // Thugs (gray): reputation_bonus=1
@@ -414,6 +551,29 @@ fn end_combat_phase_block_poison_adds_wounds_to_discard() {
     assert_eq!(discard_wounds, 2);
 }
 
+#[test]
+fn wound_pile_tracks_combat_wounds_and_healing() {
+    let mut state = setup_combat_game(&["cursed_hags"]); // 3 physical, poison
+    state.combat.as_mut().unwrap().phase = CombatPhase::Block;
+    state.wound_pile_count = Some(10);
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+
+    // ceil(3/2)=2 wounds to hand plus 2 poison wounds to discard, all from the pile
+    assert_eq!(state.players[0].wounds_received_this_turn.hand, 2);
+    assert_eq!(state.players[0].wounds_received_this_turn.discard, 2);
+    assert_eq!(state.wound_pile_count, Some(6));
+
+    // Healing the hand wounds returns them to the pile
+    let mut queue = crate::effect_queue::EffectQueue::new();
+    queue.push(CardEffect::GainHealing { amount: 5 }, None);
+    queue.drain(&mut state, 0);
+    assert!(!state.players[0].hand.iter().any(|c| c.as_str() == "wound"));
+    assert_eq!(state.wound_pile_count, Some(8));
+}
+
 #[test]
 fn end_combat_phase_attack_removes_defeated_from_hex() {
     let hex = mk_types::hex::HexCoord::new(1, -1);
@@ -1260,6 +1420,57 @@ fn empty_pile_no_summon() {
     );
 }
 
+#[test]
+fn empty_pile_summon_combat_still_advances() {
+    let mut state = setup_playing_game(vec!["march"]);
+    let tokens = vec![EnemyTokenId::from("orc_summoners_1")];
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &tokens, false, None, Default::default(),
+    ).unwrap();
+    state.enemy_tokens.brown_draw.clear();
+    state.enemy_tokens.brown_discard.clear();
+
+    let mut undo = UndoStack::new();
+    // RangedSiege → Block → AssignDamage → Attack with nothing summoned.
+    for expected in [CombatPhase::Block, CombatPhase::AssignDamage, CombatPhase::Attack] {
+        let epoch = state.action_epoch;
+        apply_legal_action(
+            &mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch,
+        ).unwrap();
+        let combat = state.combat.as_ref().unwrap();
+        assert_eq!(combat.phase, expected);
+        assert_eq!(combat.enemies.len(), 1);
+    }
+}
+
+#[test]
+fn exhausted_draw_pile_summons_from_reshuffled_discard() {
+    let mut state = setup_playing_game(vec!["march"]);
+    let tokens = vec![EnemyTokenId::from("orc_summoners_1")];
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &tokens, false, None, Default::default(),
+    ).unwrap();
+
+    // Only a discarded token is left; drawing must reshuffle it back in.
+    state.enemy_tokens.brown_draw.clear();
+    state.enemy_tokens.brown_discard = vec![EnemyTokenId::from("gargoyle_1")];
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch,
+    ).unwrap();
+
+    let combat = state.combat.as_ref().unwrap();
+    let summoned = combat.enemies.iter()
+        .find(|e| e.summoned_by_instance_id.is_some())
+        .expect("Discard should be reshuffled to supply the summon");
+    assert_eq!(summoned.enemy_id.as_str(), "gargoyle");
+    assert!(combat.enemies[0].is_summoner_hidden);
+    assert!(state.enemy_tokens.brown_discard.is_empty());
+    assert!(state.enemy_tokens.brown_draw.is_empty());
+}
+
 #[test]
 fn dragon_summoner_draws_twice() {
     // Dragon Summoner: 2 Summon attacks → draws 2 from Brown
@@ -1554,6 +1765,133 @@ fn burning_shield_destroy_blocked_by_arcane_immunity() {
     ));
 }
 
+/// Play Exploding Shield (Burning Shield powered) for real, then block `enemy`.
+fn block_with_exploding_shield(enemy: &str) -> GameState {
+    let mut state = setup_combat_game(&[enemy]);
+    state.combat.as_mut().unwrap().phase = CombatPhase::Block;
+    state.players[0].hand = vec![CardId::from("burning_shield")];
+    state.source.dice.clear(); // pay from tokens only, no source choice
+    // Spell cost + black for the powered effect (night only)
+    state.time_of_day = TimeOfDay::Night;
+    for color in [ManaColor::Red, ManaColor::Black] {
+        state.players[0].pure_mana.push(ManaToken {
+            color,
+            source: ManaTokenSource::Effect,
+            cannot_power_spells: false,
+        });
+    }
+    crate::card_play::play_card(&mut state, 0, 0, true, None).unwrap();
+    assert!(state.active_modifiers.iter().any(|m| matches!(&m.effect,
+        mk_types::modifier::ModifierEffect::BurningShieldActive {
+            mode: mk_types::modifier::BurningShieldMode::Destroy, ..
+        })));
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::DeclareBlock {
+            enemy_instance_id: CombatInstanceId::from("enemy_0"),
+            attack_index: 0,
+        },
+        epoch,
+    ).unwrap();
+    state
+}
+
+#[test]
+fn exploding_shield_card_destroys_blocked_enemy_and_is_consumed() {
+    // prowlers: 4 physical vs Fire Block 4
+    let state = block_with_exploding_shield("prowlers");
+    assert!(state.combat.as_ref().unwrap().enemies[0].is_defeated);
+    assert!(!state.active_modifiers.iter().any(|m|
+        matches!(&m.effect, mk_types::modifier::ModifierEffect::BurningShieldActive { .. })
+    ));
+}
+
+#[test]
+fn exploding_shield_card_spent_on_fire_resistant_enemy() {
+    // skeletal_warriors: 3 physical, fire resistant
+    let state = block_with_exploding_shield("skeletal_warriors");
+    let combat = state.combat.as_ref().unwrap();
+    assert!(combat.enemies[0].is_blocked);
+    assert!(!combat.enemies[0].is_defeated);
+    assert!(!state.active_modifiers.iter().any(|m|
+        matches!(&m.effect, mk_types::modifier::ModifierEffect::BurningShieldActive { .. })
+    ));
+}
+
+#[test]
+fn chill_powered_targets_the_chosen_enemy() {
+    let mut state = setup_combat_game(&["prowlers", "prowlers"]);
+    state.players[0].hand = vec![CardId::from("chill")];
+    state.source.dice.clear(); // pay from tokens only, no source choice
+    // Spell cost + black for the powered effect (night only)
+    state.time_of_day = TimeOfDay::Night;
+    for color in [ManaColor::Blue, ManaColor::Black] {
+        state.players[0].pure_mana.push(ManaToken {
+            color,
+            source: ManaTokenSource::Effect,
+            cannot_power_spells: false,
+        });
+    }
+    crate::card_play::play_card(&mut state, 0, 0, true, None).unwrap();
+    match &state.players[0].pending.active {
+        Some(ActivePending::SelectCombatEnemy { eligible_enemy_ids, .. }) => {
+            assert_eq!(eligible_enemy_ids.len(), 2);
+        }
+        other => panic!("expected SelectCombatEnemy, got {:?}", other),
+    }
+
+    let mut undo = UndoStack::new();
+    resolve_choice(&mut state, &mut undo, 1);
+    assert!(state.players[0].pending.active.is_none());
+
+    // Armor -4 lands on the second prowlers only
+    let armor_targets: Vec<&str> = state.active_modifiers.iter().filter_map(|m| {
+        match (&m.effect, &m.scope) {
+            (
+                mk_types::modifier::ModifierEffect::EnemyStat {
+                    stat: mk_types::modifier::EnemyStat::Armor, ..
+                },
+                mk_types::modifier::ModifierScope::OneEnemy { enemy_id },
+            ) => Some(enemy_id.as_str()),
+            _ => None,
+        }
+    }).collect();
+    assert_eq!(armor_targets, vec!["enemy_1"]);
+}
+
+#[test]
+fn polarization_token_cannot_pay_for_chill() {
+    let mut state = setup_combat_game(&["prowlers"]);
+    state.players[0].hand = vec![CardId::from("chill")];
+    state.source.dice.clear();
+    // Polarization's daytime black conversion marks the token unusable for spells.
+    state.players[0].pure_mana.push(ManaToken {
+        color: ManaColor::Blue,
+        source: ManaTokenSource::Effect,
+        cannot_power_spells: true,
+    });
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &UndoStack::new());
+    assert!(!legal.actions.iter().any(|a| matches!(a, LegalAction::PlayCardBasic { .. })));
+    assert!(matches!(
+        crate::card_play::play_card(&mut state, 0, 0, false, None),
+        Err(crate::card_play::CardPlayError::ManaSourceRequired)
+    ));
+
+    // An ordinary blue token pays instead, leaving the restricted one behind.
+    state.players[0].pure_mana.push(ManaToken {
+        color: ManaColor::Blue,
+        source: ManaTokenSource::Effect,
+        cannot_power_spells: false,
+    });
+    crate::card_play::play_card(&mut state, 0, 0, false, None).unwrap();
+    assert_eq!(state.players[0].pure_mana.len(), 1);
+    assert!(state.players[0].pure_mana[0].cannot_power_spells);
+}
+
 // =========================================================================
 // Rampaging enemy defeat: reputation bonus + type slot cleanup
 // =========================================================================
@@ -1633,6 +1971,75 @@ fn challenge_orc_marauder_defeat_grants_rep_plus_1() {
     );
 }
 
+#[test]
+fn end_attack_phase_leaves_optional_enemy_undefeated() {
+    let hex_coord = mk_types::hex::HexCoord { q: 1, r: 0 };
+    let (mut state, mut undo) = setup_rampaging_challenge(
+        RampagingEnemyType::OrcMarauder,
+        "prowlers",
+        EnemyColor::Green,
+        hex_coord,
+    );
+    for _ in 0..3 {
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    }
+    let combat = state.combat.as_ref().unwrap();
+    assert_eq!(combat.phase, CombatPhase::Attack);
+    assert!(!combat.enemies[0].is_required_for_conquest);
+
+    // Attacking is possible, but ending the phase is offered alongside it.
+    state.players[0].combat_accumulator.attack.normal_elements.physical = 10;
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(legal.actions.iter().any(|a| matches!(a, LegalAction::SubsetSelect { .. })));
+    assert!(legal.actions.contains(&LegalAction::EndCombatPhase));
+
+    let fame_before = state.players[0].fame;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, legal.epoch).unwrap();
+
+    assert!(state.combat.is_none(), "combat ends with the enemy still alive");
+    assert_eq!(state.players[0].fame, fame_before);
+    let hex = &state.map.hexes[&hex_coord.key()];
+    assert_eq!(hex.enemies.len(), 1, "surviving enemy stays on the map");
+    assert_eq!(hex.rampaging_enemies.len(), 1);
+}
+
+#[test]
+fn ending_challenge_without_acting_still_takes_unblocked_damage() {
+    let hex_coord = mk_types::hex::HexCoord { q: 1, r: 0 };
+    let (mut state, mut undo) = setup_rampaging_challenge(
+        RampagingEnemyType::OrcMarauder,
+        "prowlers",
+        EnemyColor::Green,
+        hex_coord,
+    );
+    let wounds = |state: &GameState| {
+        state.players[0].hand.iter().filter(|c| c.as_str() == "wound").count()
+    };
+    assert_eq!(wounds(&state), 0, "entering combat deals no damage by itself");
+
+    // RangedSiege → Block → AssignDamage → Attack → end, without playing anything
+    let mut phases = Vec::new();
+    while let Some(combat) = state.combat.as_ref() {
+        phases.push(combat.phase);
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    }
+    assert_eq!(
+        phases,
+        vec![
+            CombatPhase::RangedSiege,
+            CombatPhase::Block,
+            CombatPhase::AssignDamage,
+            CombatPhase::Attack,
+        ]
+    );
+
+    // Prowlers deal 4 physical unblocked, hero armor=2, ceil(4/2)=2 wounds
+    assert_eq!(wounds(&state), 2);
+    assert_eq!(state.map.hexes[&hex_coord.key()].enemies.len(), 1);
+}
+
 // =========================================================================
 // Defend ability — armor bonus persistence (FAQ S29)
 // =========================================================================
@@ -2538,3 +2945,85 @@ fn end_combat_phase_clears_declared_attack() {
     }
 }
 
+
+// =========================================================================
+// Altem Guardians — grant all resistances
+// =========================================================================
+
+fn setup_guardians_vs_fire_mages() -> (GameState, UndoStack) {
+    let mut state = setup_combat_game(&["fire_mages"]);
+    state.players[0].units.push(PlayerUnit {
+        instance_id: mk_types::ids::UnitInstanceId::from("unit_0"),
+        unit_id: mk_types::ids::UnitId::from("altem_guardians"),
+        level: 4,
+        state: UnitState::Ready,
+        wounded: false,
+        used_resistance_this_combat: false,
+        used_ability_indices: vec![],
+        mana_token: None,
+    });
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::ActivateUnit {
+            unit_instance_id: mk_types::ids::UnitInstanceId::from("unit_0"),
+            ability_index: 2, // Grant all resistances
+        },
+        epoch,
+    )
+    .unwrap();
+    state.combat.as_mut().unwrap().phase = CombatPhase::AssignDamage;
+    (state, undo)
+}
+
+fn assign_fire_mages_to_guardians(state: &mut GameState, undo: &mut UndoStack) -> bool {
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        state,
+        undo,
+        0,
+        &LegalAction::AssignDamageToUnit {
+            enemy_index: 0,
+            attack_index: 0,
+            unit_instance_id: mk_types::ids::UnitInstanceId::from("unit_0"),
+        },
+        epoch,
+    )
+    .unwrap();
+    state.players[0].units[0].wounded
+}
+
+#[test]
+fn grant_all_resistances_lets_unit_resist_fire_attack() {
+    use mk_types::modifier::{ModifierDuration, ModifierEffect, ModifierScope};
+
+    let (mut state, mut undo) = setup_guardians_vs_fire_mages();
+    let m = state
+        .active_modifiers
+        .iter()
+        .find(|m| matches!(m.effect, ModifierEffect::GrantResistances { .. }))
+        .expect("grant resistances modifier");
+    assert!(matches!(m.scope, ModifierScope::AllUnits));
+    assert_eq!(m.duration, ModifierDuration::Turn);
+    if let ModifierEffect::GrantResistances { resistances } = &m.effect {
+        for r in [ResistanceElement::Physical, ResistanceElement::Fire, ResistanceElement::Ice] {
+            assert!(resistances.contains(&r));
+        }
+    }
+
+    // Fire 6 is halved by the granted resistance to 3, within armor 4.
+    assert!(!assign_fire_mages_to_guardians(&mut state, &mut undo));
+    assert!(state.players[0].units[0].used_resistance_this_combat);
+}
+
+#[test]
+fn grant_all_resistances_ignores_other_players_modifier() {
+    let (mut state, mut undo) = setup_guardians_vs_fire_mages();
+    for m in &mut state.active_modifiers {
+        m.created_by_player_id = mk_types::ids::PlayerId::from("someone_else");
+    }
+    assert!(assign_fire_mages_to_guardians(&mut state, &mut undo));
+}
@@ -8,6 +8,28 @@ fn stale_epoch_rejected() {
     assert!(matches!(result, Err(ApplyError::StaleActionSet { .. })));
 }
 
+#[test]
+fn apply_or_refresh_retries_stale_action_that_is_still_legal() {
+    let mut state = setup_playing_game(vec!["march", "march"]);
+    let mut undo = UndoStack::new();
+    let march = LegalAction::PlayCardBasic { hand_index: 0, card_id: CardId::from("march") };
+    let stale_epoch = state.action_epoch;
+
+    let (_, refreshed) = apply_or_refresh(&mut state, &mut undo, 0, &march, stale_epoch).unwrap();
+    assert!(!refreshed);
+    assert_eq!(state.players[0].move_points, 2);
+
+    // Epoch moved on, but the second march is still at hand index 0
+    let (_, refreshed) = apply_or_refresh(&mut state, &mut undo, 0, &march, stale_epoch).unwrap();
+    assert!(refreshed);
+    assert_eq!(state.players[0].move_points, 4);
+
+    // No longer legal after refresh: the stale error is passed through
+    let result = apply_or_refresh(&mut state, &mut undo, 0, &march, stale_epoch);
+    assert!(matches!(result, Err(ApplyError::StaleActionSet { .. })));
+    assert_eq!(state.players[0].move_points, 4);
+}
+
 #[test]
 fn epoch_increments_after_action() {
     let mut state = setup_playing_game(vec!["march"]);
@@ -145,6 +167,148 @@ fn move_works() {
     }
 }
 
+/// Druidic Paths in hand, blue mana to power it, and a forest and a swamp hex
+/// next to the player.
+fn druidic_paths_board() -> (GameState, mk_types::hex::HexCoord, mk_types::hex::HexCoord) {
+    let mut state = setup_playing_game(vec!["braevalar_druidic_paths"]);
+    state.time_of_day = TimeOfDay::Day;
+    state.source.dice.clear();
+    state.players[0].pure_mana.push(ManaToken {
+        color: ManaColor::Blue,
+        source: ManaTokenSource::Effect,
+        cannot_power_spells: false,
+    });
+    let pos = state.players[0].position.unwrap();
+    let mut adjacent = pos.neighbors().into_iter()
+        .filter(|c| state.map.hexes.contains_key(&c.key()));
+    let forest = adjacent.next().expect("starting hex should have a neighbor");
+    let swamp = adjacent.next().expect("starting hex should have two neighbors");
+    state.map.hexes.get_mut(&forest.key()).unwrap().terrain = Terrain::Forest;
+    state.map.hexes.get_mut(&swamp.key()).unwrap().terrain = Terrain::Swamp;
+    (state, forest, swamp)
+}
+
+/// Play Druidic Paths powered and pick `terrain` for its cost reduction.
+fn druidic_paths_terrain_reduction(terrain: Terrain) -> (GameState, mk_types::hex::HexCoord, mk_types::hex::HexCoord) {
+    let (mut state, forest, swamp) = druidic_paths_board();
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::PlayCardPowered {
+            hand_index: 0,
+            card_id: CardId::from("braevalar_druidic_paths"),
+            mana_color: Some(BasicManaColor::Blue),
+        },
+        epoch,
+    )
+    .unwrap();
+    assert!(matches!(
+        state.players[0].pending.active,
+        Some(ActivePending::TerrainCostReduction(_))
+    ));
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::ResolveTerrainCostReduction { terrain },
+        epoch,
+    )
+    .unwrap();
+    assert!(state.players[0].pending.active.is_none());
+    (state, forest, swamp)
+}
+
+#[test]
+fn terrain_cost_reduction_for_forest_lasts_the_turn() {
+    let (state, forest, swamp) = druidic_paths_terrain_reduction(Terrain::Forest);
+    assert_eq!(state.players[0].move_points, 4);
+
+    // Forest 3 - 2 floors at 2; swamp is untouched.
+    assert_eq!(crate::movement::evaluate_move_entry(&state, 0, forest).cost, Some(2));
+    assert_eq!(crate::movement::evaluate_move_entry(&state, 0, swamp).cost, Some(5));
+    assert!(state.active_modifiers.iter().any(|m| matches!(
+        m.duration,
+        mk_types::modifier::ModifierDuration::Turn
+    )));
+}
+
+#[test]
+fn terrain_cost_reduction_reduces_rather_than_sets_cost() {
+    let (state, forest, swamp) = druidic_paths_terrain_reduction(Terrain::Swamp);
+
+    // Swamp 5 - 2 = 3, not forced down to the minimum of 2.
+    assert_eq!(crate::movement::evaluate_move_entry(&state, 0, swamp).cost, Some(3));
+    assert_eq!(crate::movement::evaluate_move_entry(&state, 0, forest).cost, Some(3));
+}
+
+#[test]
+fn hex_cost_reduction_reduces_rather_than_sets_cost() {
+    let (mut state, forest, swamp) = druidic_paths_board();
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::PlayCardBasic {
+            hand_index: 0,
+            card_id: CardId::from("braevalar_druidic_paths"),
+        },
+        epoch,
+    )
+    .unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::ResolveHexCostReduction { coordinate: swamp },
+        epoch,
+    )
+    .unwrap();
+    assert!(state.players[0].pending.active.is_none());
+
+    // The chosen hex's terrain (swamp) drops 5 - 2 = 3, not to the minimum of 2.
+    assert_eq!(crate::movement::evaluate_move_entry(&state, 0, swamp).cost, Some(3));
+    assert_eq!(crate::movement::evaluate_move_entry(&state, 0, forest).cost, Some(3));
+}
+
+#[test]
+fn chosen_move_applies_once_across_reenumeration() {
+    let mut state = setup_playing_game(vec!["tovak_instinct"]);
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::PlayCardBasic {
+            hand_index: 0,
+            card_id: CardId::from("tovak_instinct"),
+        },
+        epoch,
+    )
+    .unwrap();
+    assert_eq!(state.players[0].move_points, 0);
+
+    resolve_choice(&mut state, &mut undo, 0); // Move 2
+    assert!(state.players[0].pending.active.is_none());
+    assert_eq!(state.players[0].move_points, 2);
+
+    // Undoing the choice and picking Move again must not stack the move.
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::Undo, legal.epoch).unwrap();
+    assert!(state.players[0].pending.has_active());
+    assert_eq!(state.players[0].move_points, 0);
+    resolve_choice(&mut state, &mut undo, 0);
+    assert_eq!(state.players[0].move_points, 2);
+}
+
 #[test]
 fn declare_rest_sets_flag() {
     let mut state = setup_playing_game(vec!["march"]);
@@ -333,6 +497,92 @@ fn complete_rest_empty_hand_sets_flags() {
     assert!(state.players[0].discard.is_empty());
 }
 
+#[test]
+fn complete_rest_mixed_hand_requires_non_wound_discard() {
+    // Hand: wound, march → only the non-wound is offered; discarding the wound is rejected.
+    let mut state = setup_playing_game(vec!["wound", "march"]);
+    state.players[0].flags.insert(PlayerFlags::IS_RESTING);
+    let mut undo = UndoStack::new();
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let rest_options: Vec<_> = legal
+        .actions
+        .iter()
+        .filter(|a| matches!(a, LegalAction::CompleteRest { .. }))
+        .collect();
+    assert_eq!(
+        rest_options,
+        vec![&LegalAction::CompleteRest { discard_hand_index: Some(1) }]
+    );
+
+    let epoch = state.action_epoch;
+    let result = apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::CompleteRest { discard_hand_index: Some(0) },
+        epoch,
+    );
+    assert!(result.is_err());
+    assert_eq!(state.players[0].hand.len(), 2);
+    assert!(state.players[0].flags.contains(PlayerFlags::IS_RESTING));
+}
+
+#[test]
+fn complete_rest_without_discard_rejected_when_hand_not_empty() {
+    let mut state = setup_playing_game(vec!["march"]);
+    state.players[0].flags.insert(PlayerFlags::IS_RESTING);
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+
+    let result = apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::CompleteRest { discard_hand_index: None },
+        epoch,
+    );
+    assert!(result.is_err());
+    assert_eq!(state.players[0].hand.len(), 1);
+    assert!(!state.players[0].flags.contains(PlayerFlags::HAS_RESTED_THIS_TURN));
+}
+
+#[test]
+fn slow_recovery_rest_blocks_move_and_actions() {
+    let mut state = setup_playing_game(vec!["wound", "wound"]);
+    state.players[0].move_points = 5;
+    let mut undo = UndoStack::new();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::DeclareRest, epoch).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::CompleteRest { discard_hand_index: Some(1) },
+        epoch,
+    )
+    .unwrap();
+
+    // Exactly one wound discarded, nothing else
+    assert_eq!(state.players[0].hand.len(), 1);
+    assert_eq!(state.players[0].discard.len(), 1);
+    let flags = state.players[0].flags;
+    assert!(flags.contains(PlayerFlags::HAS_RESTED_THIS_TURN));
+    assert!(flags.contains(PlayerFlags::HAS_TAKEN_ACTION_THIS_TURN));
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(!legal.actions.iter().any(|a| matches!(
+        a,
+        LegalAction::Move { .. }
+            | LegalAction::Explore { .. }
+            | LegalAction::ChallengeRampaging { .. }
+            | LegalAction::DeclareRest
+    )));
+    assert!(legal.actions.contains(&LegalAction::EndTurn));
+}
+
 #[test]
 fn undo_complete_rest_restores_hand() {
     // DeclareRest + CompleteRest + SubsetSelect (wound), then undo all.
@@ -474,6 +724,71 @@ fn undo_restores_state() {
     assert_eq!(state.players[0].hand.len(), original_hand_len);
 }
 
+#[test]
+fn undo_advances_epoch_and_rejects_pre_undo_action_set() {
+    let mut state = setup_playing_game(vec!["march", "rage"]);
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    let march = LegalAction::PlayCardBasic { hand_index: 0, card_id: CardId::from("march") };
+    apply_legal_action(&mut state, &mut undo, 0, &march, epoch).unwrap();
+
+    let after_play = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::Undo, after_play).unwrap();
+    assert_eq!(state.action_epoch, after_play + 1);
+
+    // An action set enumerated before the undo is stale
+    let result = apply_legal_action(&mut state, &mut undo, 0, &march, after_play);
+    assert!(matches!(result, Err(ApplyError::StaleActionSet { .. })));
+}
+
+#[test]
+fn action_after_undo_succeeds_with_reenumerated_epoch() {
+    let mut state = setup_playing_game(vec!["march", "rage"]);
+    let mut undo = UndoStack::new();
+    let march = LegalAction::PlayCardBasic { hand_index: 0, card_id: CardId::from("march") };
+    let start = state.action_epoch;
+
+    apply_legal_action(&mut state, &mut undo, 0, &march, start).unwrap();
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(legal.actions.contains(&LegalAction::Undo));
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::Undo, legal.epoch).unwrap();
+    assert!(state.action_epoch > legal.epoch, "undo must not rewind the epoch");
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert_eq!(legal.epoch, state.action_epoch);
+    assert!(legal.actions.contains(&march));
+    apply_legal_action(&mut state, &mut undo, 0, &march, legal.epoch).unwrap();
+    assert_eq!(state.action_epoch, start + 3);
+    assert_eq!(state.players[0].move_points, 2);
+}
+
+#[test]
+fn undo_to_checkpoint_rewinds_whole_reversible_sequence() {
+    let mut state = setup_playing_game(vec!["march", "rage", "swiftness", "stamina"]);
+    let mut undo = UndoStack::new();
+    let march = LegalAction::PlayCardBasic { hand_index: 0, card_id: CardId::from("march") };
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &march, epoch).unwrap();
+    undo.set_checkpoint();
+    let mut before = serde_json::to_value(&state).unwrap();
+
+    for _ in 0..3 {
+        let card_id = state.players[0].hand[0].clone();
+        let sideways = LegalAction::PlayCardSideways { hand_index: 0, card_id, sideways_as: SidewaysAs::Move };
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &sideways, epoch).unwrap();
+    }
+    assert_eq!(undo.depth(), 3);
+    assert!(state.players[0].hand.is_empty());
+
+    let epoch = state.action_epoch;
+    assert!(super::undo_to_checkpoint(&mut state, &mut undo));
+    assert_eq!(state.action_epoch, epoch + 1, "rewinding still advances the epoch");
+    before["action_epoch"] = serde_json::json!(state.action_epoch);
+    assert_eq!(serde_json::to_value(&state).unwrap(), before);
+    assert!(!undo.can_undo(), "nothing left to undo past the checkpoint");
+}
+
 #[test]
 fn tranquility_draw_after_basic_play_clears_undo_stack() {
     // Tranquility basic: heal vs draw. With no wounds, only draw resolves and
@@ -581,3 +896,162 @@ fn blood_powered_choice_events_have_descriptions() {
     }
 }
 
+
+#[test]
+fn powered_meditation_places_three_distinct_picks_exactly_once() {
+    use mk_types::pending::{MeditationPhase, PendingMeditation};
+
+    let mut state = setup_playing_game(vec!["march"]);
+    let mut undo = UndoStack::new();
+    state.players[0].deck = vec![CardId::from("concentration")];
+    state.players[0].discard = vec![
+        CardId::from("march"),
+        CardId::from("rage"),
+        CardId::from("wound"),
+        CardId::from("stamina"),
+        CardId::from("swiftness"),
+    ];
+    state.players[0].pending.active = Some(ActivePending::Meditation(PendingMeditation {
+        version: EffectMode::Powered,
+        phase: MeditationPhase::SelectCards,
+        selected_card_ids: vec![],
+        selected_discard_indices: vec![],
+    }));
+
+    let select = |state: &mut GameState, undo: &mut UndoStack, idx: usize| {
+        let epoch = state.action_epoch;
+        apply_legal_action(
+            state,
+            undo,
+            0,
+            &LegalAction::ResolveMeditation { selection_index: idx, place_on_top: None },
+            epoch,
+        )
+    };
+
+    select(&mut state, &mut undo, 1).unwrap(); // rage
+    assert!(select(&mut state, &mut undo, 1).is_err(), "same card twice");
+    assert!(select(&mut state, &mut undo, 2).is_err(), "wound");
+    select(&mut state, &mut undo, 4).unwrap(); // swiftness
+    select(&mut state, &mut undo, 0).unwrap(); // march — third pick switches phase
+
+    match &state.players[0].pending.active {
+        Some(ActivePending::Meditation(m)) => {
+            assert_eq!(m.phase, MeditationPhase::PlaceCards);
+            assert_eq!(m.selected_card_ids.len(), 3);
+        }
+        other => panic!("Expected meditation placement, got {:?}", other),
+    }
+
+    // Place rage on top, swiftness on the bottom, march on top.
+    for on_top in [true, false, true] {
+        let epoch = state.action_epoch;
+        apply_legal_action(
+            &mut state,
+            &mut undo,
+            0,
+            &LegalAction::ResolveMeditation { selection_index: 0, place_on_top: Some(on_top) },
+            epoch,
+        )
+        .unwrap();
+    }
+
+    let p = &state.players[0];
+    assert!(p.pending.active.is_none());
+    assert_eq!(
+        p.deck,
+        vec![
+            CardId::from("march"),
+            CardId::from("rage"),
+            CardId::from("concentration"),
+            CardId::from("swiftness"),
+        ]
+    );
+    assert_eq!(p.discard, vec![CardId::from("wound"), CardId::from("stamina")]);
+    assert_eq!(p.meditation_hand_limit_bonus, 1);
+}
+
+#[test]
+fn powered_meditation_can_pick_two_copies_of_the_same_card() {
+    use mk_types::pending::{MeditationPhase, PendingMeditation};
+
+    let mut state = setup_playing_game(vec!["march"]);
+    let mut undo = UndoStack::new();
+    state.players[0].deck = Vec::new();
+    state.players[0].discard = vec![CardId::from("march"), CardId::from("rage"), CardId::from("march")];
+    state.players[0].pending.active = Some(ActivePending::Meditation(PendingMeditation {
+        version: EffectMode::Powered,
+        phase: MeditationPhase::SelectCards,
+        selected_card_ids: vec![],
+        selected_discard_indices: vec![],
+    }));
+    let select = |state: &mut GameState, undo: &mut UndoStack, idx: usize| {
+        let epoch = state.action_epoch;
+        let action = LegalAction::ResolveMeditation { selection_index: idx, place_on_top: None };
+        apply_legal_action(state, undo, 0, &action, epoch)
+    };
+
+    select(&mut state, &mut undo, 0).unwrap();
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(legal
+        .actions
+        .contains(&LegalAction::ResolveMeditation { selection_index: 2, place_on_top: None }));
+    assert!(!legal
+        .actions
+        .contains(&LegalAction::ResolveMeditation { selection_index: 0, place_on_top: None }));
+    assert!(select(&mut state, &mut undo, 0).is_err(), "same discard index twice");
+    select(&mut state, &mut undo, 2).unwrap();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::MeditationDoneSelecting, epoch).unwrap();
+    for _ in 0..2 {
+        let epoch = state.action_epoch;
+        let place = LegalAction::ResolveMeditation { selection_index: 0, place_on_top: Some(true) };
+        apply_legal_action(&mut state, &mut undo, 0, &place, epoch).unwrap();
+    }
+
+    assert_eq!(state.players[0].deck, vec![CardId::from("march"), CardId::from("march")]);
+    assert_eq!(state.players[0].discard, vec![CardId::from("rage")]);
+}
+
+#[test]
+fn unknown_ids_yield_internal_errors_naming_the_id() {
+    let error_for = |state: &mut GameState, action: LegalAction| {
+        let mut undo = UndoStack::new();
+        let epoch = state.action_epoch;
+        match apply_legal_action(state, &mut undo, 0, &action, epoch) {
+            Err(ApplyError::InternalError(msg)) => msg,
+            other => panic!("expected InternalError for {action:?}, got {other:?}"),
+        }
+    };
+
+    let mut state = setup_playing_game(vec!["bogus_card"]);
+    let msg = error_for(&mut state, LegalAction::PlayCardBasic {
+        hand_index: 0,
+        card_id: CardId::from("bogus_card"),
+    });
+    assert!(msg.contains("bogus_card"), "{msg}");
+    assert_eq!(state.players[0].hand, vec![CardId::from("bogus_card")], "hand untouched");
+
+    let mut state = setup_playing_game(vec!["march"]);
+    state.offers.units = vec![UnitId::from("bogus_unit")];
+    state.players[0].influence_points = 5;
+    let msg = error_for(&mut state, LegalAction::RecruitUnit {
+        unit_id: UnitId::from("bogus_unit"),
+        offer_index: 0,
+        influence_cost: 3,
+    });
+    assert!(msg.contains("bogus_unit"), "{msg}");
+    assert!(state.players[0].units.is_empty());
+    assert_eq!(state.players[0].influence_points, 5, "influence untouched");
+
+    let mut state = setup_combat_game(&["prowlers"]);
+    let combat = state.combat.as_mut().unwrap();
+    combat.phase = CombatPhase::Block;
+    combat.enemies[0].enemy_id = mk_types::ids::EnemyId::from("bogus_enemy");
+    let msg = error_for(&mut state, LegalAction::DeclareBlock {
+        enemy_instance_id: CombatInstanceId::from("enemy_0"),
+        attack_index: 0,
+    });
+    assert!(msg.contains("bogus_enemy"), "{msg}");
+}
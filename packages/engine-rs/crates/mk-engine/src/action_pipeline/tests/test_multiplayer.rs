@@ -96,6 +96,39 @@ fn multiplayer_turn_order_sorted_by_tactic() {
     assert_eq!(state.turn_order[1].as_str(), "player_0");
 }
 
+#[test]
+fn multiplayer_turn_order_tie_breaks_on_player_id() {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    let mut undo = UndoStack::new();
+
+    let p1_idx = state.players.iter().position(|p| p.id.as_str() == "player_1").unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, p1_idx,
+        &LegalAction::SelectTactic { tactic_id: TacticId::from("early_bird") },
+        epoch,
+    ).unwrap();
+
+    // Force an identical tactic number (variant config) and a reversed prior order
+    state.players[p1_idx].selected_tactic = Some(TacticId::from("planning"));
+    state.turn_order = vec![
+        mk_types::ids::PlayerId::from("player_1"),
+        mk_types::ids::PlayerId::from("player_0"),
+    ];
+
+    let p0_idx = state.players.iter().position(|p| p.id.as_str() == "player_0").unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, p0_idx,
+        &LegalAction::SelectTactic { tactic_id: TacticId::from("planning") },
+        epoch,
+    ).unwrap();
+
+    // Equal tactic numbers → ordered by player id
+    assert_eq!(state.turn_order[0].as_str(), "player_0");
+    assert_eq!(state.turn_order[1].as_str(), "player_1");
+}
+
 #[test]
 fn multiplayer_announce_end_of_round() {
     let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
@@ -140,6 +173,72 @@ fn multiplayer_announce_end_of_round() {
     assert_eq!(state.players_with_final_turn.len(), 1); // Other player gets final turn
 }
 
+/// Play the first sideways-playable card and end the turn via legal actions.
+fn play_sideways_and_end_turn(state: &mut GameState, undo: &mut UndoStack, player_idx: usize) {
+    let legal = enumerate_legal_actions_with_undo(state, player_idx, undo);
+    let play = legal
+        .actions
+        .iter()
+        .find(|a| matches!(a, LegalAction::PlayCardSideways { .. }))
+        .cloned()
+        .expect("should have a sideways play");
+    apply_legal_action(state, undo, player_idx, &play, legal.epoch).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(state, undo, player_idx, &LegalAction::EndTurn, epoch).unwrap();
+}
+
+#[test]
+fn multiplayer_round_transition_after_final_turns() {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    setup_player_turns(&mut state);
+    let mut undo = UndoStack::new();
+
+    assert_eq!(state.round, 1);
+    assert_eq!(state.time_of_day, TimeOfDay::Day);
+
+    let first_idx = first_player_idx(&state);
+    let second_idx = 1 - first_idx;
+    state.players[first_idx].deck.clear();
+
+    // First player announces, then finishes their turn
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, first_idx,
+        &LegalAction::AnnounceEndOfRound,
+        epoch,
+    ).unwrap();
+    // Mark a die as held so the reset is observable
+    state.source.dice[0].taken_by_player_id = Some(state.players[first_idx].id.clone());
+    play_sideways_and_end_turn(&mut state, &mut undo, first_idx);
+    assert_eq!(state.round, 1, "round continues until final turns are taken");
+    assert_eq!(state.players_with_final_turn, vec![state.players[second_idx].id.clone()]);
+
+    // Second player takes their final turn → round ends
+    let rng_before = state.rng.counter;
+    play_sideways_and_end_turn(&mut state, &mut undo, second_idx);
+
+    assert_eq!(state.round, 2);
+    assert_eq!(state.time_of_day, TimeOfDay::Night);
+    assert_eq!(state.round_phase, RoundPhase::TacticsSelection);
+    assert!(state.end_of_round_announced_by.is_none());
+    assert!(state.players_with_final_turn.is_empty());
+
+    // Source fully re-rolled for night: fresh dice, gold depleted
+    assert!(state.rng.counter > rng_before, "source re-roll consumes RNG");
+    assert_eq!(state.source.dice.len(), 4);
+    for die in &state.source.dice {
+        assert!(die.taken_by_player_id.is_none());
+        if die.color == ManaColor::Gold {
+            assert!(die.is_depleted);
+        }
+    }
+
+    // Tactics returned: night set available, none held
+    assert!(state.players.iter().all(|p| p.selected_tactic.is_none()));
+    assert!(state.available_tactics.iter().any(|t| t.as_str() == "from_the_dusk"));
+    assert!(state.current_tactic_selector.is_some());
+}
+
 #[test]
 fn multiplayer_announce_end_of_round_enumeration() {
     let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
@@ -266,6 +365,77 @@ fn multiplayer_mana_steal_from_source() {
     assert!(state.players[p1_idx].tactic_state.stored_mana_die.is_some());
 }
 
+/// Helper: player 1 stores a die via Mana Steal, then player 0 (first to act)
+/// is given a Mana Steal decision of their own. Returns (p0, p1, die index).
+fn setup_mana_steal_contest(state: &mut GameState, undo: &mut UndoStack) -> (usize, usize, usize) {
+    let p0_idx = state.players.iter().position(|p| p.id.as_str() == "player_0").unwrap();
+    let p1_idx = state.players.iter().position(|p| p.id.as_str() == "player_1").unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        state, undo, p1_idx,
+        &LegalAction::SelectTactic { tactic_id: TacticId::from("mana_steal") },
+        epoch,
+    ).unwrap();
+    let die_idx = state.source.dice.iter().position(|d| !d.is_depleted && d.taken_by_player_id.is_none() && d.color.is_basic()).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        state, undo, p1_idx,
+        &LegalAction::ResolveTacticDecision { data: TacticDecisionData::ManaSteal { die_index: die_idx } },
+        epoch,
+    ).unwrap();
+
+    // Player 0 picks Early Bird and goes first.
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        state, undo, p0_idx,
+        &LegalAction::SelectTactic { tactic_id: TacticId::from("early_bird") },
+        epoch,
+    ).unwrap();
+
+    state.players[p0_idx].pending.active =
+        Some(ActivePending::TacticDecision(PendingTacticDecision::ManaSteal));
+    (p0_idx, p1_idx, die_idx)
+}
+
+#[test]
+fn multiplayer_mana_steal_takes_die_from_other_player() {
+    let mut state = crate::setup::create_two_player_game(100, Hero::Arythea, Hero::Tovak);
+    let mut undo = UndoStack::new();
+    let (p0_idx, p1_idx, die_idx) = setup_mana_steal_contest(&mut state, &mut undo);
+
+    let legal = enumerate_legal_actions_with_undo(&state, p0_idx, &undo);
+    let steal = LegalAction::ResolveTacticDecision { data: TacticDecisionData::ManaSteal { die_index: die_idx } };
+    assert!(legal.actions.contains(&steal), "other player's unspent die can be stolen");
+    apply_legal_action(&mut state, &mut undo, p0_idx, &steal, legal.epoch).unwrap();
+
+    let die = &state.source.dice[die_idx];
+    assert_eq!(die.taken_by_player_id.as_ref().unwrap().as_str(), "player_0");
+    assert!(state.players[p1_idx].tactic_state.stored_mana_die.is_none());
+    assert_eq!(state.players[p0_idx].tactic_state.stored_mana_die.as_ref().unwrap().die_id, die.id);
+}
+
+#[test]
+fn multiplayer_mana_steal_rejects_spent_die() {
+    let mut state = crate::setup::create_two_player_game(100, Hero::Arythea, Hero::Tovak);
+    let mut undo = UndoStack::new();
+    let (p0_idx, p1_idx, die_idx) = setup_mana_steal_contest(&mut state, &mut undo);
+    state.players[p1_idx].tactic_state.mana_steal_used_this_turn = true;
+
+    let legal = enumerate_legal_actions_with_undo(&state, p0_idx, &undo);
+    let steal = LegalAction::ResolveTacticDecision { data: TacticDecisionData::ManaSteal { die_index: die_idx } };
+    assert!(!legal.actions.contains(&steal), "spent die is not offered");
+
+    let result = super::tactics::apply_resolve_tactic_decision(
+        &mut state,
+        p0_idx,
+        &TacticDecisionData::ManaSteal { die_index: die_idx },
+    );
+    assert!(result.is_err());
+    assert_eq!(state.source.dice[die_idx].taken_by_player_id.as_ref().unwrap().as_str(), "player_1");
+    assert!(state.players[p1_idx].tactic_state.stored_mana_die.is_some());
+    assert!(state.players[p0_idx].tactic_state.stored_mana_die.is_none());
+}
+
 #[test]
 fn multiplayer_three_player_tactic_selection() {
     let config = mk_data::scenarios::first_reconnaissance_3p();
@@ -370,6 +540,93 @@ fn announce_available_with_empty_deck_nonempty_hand() {
     );
 }
 
+#[test]
+fn announce_not_legal_after_playing_card() {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    setup_player_turns(&mut state);
+
+    let first_idx = first_player_idx(&state);
+    state.players[first_idx].deck.clear();
+    let mut undo = UndoStack::new();
+
+    let legal = enumerate_legal_actions_with_undo(&state, first_idx, &undo);
+    let play = legal
+        .actions
+        .iter()
+        .find(|a| matches!(a, LegalAction::PlayCardBasic { .. }))
+        .cloned()
+        .expect("starting hand should have a playable card");
+    apply_legal_action(&mut state, &mut undo, first_idx, &play, legal.epoch).unwrap();
+    assert!(!state.players[first_idx].hand.is_empty());
+
+    let legal = enumerate_legal_actions_with_undo(&state, first_idx, &undo);
+    assert!(
+        !legal.actions.iter().any(|a| matches!(a, LegalAction::AnnounceEndOfRound)),
+        "AnnounceEndOfRound should NOT be available after playing a card"
+    );
+}
+
+#[test]
+fn announce_not_legal_after_moving_acting_or_resting() {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    setup_player_turns(&mut state);
+    let first_idx = first_player_idx(&state);
+    state.players[first_idx].deck.clear();
+    let can_announce = |state: &GameState| {
+        enumerate_legal_actions_with_undo(state, first_idx, &UndoStack::new())
+            .actions
+            .contains(&LegalAction::AnnounceEndOfRound)
+    };
+    assert!(can_announce(&state));
+
+    for flag in [PlayerFlags::HAS_MOVED_THIS_TURN, PlayerFlags::HAS_TAKEN_ACTION_THIS_TURN] {
+        let mut acted = state.clone();
+        acted.players[first_idx].flags.insert(flag);
+        assert!(!can_announce(&acted), "announce after {flag:?}");
+    }
+
+    // Resting is the turn's action: blocked both mid-rest and after completing it.
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, first_idx, &LegalAction::DeclareRest, epoch).unwrap();
+    assert!(!can_announce(&state), "announce while resting");
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, first_idx,
+        &LegalAction::CompleteRest { discard_hand_index: Some(0) },
+        epoch,
+    ).unwrap();
+    assert!(!can_announce(&state), "announce after resting");
+}
+
+#[test]
+fn second_announce_cannot_override_first() {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    setup_player_turns(&mut state);
+
+    let first_idx = first_player_idx(&state);
+    let second_idx = 1 - first_idx;
+    state.players[first_idx].deck.clear();
+    let mut undo = UndoStack::new();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, first_idx,
+        &LegalAction::AnnounceEndOfRound,
+        epoch,
+    ).unwrap();
+    let first_id = state.players[first_idx].id.clone();
+
+    let epoch = state.action_epoch;
+    let result = apply_legal_action(
+        &mut state, &mut undo, second_idx,
+        &LegalAction::AnnounceEndOfRound,
+        epoch,
+    );
+    assert!(result.is_err());
+    assert_eq!(state.end_of_round_announced_by, Some(first_id));
+}
+
 #[test]
 fn must_announce_when_hand_and_deck_empty() {
     let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
@@ -421,6 +678,50 @@ fn first_player_idx(state: &GameState) -> usize {
     state.players.iter().position(|p| &p.id == first_id).unwrap()
 }
 
+#[test]
+fn the_right_moment_grants_extra_turn_in_multiplayer() {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    let mut undo = UndoStack::new();
+
+    let p1_idx = state.players.iter().position(|p| p.id.as_str() == "player_1").unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, p1_idx,
+        &LegalAction::SelectTactic { tactic_id: TacticId::from("early_bird") },
+        epoch,
+    ).unwrap();
+    let p0_idx = state.players.iter().position(|p| p.id.as_str() == "player_0").unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, p0_idx,
+        &LegalAction::SelectTactic { tactic_id: TacticId::from("the_right_moment") },
+        epoch,
+    ).unwrap();
+
+    // Player 1 (early bird) goes first, then player 0.
+    play_sideways_and_end_turn(&mut state, &mut undo, p1_idx);
+    assert_eq!(state.turn_order[state.current_player_index as usize].as_str(), "player_0");
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, p0_idx, &LegalAction::ActivateTactic, epoch).unwrap();
+    let turn_before = state.turn_number;
+    play_sideways_and_end_turn(&mut state, &mut undo, p0_idx);
+
+    // Same player is active again for a fresh turn, with the tactic spent.
+    assert_eq!(state.turn_order[state.current_player_index as usize].as_str(), "player_0");
+    assert_eq!(state.turn_number, turn_before + 1);
+    let player = &state.players[p0_idx];
+    assert!(player.flags.contains(PlayerFlags::TACTIC_FLIPPED));
+    assert!(!player.tactic_state.extra_turn_pending);
+    assert!(!player.flags.contains(PlayerFlags::PLAYED_CARD_FROM_HAND_THIS_TURN));
+    let legal = enumerate_legal_actions_with_undo(&state, p0_idx, &undo);
+    assert!(!legal.actions.contains(&LegalAction::ActivateTactic));
+
+    // After the extra turn, play passes back to player 1.
+    play_sideways_and_end_turn(&mut state, &mut undo, p0_idx);
+    assert_eq!(state.turn_order[state.current_player_index as usize].as_str(), "player_1");
+}
+
 // =========================================================================
 // Step 5: Flipped Round-Order Token Skips Turn
 // =========================================================================
@@ -680,7 +981,7 @@ fn assault_other_player_keep_half_fame_on_victory() {
     assert!(state.combat.is_none(), "Combat should have ended");
 
     // Fame should be half (rounded up)
-    let expected_fame = (full_fame + 1) / 2;
+    let expected_fame = full_fame.div_ceil(2);
     let actual_fame = state.players[0].fame - fame_before;
     assert_eq!(
         actual_fame, expected_fame,
@@ -942,3 +1243,163 @@ fn forfeit_removes_from_final_turn_list() {
     );
 }
 
+
+// =========================================================================
+// Cooperative assault spoils
+// =========================================================================
+
+/// Two-player cooperative assault on a Blue city: player 0 fights one
+/// defender, player 1 is assigned the other two.
+fn start_cooperative_city_assault() -> (GameState, HexCoord) {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    setup_player_turns(&mut state);
+    state.current_player_index =
+        state.turn_order.iter().position(|id| id.as_str() == "player_0").unwrap() as u32;
+
+    let city_pos = HexCoord::new(2, 0);
+    let garrison = ["guardsmen_1", "prowlers_1", "swordsmen_1"];
+    state.map.hexes.insert(city_pos.key(), HexState {
+        coord: city_pos,
+        terrain: Terrain::Plains,
+        tile_id: TileId::StartingA,
+        site: Some(Site {
+            site_type: SiteType::City,
+            owner: None,
+            is_conquered: false,
+            is_burned: false,
+            city_color: Some(BasicManaColor::Blue),
+            mine_color: None,
+            deep_mine_colors: None,
+        }),
+        enemies: garrison
+            .iter()
+            .map(|token| HexEnemy {
+                token_id: EnemyTokenId::from(*token),
+                color: EnemyColor::Gray,
+                is_revealed: true,
+            })
+            .collect(),
+        rampaging_enemies: ArrayVec::new(),
+        shield_tokens: Vec::new(),
+        ruins_token: None,
+    });
+    let neighbors = city_pos.neighbors();
+    state.players[0].position = Some(neighbors[0]);
+    state.players[1].position = Some(neighbors[1]);
+
+    // Player 0 takes one defender, player 1 takes the other two.
+    crate::cooperative_assault::apply_propose(&mut state, 0, city_pos, &[1], &[(0, 1), (1, 2)]).unwrap();
+    assert!(crate::cooperative_assault::apply_respond(&mut state, 1, true).unwrap());
+
+    (state, city_pos)
+}
+
+#[test]
+fn cooperative_assault_splits_fame_and_shields_per_distribution() {
+    let (mut state, city_pos) = start_cooperative_city_assault();
+    let p1_hand_before = state.players[1].hand.clone();
+
+    // The combatant's +1 fame per enemy bonus travels with each enemy's spoils.
+    let pid = state.players[0].id.clone();
+    state.active_modifiers.push(mk_types::modifier::ActiveModifier {
+        id: mk_types::ids::ModifierId::from("fame_bonus"),
+        source: mk_types::modifier::ModifierSource::Card {
+            card_id: CardId::from("banner_of_glory"),
+            player_id: pid.clone(),
+        },
+        duration: mk_types::modifier::ModifierDuration::Combat,
+        scope: mk_types::modifier::ModifierScope::AllUnits,
+        effect: mk_types::modifier::ModifierEffect::FamePerEnemyDefeated {
+            fame_per_enemy: 1,
+            exclude_summoned: false,
+        },
+        created_at_round: 1,
+        created_by_player_id: pid,
+    });
+
+    let assignments = state.combat.as_ref().unwrap().enemy_assignments.clone().unwrap();
+    let fame_of = |owner: &str, state: &GameState| -> u32 {
+        let combat = state.combat.as_ref().unwrap();
+        assignments[owner]
+            .iter()
+            .map(|instance| {
+                let enemy = combat.enemies.iter().find(|e| e.instance_id.as_str() == instance).unwrap();
+                mk_data::enemies::get_enemy(enemy.enemy_id.as_str()).unwrap().fame + 1
+            })
+            .sum()
+    };
+    let p0_share = fame_of("player_0", &state);
+    let p1_share = fame_of("player_1", &state);
+    let p0_fame_before = state.players[0].fame;
+    let p1_fame_before = state.players[1].fame;
+
+    // Every defender falls to one overwhelming attack. Target selection only
+    // offers the combatant's own enemies, so declare the whole garrison
+    // directly and let ResolveAttack credit the fame.
+    {
+        let combat = state.combat.as_mut().unwrap();
+        combat.phase = CombatPhase::Attack;
+        combat.declared_attack_targets =
+            Some(combat.enemies.iter().map(|e| e.instance_id.clone()).collect());
+        combat.declared_attack_type = Some(CombatType::Melee);
+    }
+    let acc = &mut state.players[0].combat_accumulator.attack;
+    acc.normal = 100;
+    acc.normal_elements.physical = 100;
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveAttack, epoch).unwrap();
+    assert!(state.combat.as_ref().unwrap().enemies.iter().all(|e| e.is_defeated));
+    assert_eq!(state.combat.as_ref().unwrap().fame_gained, p0_share + p1_share);
+
+    let mut settled = state.clone();
+    crate::cooperative_assault::settle_spoils(&mut settled, 0);
+    assert_eq!(settled.combat.as_ref().unwrap().fame_gained, p0_share);
+
+    super::combat_end::end_combat(&mut state, 0);
+
+    assert_eq!(state.players[0].fame - p0_fame_before, p0_share);
+    assert_eq!(state.players[1].fame - p1_fame_before, p1_share);
+
+    let hex = state.map.hexes.get(&city_pos.key()).unwrap();
+    let site = hex.site.as_ref().unwrap();
+    assert!(site.is_conquered);
+    assert_eq!(
+        site.owner.as_ref().unwrap().as_str(), "player_1",
+        "participant with the most defeated enemies leads the city"
+    );
+    let shields_of = |id: &str| hex.shield_tokens.iter().filter(|s| s.as_str() == id).count();
+    assert_eq!(shields_of("player_0"), 1);
+    assert_eq!(shields_of("player_1"), 2);
+
+    // Only the initiator fought on their turn; the invitee's cards are untouched.
+    assert_eq!(state.players[1].hand, p1_hand_before);
+}
+
+#[test]
+fn cooperative_assault_moves_fame_for_enemy_defeated_by_select_enemy_effect() {
+    let (mut state, _) = start_cooperative_city_assault();
+    let assignments = state.combat.as_ref().unwrap().enemy_assignments.clone().unwrap();
+    let target = assignments["player_1"][0].clone();
+    let fame = {
+        let combat = state.combat.as_ref().unwrap();
+        let enemy = combat.enemies.iter().find(|e| e.instance_id.as_str() == target).unwrap();
+        mk_data::enemies::get_enemy(enemy.enemy_id.as_str()).unwrap().fame
+    };
+    let p0_fame_before = state.players[0].fame;
+    let p1_fame_before = state.players[1].fame;
+
+    // Defeat player 1's enemy outright (Whirlwind-style "defeat" template)
+    let template = mk_types::pending::SelectEnemyTemplate {
+        defeat: true,
+        ..mk_types::pending::SelectEnemyTemplate::new()
+    };
+    super::super::units::apply_select_enemy_effects(&mut state, 0, &None, &target, &template).unwrap();
+    assert_eq!(state.players[0].fame - p0_fame_before, fame);
+    assert_eq!(state.combat.as_ref().unwrap().fame_gained, fame);
+
+    crate::cooperative_assault::settle_spoils(&mut state, 0);
+    assert_eq!(state.players[0].fame, p0_fame_before);
+    assert_eq!(state.players[1].fame - p1_fame_before, fame);
+    assert_eq!(state.combat.as_ref().unwrap().fame_gained, 0);
+}
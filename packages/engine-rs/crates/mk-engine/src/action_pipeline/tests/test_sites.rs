@@ -218,6 +218,80 @@ fn conquest_marks_site() {
     assert!(hex.enemies.is_empty());
 }
 
+#[test]
+fn puppet_master_keep_waits_for_dungeon_reward() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_puppet_master");
+    state.players[0].hand = vec![CardId::from("march")];
+    state.decks.artifact_deck = vec![
+        CardId::from("banner_of_command"),
+        CardId::from("ring_of_flame"),
+    ];
+    place_player_on_site(&mut state, SiteType::Dungeon);
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EnterSite, epoch).unwrap();
+    let combat = state.combat.as_mut().unwrap();
+    for enemy in combat.enemies.iter_mut() {
+        enemy.is_defeated = true;
+    }
+    combat.phase = CombatPhase::Attack;
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+
+    // The dungeon reward takes the active slot; the keep offer waits behind it.
+    let is_keep_offer = |state: &GameState| matches!(
+        &state.players[0].pending.active,
+        Some(ActivePending::Choice(c))
+            if matches!(c.resolution, mk_types::pending::ChoiceResolution::PuppetMasterKeepToken { .. })
+    );
+    assert!(state.players[0].pending.has_active());
+    assert!(!is_keep_offer(&state));
+    assert!(state.players[0].pending.deferred.iter()
+        .any(|d| matches!(d, DeferredPending::PuppetMasterKeep { .. })));
+
+    for _ in 0..5 {
+        if is_keep_offer(&state) {
+            break;
+        }
+        let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+        apply_legal_action(&mut state, &mut undo, 0, &legal.actions[0], legal.epoch).unwrap();
+    }
+    assert!(is_keep_offer(&state), "keep offer promoted once the reward is resolved");
+    assert!(state.players[0].pending.deferred.is_empty());
+}
+
+#[test]
+fn puppet_master_keep_promoted_after_non_reward_pending() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_puppet_master");
+    state.players[0].pending.active = Some(ActivePending::Choice(mk_types::pending::PendingChoice {
+        card_id: None,
+        skill_id: None,
+        unit_instance_id: None,
+        options: vec![mk_types::effect::CardEffect::Noop, mk_types::effect::CardEffect::Noop],
+        continuation: vec![],
+        movement_bonus_applied: false,
+        resolution: mk_types::pending::ChoiceResolution::Standard,
+    }));
+    super::super::skills_complex::offer_puppet_master_keep(
+        &mut state, 0, vec![mk_types::ids::EnemyId::from("prowlers")],
+    );
+    assert!(state.players[0].pending.deferred.iter()
+        .any(|d| matches!(d, DeferredPending::PuppetMasterKeep { .. })));
+
+    // Resolving the unrelated choice frees the slot for the keep offer this turn
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 0 },
+        epoch,
+    ).unwrap();
+    assert!(matches!(
+        &state.players[0].pending.active,
+        Some(ActivePending::Choice(c))
+            if matches!(c.resolution, mk_types::pending::ChoiceResolution::PuppetMasterKeepToken { .. })
+    ));
+    assert!(state.players[0].pending.deferred.is_empty());
+}
+
 // --- InteractSite tests ---
 
 #[test]
@@ -341,6 +415,33 @@ fn keep_only_when_conquered() {
     );
 }
 
+#[test]
+fn keep_recruitment_is_owner_only() {
+    let mut state = setup_playing_game(vec!["march"]);
+    let coord = place_player_on_site(&mut state, SiteType::Keep);
+    let site = state.map.hexes.get_mut(&coord.key()).unwrap().site.as_mut().unwrap();
+    site.is_conquered = true;
+    site.owner = Some(mk_types::ids::PlayerId::from("player_1"));
+    state.players[0].reputation = 0;
+    state.players[0].influence_points = 10;
+    state.offers.units = vec![mk_types::ids::UnitId::from("scouts")];
+    state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+
+    let actions = enumerate_legal_actions_with_undo(&state, 0, &UndoStack::new());
+    assert!(
+        !actions.actions.iter().any(|a| matches!(a, LegalAction::RecruitUnit { .. })),
+        "Someone else's keep offers no recruitment"
+    );
+
+    let pid = state.players[0].id.clone();
+    state.map.hexes.get_mut(&coord.key()).unwrap().site.as_mut().unwrap().owner = Some(pid);
+    let actions = enumerate_legal_actions_with_undo(&state, 0, &UndoStack::new());
+    assert!(
+        actions.actions.iter().any(|a| matches!(a, LegalAction::RecruitUnit { .. })),
+        "Owner can recruit at their keep"
+    );
+}
+
 #[test]
 fn multiple_healing_levels_enumerated() {
     let mut state = setup_playing_game(vec!["wound", "wound", "wound", "march"]);
@@ -409,6 +510,35 @@ fn plunder_village_draws_cards_and_rep_hit_without_burning_site() {
     assert!(state.players[0].deck.is_empty(), "Deck should be empty after draw");
 }
 
+#[test]
+fn plunder_draws_top_two_cards_once_per_turn() {
+    let mut state = setup_playing_game(vec!["march"]);
+    place_player_on_site(&mut state, SiteType::Village);
+    state.players[0].pending.active = Some(ActivePending::PlunderDecision);
+    state.players[0].reputation = 2;
+    state.players[0].deck = vec![
+        CardId::from("rage"),
+        CardId::from("swiftness"),
+        CardId::from("stamina"),
+    ];
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::PlunderSite, epoch).unwrap();
+
+    let player = &state.players[0];
+    assert_eq!(
+        player.hand,
+        vec![CardId::from("march"), CardId::from("rage"), CardId::from("swiftness")]
+    );
+    assert_eq!(player.deck, vec![CardId::from("stamina")]);
+    assert_eq!(player.reputation, 1);
+
+    let actions = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(!actions.actions.iter().any(|a| matches!(a, LegalAction::PlunderSite)));
+    assert!(!actions.actions.iter().any(|a| matches!(a, LegalAction::DeclinePlunder)));
+}
+
 #[test]
 fn plunder_village_leaves_unit_recruitment_available() {
     let mut state = setup_playing_game(vec!["march"]);
@@ -566,6 +696,103 @@ fn glade_wound_choice_enumeration() {
     )));
 }
 
+/// End a turn on a Magical Glade with a wound in both hand and discard.
+fn end_turn_on_glade_with_wounds_in_hand_and_discard() -> GameState {
+    let mut state = setup_playing_game(vec!["wound", "march"]);
+    place_player_on_site(&mut state, SiteType::MagicalGlade);
+    state.players[0].discard.push(CardId::from("wound"));
+    state.wound_pile_count = Some(10);
+
+    crate::card_play::play_card_sideways(&mut state, 0, 1, SidewaysAs::Move).unwrap();
+    crate::end_turn::end_turn(&mut state, 0).unwrap();
+    assert!(matches!(state.players[0].pending.active, Some(ActivePending::GladeWoundChoice)));
+    state
+}
+
+fn wound_count(cards: &[CardId]) -> usize {
+    cards.iter().filter(|c| c.as_str() == "wound").count()
+}
+
+#[test]
+fn glade_end_turn_offers_both_piles_and_returns_wound_to_pile() {
+    for choice in [GladeWoundChoice::Hand, GladeWoundChoice::Discard] {
+        let mut state = end_turn_on_glade_with_wounds_in_hand_and_discard();
+        let mut undo = UndoStack::new();
+        let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+        let offered: Vec<_> = legal.actions.iter().filter_map(|a| match a {
+            LegalAction::ResolveGladeWound { choice } => Some(*choice),
+            _ => None,
+        }).collect();
+        assert_eq!(offered, vec![GladeWoundChoice::Hand, GladeWoundChoice::Discard]);
+
+        let action = LegalAction::ResolveGladeWound { choice };
+        apply_legal_action(&mut state, &mut undo, 0, &action, legal.epoch).unwrap();
+
+        let player = &state.players[0];
+        let (hand, discard) = match choice {
+            GladeWoundChoice::Hand => (0, 1),
+            _ => (1, 0),
+        };
+        assert_eq!(wound_count(&player.hand), hand, "{:?}", choice);
+        assert_eq!(wound_count(&player.discard), discard, "{:?}", choice);
+        assert_eq!(state.wound_pile_count, Some(11), "{:?}", choice);
+    }
+}
+
+#[test]
+fn glade_wound_choice_only_offers_piles_holding_a_wound() {
+    let mut state = setup_playing_game(vec!["wound", "march"]);
+    state.players[0].pending.active = Some(ActivePending::GladeWoundChoice);
+
+    let actions = enumerate_legal_actions_with_undo(&state, 0, &UndoStack::new());
+    assert!(actions.actions.iter().any(|a| matches!(a,
+        LegalAction::ResolveGladeWound { choice: GladeWoundChoice::Hand }
+    )));
+    assert!(!actions.actions.iter().any(|a| matches!(a,
+        LegalAction::ResolveGladeWound { choice: GladeWoundChoice::Discard }
+    )));
+}
+
+// =========================================================================
+// Deep Mine crystal choice
+// =========================================================================
+
+#[test]
+fn deep_mine_choice_offers_mine_colors_and_grants_chosen_crystal() {
+    let mut state = setup_playing_game(vec!["march"]);
+    place_player_on_site(&mut state, SiteType::DeepMine);
+    state.players[0].crystals.green = 2;
+
+    crate::card_play::play_card_sideways(&mut state, 0, 0, SidewaysAs::Move).unwrap();
+    crate::end_turn::end_turn(&mut state, 0).unwrap();
+
+    match &state.players[0].pending.active {
+        Some(ActivePending::DeepMineChoice { colors }) => {
+            assert_eq!(colors.as_slice(), &[BasicManaColor::Blue, BasicManaColor::Green]);
+        }
+        other => panic!("expected DeepMineChoice, got {:?}", other),
+    }
+    let mut undo = UndoStack::new();
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let choices: Vec<usize> = legal.actions.iter().filter_map(|a| match a {
+        LegalAction::ResolveChoice { choice_index } => Some(*choice_index),
+        _ => None,
+    }).collect();
+    assert_eq!(choices, vec![0, 1]);
+
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 1 },
+        legal.epoch,
+    ).unwrap();
+
+    let crystals = &state.players[0].crystals;
+    assert_eq!(crystals.green, 3);
+    assert_eq!(crystals.blue, 0);
+    assert_eq!(crystals.red + crystals.white, 0);
+    assert!(!state.players[0].pending.has_active());
+}
+
 // =========================================================================
 // Site Commerce Tests
 // =========================================================================
@@ -1165,6 +1392,42 @@ fn burn_monastery_victory_queues_artifact() {
     );
 }
 
+#[test]
+fn burned_monastery_no_longer_interactable_and_burner_gets_artifact() {
+    let mut state = setup_playing_game(vec!["march"]);
+    let mut undo = UndoStack::new();
+    state.offers.monastery_advanced_actions = vec![CardId::from("blood_rage")];
+    setup_burn_monastery_combat(&mut state, &mut undo);
+    win_burn_monastery_combat(&mut state, &mut undo);
+
+    assert!(
+        state.players[0].deck.iter().any(|c| c.as_str() == "banner_of_command"),
+        "Burner should receive the artifact"
+    );
+
+    // The site itself is burned, so the monastery is gone for every player:
+    // even with a fresh turn's flags and plenty of influence nothing is offered.
+    state.players[0].flags.remove(
+        PlayerFlags::HAS_TAKEN_ACTION_THIS_TURN | PlayerFlags::HAS_COMBATTED_THIS_TURN,
+    );
+    state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+    state.players[0].influence_points = 20;
+    state.enemy_tokens.violet_draw = vec![EnemyTokenId::from("monks_1")];
+
+    let actions = enumerate_legal_actions_with_undo(&state, 0, &UndoStack::new());
+    assert!(
+        !actions.actions.iter().any(|a| matches!(
+            a,
+            LegalAction::BeginInteraction
+                | LegalAction::InteractSite { .. }
+                | LegalAction::LearnAdvancedAction { .. }
+                | LegalAction::BurnMonastery
+        )),
+        "Burned monastery should offer no interaction: {:?}",
+        actions.actions
+    );
+}
+
 #[test]
 fn burn_monastery_defeat_does_not_burn() {
     let mut state = setup_playing_game(vec!["march"]);
@@ -4956,6 +5219,70 @@ fn city_conquest_places_shield_per_defeated_enemy() {
     );
 }
 
+#[test]
+fn city_assault_draws_garrison_and_conquest_awards_fame_and_ownership() {
+    use crate::movement::execute_move;
+
+    let mut state = setup_playing_game(vec!["march"]);
+    state.scenario_config.cities_can_be_entered = true;
+    state.scenario_config.default_city_level = 1;
+    state.players[0].move_points = 10;
+    let pid = state.players[0].id.clone();
+    let from = HexCoord::new(0, 0);
+    let city_coord = HexCoord::new(1, 0);
+    state.players[0].position = Some(from);
+    for (coord, site) in [
+        (from, None),
+        (city_coord, Some(Site {
+            site_type: SiteType::City,
+            owner: None,
+            is_conquered: false,
+            is_burned: false,
+            city_color: Some(BasicManaColor::Blue),
+            mine_color: None,
+            deep_mine_colors: None,
+        })),
+    ] {
+        state.map.hexes.insert(coord.key(), HexState {
+            coord,
+            terrain: Terrain::Plains,
+            tile_id: TileId::StartingA,
+            site,
+            rampaging_enemies: ArrayVec::new(),
+            enemies: ArrayVec::new(),
+            ruins_token: None,
+            shield_tokens: vec![],
+        });
+    }
+    // Blue city level 1 garrison = [Gray, Violet]
+    state.enemy_tokens.gray_draw = vec![EnemyTokenId::from("swordsmen_1")];
+    state.enemy_tokens.violet_draw = vec![EnemyTokenId::from("monks_1")];
+
+    assert!(execute_move(&mut state, 0, city_coord).unwrap().assault);
+    let combat = state.combat.as_ref().unwrap();
+    assert_eq!(combat.enemies.len(), 2);
+    assert!(combat.is_at_fortified_site);
+
+    // Siege through the walls: 5 + 5 armor.
+    let fame_before = state.players[0].fame;
+    let mut undo = UndoStack::new();
+    state.players[0].combat_accumulator.attack.siege = 10;
+    state.players[0].combat_accumulator.attack.siege_elements.physical = 10;
+    execute_attack(&mut state, &mut undo, CombatType::Siege, 2);
+    while state.combat.is_some() {
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    }
+
+    assert_eq!(state.players[0].fame, fame_before + 8, "Swordsmen 4 + Monks 4");
+    let hex = &state.map.hexes[&city_coord.key()];
+    let site = hex.site.as_ref().unwrap();
+    assert!(site.is_conquered);
+    assert_eq!(site.owner.as_ref(), Some(&pid));
+    assert!(hex.enemies.is_empty());
+    assert_eq!(hex.shield_tokens, vec![pid.clone(), pid]);
+}
+
 #[test]
 fn keep_conquest_places_single_shield() {
     let mut state = setup_playing_game(vec!["march"]);
@@ -5028,6 +5355,71 @@ fn keep_conquest_places_single_shield() {
     assert_eq!(hex.shield_tokens[0], pid);
 }
 
+#[test]
+fn conquered_keep_is_owned_and_reentry_does_not_restart_combat() {
+    use crate::movement::execute_move;
+    let mut state = setup_playing_game(vec!["march"]);
+    state.players[0].move_points = 10;
+    let pid = state.players[0].id.clone();
+    let from = HexCoord { q: 0, r: 0 };
+    let keep_coord = HexCoord { q: 1, r: 0 };
+    state.players[0].position = Some(from);
+
+    state.map.hexes.insert(from.key(), HexState {
+        coord: from,
+        terrain: Terrain::Plains,
+        tile_id: TileId::StartingA,
+        site: None,
+        rampaging_enemies: ArrayVec::new(),
+        enemies: ArrayVec::new(),
+        ruins_token: None,
+        shield_tokens: Vec::new(),
+    });
+    let mut defenders = ArrayVec::new();
+    defenders.push(HexEnemy {
+        token_id: EnemyTokenId::from("guardsmen_1"),
+        color: EnemyColor::Gray,
+        is_revealed: true,
+    });
+    state.map.hexes.insert(keep_coord.key(), HexState {
+        coord: keep_coord,
+        terrain: Terrain::Plains,
+        tile_id: TileId::StartingA,
+        site: Some(Site {
+            site_type: SiteType::Keep,
+            owner: None,
+            is_conquered: false,
+            is_burned: false,
+            city_color: None,
+            mine_color: None,
+            deep_mine_colors: None,
+        }),
+        rampaging_enemies: ArrayVec::new(),
+        enemies: defenders,
+        ruins_token: None,
+        shield_tokens: Vec::new(),
+    });
+
+    // Assault and win.
+    assert!(execute_move(&mut state, 0, keep_coord).unwrap().assault);
+    state.combat.as_mut().unwrap().enemies[0].is_defeated = true;
+    super::combat_end::end_combat(&mut state, 0);
+
+    let hex = &state.map.hexes[&keep_coord.key()];
+    let site = hex.site.as_ref().unwrap();
+    assert!(site.is_conquered);
+    assert_eq!(site.owner.as_ref(), Some(&pid));
+    assert!(hex.enemies.is_empty(), "Defenders must not remain on the keep");
+    assert_eq!(hex.shield_tokens, vec![pid.clone()], "Shield counts toward end-game scoring");
+
+    // On a later turn, leave and come back: the owner walks in without a fight.
+    state.players[0].move_points = 10;
+    execute_move(&mut state, 0, from).unwrap();
+    let result = execute_move(&mut state, 0, keep_coord).unwrap();
+    assert!(!result.assault);
+    assert!(state.combat.is_none());
+}
+
 // =========================================================================
 // Gap 2c/2d: City leader tracking + hand limit bonus
 // =========================================================================
@@ -5279,21 +5671,16 @@ fn setup_fortified_assault(
     state.players[0].position = Some(origin);
 
     // Ensure origin hex exists
-    if state.map.hexes.get(&origin.key()).is_none() {
-        state.map.hexes.insert(
-            origin.key(),
-            HexState {
-                coord: origin,
-                terrain: Terrain::Plains,
-                tile_id: TileId::StartingA,
-                site: None,
-                rampaging_enemies: ArrayVec::new(),
-                enemies: ArrayVec::new(),
-                ruins_token: None,
-                shield_tokens: Vec::new(),
-            },
-        );
-    }
+    state.map.hexes.entry(origin.key()).or_insert_with(|| HexState {
+        coord: origin,
+        terrain: Terrain::Plains,
+        tile_id: TileId::StartingA,
+        site: None,
+        rampaging_enemies: ArrayVec::new(),
+        enemies: ArrayVec::new(),
+        ruins_token: None,
+        shield_tokens: Vec::new(),
+    });
 
     // Build the target hex with a Keep and garrison enemies
     let mut enemies: ArrayVec<HexEnemy, 8> = ArrayVec::new();
@@ -5512,6 +5899,146 @@ fn fortified_assault_rampaging_enemy_not_required_for_conquest() {
     );
 }
 
+/// Set the player's accumulated physical melee attack.
+fn grant_melee_attack(state: &mut GameState, amount: u32) {
+    state.players[0].combat_accumulator.attack.normal_elements = ElementalValues {
+        physical: amount,
+        fire: 0,
+        ice: 0,
+        cold_fire: 0,
+    };
+}
+
+#[test]
+fn keep_assault_conquers_with_provoked_rampaging_enemy_alive() {
+    let origin = HexCoord { q: 0, r: 0 };
+    let target = HexCoord { q: 1, r: 0 };
+    let rampaging_coord = HexCoord { q: 1, r: -1 };
+
+    let mut state = setup_playing_game(vec!["march"]);
+    state.players[0].position = Some(origin);
+    for coord in [origin, target, rampaging_coord] {
+        state.map.hexes.insert(
+            coord.key(),
+            HexState {
+                coord,
+                terrain: Terrain::Plains,
+                tile_id: TileId::StartingA,
+                site: None,
+                rampaging_enemies: ArrayVec::new(),
+                enemies: ArrayVec::new(),
+                ruins_token: None,
+                shield_tokens: Vec::new(),
+            },
+        );
+    }
+    let keep_hex = state.map.hexes.get_mut(&target.key()).unwrap();
+    keep_hex.site = Some(Site {
+        site_type: SiteType::Keep,
+        owner: None,
+        is_conquered: false,
+        is_burned: false,
+        city_color: None,
+        mine_color: None,
+        deep_mine_colors: None,
+    });
+    keep_hex.enemies.push(HexEnemy {
+        token_id: EnemyTokenId::from("prowlers_0"),
+        color: EnemyColor::Green,
+        is_revealed: true,
+    });
+    let orc_hex = state.map.hexes.get_mut(&rampaging_coord.key()).unwrap();
+    orc_hex.rampaging_enemies.push(RampagingEnemyType::OrcMarauder);
+    orc_hex.enemies.push(HexEnemy {
+        token_id: EnemyTokenId::from("diggers_1"),
+        color: EnemyColor::Green,
+        is_revealed: true,
+    });
+
+    // Garrison first (required), then the provoked rampaging enemy (optional)
+    crate::movement::enter_assault_combat(
+        &mut state,
+        0,
+        &[EnemyTokenId::from("prowlers_0"), EnemyTokenId::from("diggers_1")],
+        1,
+        origin,
+        target,
+        None,
+    )
+    .unwrap();
+    state.players[0].position = Some(target);
+    let mut undo = UndoStack::new();
+
+    for _ in 0..3 {
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    }
+    assert_eq!(state.combat.as_ref().unwrap().phase, CombatPhase::Attack);
+
+    // Defeat only the garrison
+    let initial_fame = state.players[0].fame;
+    grant_melee_attack(&mut state, 3);
+    execute_attack(&mut state, &mut undo, CombatType::Melee, 1);
+    let combat = state.combat.as_ref().unwrap();
+    assert!(combat.enemies[0].is_defeated);
+    assert!(!combat.enemies[1].is_defeated);
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    assert!(state.combat.is_none());
+
+    let keep_hex = state.map.hexes.get(&target.key()).unwrap();
+    let site = keep_hex.site.as_ref().unwrap();
+    assert!(site.is_conquered, "Keep conquered once its garrison is defeated");
+    assert_eq!(site.owner.as_ref(), Some(&state.players[0].id));
+    assert_eq!(keep_hex.shield_tokens, vec![state.players[0].id.clone()]);
+    assert_eq!(state.players[0].position, Some(target));
+    assert_eq!(state.players[0].fame, initial_fame + 2, "Garrison fame kept");
+
+    // The surviving rampaging enemy stays on its own hex
+    let orc_hex = state.map.hexes.get(&rampaging_coord.key()).unwrap();
+    assert_eq!(orc_hex.enemies.len(), 1);
+    assert_eq!(orc_hex.rampaging_enemies.as_slice(), &[RampagingEnemyType::OrcMarauder]);
+}
+
+#[test]
+fn keep_assault_with_surviving_garrison_keeps_fame_but_not_site() {
+    let origin = HexCoord { q: 0, r: 0 };
+    let target = HexCoord { q: 1, r: 0 };
+
+    let (mut state, mut undo) = setup_fortified_assault(
+        vec!["march"],
+        &["prowlers", "prowlers"],
+        origin,
+        target,
+    );
+    assert!(state.combat.as_ref().unwrap().enemies.iter().all(|e| e.is_required_for_conquest));
+
+    for _ in 0..3 {
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    }
+
+    // Defeat one of the two garrison enemies
+    let initial_fame = state.players[0].fame;
+    grant_melee_attack(&mut state, 3);
+    execute_attack(&mut state, &mut undo, CombatType::Melee, 1);
+    assert!(state.combat.as_ref().unwrap().enemies[0].is_defeated);
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    assert!(state.combat.is_none());
+
+    let keep_hex = state.map.hexes.get(&target.key()).unwrap();
+    let site = keep_hex.site.as_ref().unwrap();
+    assert!(!site.is_conquered, "Surviving garrison enemy blocks conquest");
+    assert!(site.owner.is_none());
+    assert!(keep_hex.shield_tokens.is_empty());
+    assert_eq!(keep_hex.enemies.len(), 1, "Only the defeated garrison token is removed");
+    assert_eq!(state.players[0].fame, initial_fame + 2, "Fame for the defeated enemy is kept");
+    assert_eq!(state.players[0].position, Some(origin));
+}
+
 // =========================================================================
 // CityConquered scenario end trigger
 // =========================================================================
@@ -46,6 +46,25 @@ fn mana_overload_color_select_gains_mana_token() {
     assert_eq!(state.players[0].pure_mana.last().unwrap().color, ManaColor::Blue);
 }
 
+#[test]
+fn mana_overload_leaves_crystals_and_token_expires_at_turn_end() {
+    let (mut state, mut undo) = setup_two_player_with_skill(Hero::Tovak, "tovak_mana_overload");
+    state.players[0].pure_mana.clear();
+    state.players[0].crystals = Crystals { red: 2, blue: 1, ..Crystals::default() };
+    activate_skill(&mut state, &mut undo, "tovak_mana_overload");
+    // Black (index 4)
+    resolve_choice(&mut state, &mut undo, 4);
+
+    // One token of the chosen color; crystals are not converted
+    let colors: Vec<ManaColor> = state.players[0].pure_mana.iter().map(|t| t.color).collect();
+    assert_eq!(colors, vec![ManaColor::Black]);
+    assert_eq!(state.players[0].crystals, Crystals { red: 2, blue: 1, ..Crystals::default() });
+
+    crate::end_turn::reset_player_turn(&mut state, 0);
+    assert!(state.players[0].pure_mana.is_empty(), "Mana tokens do not persist past the turn");
+    assert_eq!(state.players[0].crystals.red, 2);
+}
+
 #[test]
 fn mana_overload_trigger_on_matching_color() {
     let (mut state, mut undo) = setup_two_player_with_skill(Hero::Tovak, "tovak_mana_overload");
@@ -110,6 +129,35 @@ fn mana_overload_no_trigger_wrong_color() {
     assert!(state.mana_overload_center.is_some());
 }
 
+#[test]
+fn mana_overload_no_trigger_on_basic_spell() {
+    let (mut state, mut undo) = setup_two_player_with_skill(Hero::Tovak, "tovak_mana_overload");
+    activate_skill(&mut state, &mut undo, "tovak_mana_overload");
+    // Choose Green (index 2)
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 2 }, epoch).unwrap();
+
+    // Player 1 pays green for Underground Travel's basic effect: not a powered play
+    switch_to_player_1(&mut state);
+    state.source.dice.clear(); // control mana sources explicitly
+    state.players[1].hand = vec![CardId::from("underground_travel")];
+    state.players[1].pure_mana.push(mk_types::state::ManaToken {
+        color: ManaColor::Green,
+        source: mk_types::state::ManaTokenSource::Effect,
+        cannot_power_spells: false,
+    });
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 1,
+        &LegalAction::PlayCardBasic {
+            hand_index: 0, card_id: CardId::from("underground_travel"),
+        }, epoch).unwrap();
+    assert!(state.players[1].pure_mana.is_empty(), "basic spell still costs its color");
+    // Underground Travel basic = 3 move, no Mana Overload bonus
+    assert_eq!(state.players[1].move_points, 3);
+    assert!(state.mana_overload_center.is_some());
+}
+
 #[test]
 fn mana_overload_skill_flipped_after_activation() {
     let (mut state, mut undo) = setup_two_player_with_skill(Hero::Tovak, "tovak_mana_overload");
@@ -305,6 +353,66 @@ fn source_opening_reroll_die_sets_center() {
     assert!(state.rng.counter > initial_rng_counter);
 }
 
+#[test]
+fn source_opening_rerolled_die_respects_time_of_day_and_can_be_claimed() {
+    use mk_types::rng::RngState;
+
+    let (mut state, mut undo) = setup_two_player_with_skill(Hero::Goldyx, "goldyx_source_opening");
+    state.time_of_day = TimeOfDay::Day;
+    state.source.dice[0].color = ManaColor::Red;
+    state.source.dice[0].is_depleted = false;
+    state.source.dice[0].taken_by_player_id = None;
+    let die_id = state.source.dice[0].id.clone();
+    // Leave die_0 as the only die player 0 could take
+    for die in state.source.dice.iter_mut().skip(1) {
+        die.taken_by_player_id = Some(mk_types::ids::PlayerId::from("player_1"));
+    }
+    state.players[0].crystals = Crystals::default();
+    state.players[0].pure_mana.clear();
+    state.players[0].hand = vec![CardId::from("march")];
+
+    // Find seeds whose reroll of die_0 lands on Black (depleted by day) and Green
+    let reroll_with = |seed: u32| {
+        let mut source = state.source.clone();
+        crate::mana::reroll_die(&mut source, &die_id, TimeOfDay::Day, &mut RngState::new(seed));
+        source.dice[0].color
+    };
+    let black_seed = (0..1000).find(|&s| reroll_with(s) == ManaColor::Black).unwrap();
+    let green_seed = (0..1000).find(|&s| reroll_with(s) == ManaColor::Green).unwrap();
+
+    // Black by day: rerolled but depleted, so it cannot power a card
+    let mut day_black = state.clone();
+    let mut black_undo = UndoStack::new();
+    activate_skill(&mut day_black, &mut black_undo, "goldyx_source_opening");
+    day_black.rng = RngState::new(black_seed);
+    let epoch = day_black.action_epoch;
+    apply_legal_action(&mut day_black, &mut black_undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 0 }, epoch).unwrap();
+    assert_eq!(day_black.source.dice[0].color, ManaColor::Black);
+    assert!(day_black.source.dice[0].is_depleted);
+
+    // Green: reroll, then claim it to power March
+    activate_skill(&mut state, &mut undo, "goldyx_source_opening");
+    state.rng = RngState::new(green_seed);
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 0 }, epoch).unwrap();
+    assert_eq!(state.source.dice[0].color, ManaColor::Green);
+    assert!(!state.source.dice[0].is_depleted);
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0,
+        &LegalAction::PlayCardPowered {
+            hand_index: 0,
+            card_id: CardId::from("march"),
+            mana_color: Some(BasicManaColor::Green),
+        }, epoch).unwrap();
+    assert_eq!(state.players[0].move_points, 4);
+    assert_eq!(state.source.dice[0].taken_by_player_id.as_ref().map(|p| p.as_str()), Some("player_0"));
+    assert!(state.players[0].used_die_ids.contains(&die_id));
+    assert!(state.players[0].flags.contains(PlayerFlags::USED_MANA_FROM_SOURCE));
+}
+
 #[test]
 fn source_opening_return_grants_extra_die() {
     let (mut state, mut undo) = setup_two_player_with_skill(Hero::Goldyx, "goldyx_source_opening");
@@ -978,6 +1086,35 @@ fn master_of_chaos_gold_choice_filters_outside_combat() {
     assert_eq!(state.players[0].influence_points, 2);
 }
 
+#[test]
+fn master_of_chaos_consecutive_turns_advance_wheel() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_master_of_chaos");
+    state.players[0].master_of_chaos_state = Some(MasterOfChaosState {
+        position: ManaColor::Blue,
+        free_rotate_available: false,
+    });
+    state.players[0].hand = vec![CardId::from("march")];
+
+    // Turn 1: Blue → Green, Move 1
+    activate_skill(&mut state, &mut undo, "krang_master_of_chaos");
+    assert_eq!(state.players[0].master_of_chaos_state.as_ref().unwrap().position, ManaColor::Green);
+    assert_eq!(state.players[0].move_points, 1);
+
+    // Turn 2 (in combat): Green → Black, Ranged ColdFire Attack 1
+    crate::end_turn::reset_player_turn(&mut state, 0);
+    let mut undo = UndoStack::new();
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &[mk_types::ids::EnemyTokenId::from("prowlers_1")], false, None, Default::default(),
+    ).unwrap();
+    activate_skill(&mut state, &mut undo, "krang_master_of_chaos");
+    let moc = state.players[0].master_of_chaos_state.as_ref().unwrap();
+    assert_eq!(moc.position, ManaColor::Black);
+    assert!(!moc.free_rotate_available);
+    assert_eq!(state.players[0].combat_accumulator.attack.ranged, 1);
+    assert_eq!(state.players[0].combat_accumulator.attack.ranged_elements.cold_fire, 1);
+    assert!(state.players[0].pending.active.is_none());
+}
+
 #[test]
 fn master_of_chaos_free_rotate_window_opens_on_turn_reset() {
     // After a turn where MoC was NOT used, free_rotate_available should be set to true
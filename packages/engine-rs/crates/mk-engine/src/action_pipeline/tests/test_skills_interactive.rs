@@ -4,11 +4,11 @@ use super::*;
 // Interactive Skill tests
 // =========================================================================
 
-/// Helper: set up a 2-player game where player 0 has a specific skill and it's their turn.
+// Helper: set up a 2-player game where player 0 has a specific skill and it's their turn.
 
-/// Helper: activate a skill for player 0.
+// Helper: activate a skill for player 0.
 
-/// Helper: switch current player to player_1 (index 1).
+// Helper: switch current player to player_1 (index 1).
 
 // ---- Prayer of Weather ----
 
@@ -94,6 +94,26 @@ fn prayer_of_weather_center_cleared_on_return() {
     ));
 }
 
+#[test]
+fn prayer_of_weather_reduces_move_cost_with_floor_of_one() {
+    let (mut state, mut undo) = setup_two_player_with_skill(Hero::Norowas, "norowas_prayer_of_weather");
+    state.time_of_day = mk_types::enums::TimeOfDay::Day;
+    let pos = state.players[0].position.unwrap();
+    let mut adjacent = pos.neighbors().into_iter()
+        .filter(|c| state.map.hexes.contains_key(&c.key()));
+    let forest = adjacent.next().expect("starting hex should have a neighbor");
+    let plains = adjacent.next().expect("starting hex should have two neighbors");
+    state.map.hexes.get_mut(&forest.key()).unwrap().terrain = mk_types::enums::Terrain::Forest;
+    state.map.hexes.get_mut(&plains.key()).unwrap().terrain = mk_types::enums::Terrain::Plains;
+
+    activate_skill(&mut state, &mut undo, "norowas_prayer_of_weather");
+
+    // Forest 3 -> 1, Plains 2 -> floor of 1; no mana or Source change.
+    assert_eq!(crate::movement::evaluate_move_entry(&state, 0, forest).cost, Some(1));
+    assert_eq!(crate::movement::evaluate_move_entry(&state, 0, plains).cost, Some(1));
+    assert!(state.players[0].pure_mana.is_empty());
+}
+
 // ---- Ritual of Pain ----
 
 #[test]
@@ -153,6 +173,50 @@ fn ritual_of_pain_discard_2_wounds() {
     assert_eq!(state.players[0].hand.len(), 1); // only march
 }
 
+#[test]
+fn ritual_of_pain_then_power_of_pain_plays_remaining_wound_for_2() {
+    let (mut state, mut undo) = setup_two_player_with_skill(Hero::Arythea, "arythea_ritual_of_pain");
+    state.players[0].skills.push(mk_types::ids::SkillId::from("arythea_power_of_pain"));
+    state.players[0].hand = vec![
+        CardId::from("wound"), CardId::from("wound"), CardId::from("wound"), CardId::from("march"),
+    ];
+
+    // Ritual of Pain throws away two of the three wounds
+    activate_skill(&mut state, &mut undo, "arythea_ritual_of_pain");
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 2 },
+        epoch,
+    ).unwrap();
+    assert_eq!(state.players[0].hand.iter().filter(|c| c.as_str() == "wound").count(), 1);
+    assert!(!state.players[0].discard.iter().any(|c| c.as_str() == "wound"));
+
+    // Power of Pain: the one remaining wound goes sideways for 2
+    activate_skill(&mut state, &mut undo, "arythea_power_of_pain");
+    let actions = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let wound_sideways: Vec<usize> = actions.actions.iter().filter_map(|a| match a {
+        LegalAction::PlayCardSideways { hand_index, card_id, sideways_as: SidewaysAs::Move }
+            if card_id.as_str() == "wound" => Some(*hand_index),
+        _ => None,
+    }).collect();
+    assert_eq!(wound_sideways.len(), 1, "only the surviving wound is playable");
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::PlayCardSideways {
+            hand_index: wound_sideways[0],
+            card_id: CardId::from("wound"),
+            sideways_as: SidewaysAs::Move,
+        },
+        epoch,
+    ).unwrap();
+    // The Ritual's center value (3) is for other players only
+    assert_eq!(state.players[0].move_points, 2);
+    assert_eq!(state.players[0].hand, vec![CardId::from("march")]);
+}
+
 #[test]
 fn ritual_of_pain_center_modifiers() {
     let (mut state, mut undo) = setup_two_player_with_skill(Hero::Arythea, "arythea_ritual_of_pain");
@@ -283,6 +347,39 @@ fn natures_vengeance_allows_arcane_immune() {
     ));
 }
 
+#[test]
+fn natures_vengeance_debuffs_only_the_chosen_enemy() {
+    let (mut state, mut undo) = setup_two_player_combat_with_skill(
+        Hero::Braevalar, "braevalar_natures_vengeance", &["prowlers", "shadow"],
+    );
+    activate_skill(&mut state, &mut undo, "braevalar_natures_vengeance");
+    // Arcane-immune Shadow is a valid target alongside the Prowlers
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => assert!(matches!(
+            &choice.resolution,
+            mk_types::pending::ChoiceResolution::NaturesVengeanceTarget { eligible_enemy_ids, .. }
+                if *eligible_enemy_ids == vec!["enemy_0".to_string(), "enemy_1".to_string()]
+        )),
+        ref other => panic!("Expected Nature's Vengeance target choice, got {:?}", other),
+    }
+    resolve_choice(&mut state, &mut undo, 1);
+
+    let attack_change = |id| crate::combat_resolution::get_enemy_attack_modifier(&state.active_modifiers, id).0;
+    assert_eq!(attack_change("enemy_1"), -1);
+    assert_eq!(attack_change("enemy_0"), 0, "Unchosen enemy keeps its attack");
+    let cumbersome_scopes: Vec<_> = state.active_modifiers.iter()
+        .filter(|m| matches!(m.effect, mk_types::modifier::ModifierEffect::GrantEnemyAbility {
+            ability: EnemyAbilityType::Cumbersome,
+        }))
+        .map(|m| m.scope.clone())
+        .collect();
+    assert_eq!(cumbersome_scopes.len(), 1);
+    assert!(matches!(
+        &cumbersome_scopes[0],
+        mk_types::modifier::ModifierScope::OneEnemy { enemy_id } if enemy_id == "enemy_1"
+    ));
+}
+
 #[test]
 fn natures_vengeance_center_penalty() {
     let (mut state, mut undo) = setup_two_player_combat_with_skill(
@@ -1679,6 +1679,25 @@ fn arythea_motivation_cross_player_cooldown() {
         "Player 1's motivation should be blocked by cross-player cooldown");
 }
 
+#[test]
+fn motivation_offered_out_of_turn_and_shares_cooldown() {
+    let (mut state, mut undo) = setup_motivation(Hero::Arythea, "arythea_motivation");
+    // Player 0's turn: player 1 is offered their motivation out of turn
+    let legal = enumerate_legal_actions_with_undo(&state, 1, &undo);
+    let offer = LegalAction::UseSkill { skill_id: mk_types::ids::SkillId::from("tovak_motivation") };
+    assert_eq!(legal.actions, vec![offer.clone()]);
+
+    let before = state.players[1].hand.len();
+    apply_legal_action(&mut state, &mut undo, 1, &offer, legal.epoch).unwrap();
+    assert_eq!(state.players[1].hand.len(), before + 2);
+
+    // Shared cooldown: no motivation left for anyone this round
+    let active = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(!active.actions.iter().any(|a| matches!(a,
+        LegalAction::UseSkill { ref skill_id } if skill_id.as_str() == "arythea_motivation")));
+    assert!(enumerate_legal_actions_with_undo(&state, 1, &undo).actions.is_empty());
+}
+
 // ---- Goldyx Motivation ----
 
 #[test]
@@ -60,6 +60,49 @@ fn i_dont_give_a_damn_advanced_action_plus3() {
     assert_eq!(val, 3);
 }
 
+#[test]
+fn i_dont_give_a_damn_sideways_plays_take_best_value_not_sum() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Tovak, "tovak_i_dont_give_a_damn");
+    state.players[0].hand = vec![
+        CardId::from("fireball"),
+        CardId::from("march"),
+        CardId::from("wound"),
+    ];
+    state.players[0].move_points = 0;
+    activate_skill(&mut state, &mut undo, "tovak_i_dont_give_a_damn");
+
+    // Wounds stay excluded from sideways play.
+    let actions = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(!actions.actions.iter().any(|a| matches!(a, LegalAction::PlayCardSideways { card_id, .. }
+        if card_id.as_str() == "wound")));
+
+    // Spell: both modifiers match, the higher (+3) wins rather than 2 + 3.
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::PlayCardSideways {
+            hand_index: 0,
+            card_id: CardId::from("fireball"),
+            sideways_as: SidewaysAs::Move,
+        },
+        epoch,
+    ).unwrap();
+    assert_eq!(state.players[0].move_points, 3);
+
+    // Basic action: only the base +2 modifier matches.
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::PlayCardSideways {
+            hand_index: 0,
+            card_id: CardId::from("march"),
+            sideways_as: SidewaysAs::Move,
+        },
+        epoch,
+    ).unwrap();
+    assert_eq!(state.players[0].move_points, 5);
+}
+
 #[test]
 fn who_needs_magic_plus3_no_mana() {
     let (mut state, mut undo) = setup_with_skill(Hero::Tovak, "tovak_who_needs_magic");
@@ -102,6 +145,42 @@ fn who_needs_magic_plus2_after_mana() {
     ));
 }
 
+#[test]
+fn who_needs_magic_blocks_powered_play_from_source() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Tovak, "tovak_who_needs_magic");
+    state.players[0].hand = vec![CardId::from("march")];
+    state.players[0].pure_mana.clear();
+    state.players[0].crystals = Default::default();
+    state.source.dice = vec![mk_types::state::SourceDie {
+        id: mk_types::ids::SourceDieId::from("die_0"),
+        color: ManaColor::Green,
+        is_depleted: false,
+        taken_by_player_id: None,
+    }];
+    let powered_march = LegalAction::PlayCardPowered {
+        hand_index: 0,
+        card_id: CardId::from("march"),
+        mana_color: Some(BasicManaColor::Green),
+    };
+    let offered = |state: &GameState, action: &LegalAction| {
+        enumerate_legal_actions_with_undo(state, 0, &UndoStack::new()).actions.contains(action)
+    };
+    assert!(offered(&state, &powered_march), "Green die can power March before the skill");
+
+    activate_skill(&mut state, &mut undo, "tovak_who_needs_magic");
+    assert!(!offered(&state, &powered_march), "Source is blocked after Who Needs Magic?");
+    assert!(crate::card_play::collect_mana_sources(&state, 0, BasicManaColor::Green, false).is_empty());
+    // Still no Source used, so the +3 sideways value holds
+    assert_eq!(
+        crate::card_play::get_effective_sideways_value(&state, 0, false, DeedCardType::BasicAction, None),
+        3,
+    );
+
+    // A crystal is not the Source and still powers the card
+    state.players[0].crystals.green = 1;
+    assert!(offered(&state, &powered_march));
+}
+
 #[test]
 fn universal_power_mana_choice() {
     let (mut state, mut undo) = setup_with_skill(Hero::Goldyx, "goldyx_universal_power");
@@ -183,6 +262,47 @@ fn universal_power_color_match_plus4() {
     assert_eq!(val, 3);
 }
 
+#[test]
+fn universal_power_consumes_one_token_and_leaves_crystals_and_source() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Goldyx, "goldyx_universal_power");
+    let token = |color| ManaToken { color, source: ManaTokenSource::Effect, cannot_power_spells: false };
+    state.players[0].pure_mana = vec![token(ManaColor::Red), token(ManaColor::Red), token(ManaColor::Blue)];
+    state.players[0].crystals = Crystals { red: 1, ..Crystals::default() };
+    state.source.dice = vec![mk_types::state::SourceDie {
+        id: mk_types::ids::SourceDieId::from("die_0"),
+        color: ManaColor::Red,
+        is_depleted: false,
+        taken_by_player_id: None,
+    }];
+    activate_skill(&mut state, &mut undo, "goldyx_universal_power");
+
+    // Duplicate Red tokens collapse into one option: Red, Blue
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => assert!(matches!(
+            &choice.resolution,
+            mk_types::pending::ChoiceResolution::UniversalPowerMana { available_colors }
+                if *available_colors == vec![BasicManaColor::Red, BasicManaColor::Blue]
+        )),
+        ref other => panic!("Expected Universal Power choice, got {:?}", other),
+    }
+    resolve_choice(&mut state, &mut undo, 0);
+
+    let player = &state.players[0];
+    let colors: Vec<ManaColor> = player.pure_mana.iter().map(|t| t.color).collect();
+    assert_eq!(colors, vec![ManaColor::Red, ManaColor::Blue], "Exactly one Red token spent");
+    assert_eq!(player.crystals.red, 1);
+    assert!(state.source.dice[0].taken_by_player_id.is_none());
+    assert!(!player.flags.contains(PlayerFlags::USED_MANA_FROM_SOURCE));
+
+    // +4 only for matching-color Basic/Advanced Actions and Spells
+    let sideways = |card_type, color| crate::card_play::get_effective_sideways_value(
+        &state, 0, false, card_type, color,
+    );
+    assert_eq!(sideways(DeedCardType::Spell, Some(BasicManaColor::Red)), 4);
+    assert_eq!(sideways(DeedCardType::AdvancedAction, Some(BasicManaColor::Blue)), 3);
+    assert_eq!(sideways(DeedCardType::Artifact, Some(BasicManaColor::Red)), 3);
+}
+
 #[test]
 fn mutual_exclusivity_blocks_second_skill() {
     // Activate one sideways skill, then check that a conflicting one is not enumerable
@@ -456,6 +576,49 @@ fn regenerate_no_draw_without_bonus_color() {
     assert_eq!(state.players[0].hand.len(), 1, "Should NOT draw (Blue != Red bonus)");
 }
 
+/// Use Krang's Regenerate with a single Blue token (not Krang's Red bonus color)
+/// and return how many cards were drawn.
+fn regenerate_with_blue_draws(state: &mut GameState, undo: &mut UndoStack) -> usize {
+    state.players[0].hand = vec![CardId::from("wound"), CardId::from("march")];
+    state.players[0].deck = vec![CardId::from("rage")];
+    state.players[0].pure_mana = vec![ManaToken {
+        color: ManaColor::Blue,
+        source: ManaTokenSource::Effect,
+        cannot_power_spells: false,
+    }];
+    for die in &mut state.source.dice {
+        die.is_depleted = true;
+    }
+    state.players[0].crystals = Crystals::default();
+    activate_skill(state, undo, "krang_regenerate");
+    assert!(!state.players[0].hand.iter().any(|c| c.as_str() == "wound"));
+    1 - state.players[0].deck.len()
+}
+
+#[test]
+fn regenerate_lowest_fame_draws_in_multiplayer() {
+    let (mut state, mut undo) = setup_two_player_with_skill(Hero::Krang, "krang_regenerate");
+    state.players[0].fame = 3;
+    state.players[1].fame = 5;
+    assert_eq!(regenerate_with_blue_draws(&mut state, &mut undo), 1);
+}
+
+#[test]
+fn regenerate_tied_lowest_fame_does_not_draw() {
+    let (mut state, mut undo) = setup_two_player_with_skill(Hero::Krang, "krang_regenerate");
+    state.players[0].fame = 5;
+    state.players[1].fame = 5;
+    assert_eq!(regenerate_with_blue_draws(&mut state, &mut undo), 0);
+}
+
+#[test]
+fn regenerate_solo_draws_only_on_bonus_color() {
+    // The dummy player has no fame, so solo relies on the color match alone
+    let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_regenerate");
+    state.players[0].fame = 0;
+    assert_eq!(regenerate_with_blue_draws(&mut state, &mut undo), 0);
+}
+
 #[test]
 fn regenerate_multiple_mana_creates_pending() {
     let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_regenerate");
@@ -645,6 +808,67 @@ fn dueling_no_fame_with_unit_involvement() {
     assert_eq!(bonus, 0, "Should NOT get fame bonus when units were involved");
 }
 
+/// Duel prowlers (armor 3, fame 2) to the end of combat, optionally letting a
+/// unit help in the Block phase. Returns fame gained over the whole combat.
+fn duel_prowlers_to_the_end(unit_helps: bool) -> u32 {
+    let (mut state, mut undo) =
+        setup_combat_with_skill(Hero::Wolfhawk, "wolfhawk_dueling", &["prowlers"]);
+    state.players[0].units.push(mk_types::state::PlayerUnit {
+        instance_id: mk_types::ids::UnitInstanceId::from("unit_0"),
+        unit_id: mk_types::ids::UnitId::from("utem_swordsmen"),
+        level: 2,
+        state: UnitState::Ready,
+        wounded: false,
+        used_resistance_this_combat: false,
+        used_ability_indices: vec![],
+        mana_token: None,
+    });
+    state.combat.as_mut().unwrap().phase = CombatPhase::Block;
+    activate_skill(&mut state, &mut undo, "wolfhawk_dueling");
+
+    if unit_helps {
+        // Attack-or-Block (wound self) resolves through a pending choice.
+        let epoch = state.action_epoch;
+        apply_legal_action(
+            &mut state, &mut undo, 0,
+            &LegalAction::ActivateUnit {
+                unit_instance_id: mk_types::ids::UnitInstanceId::from("unit_0"),
+                ability_index: 2,
+            },
+            epoch,
+        ).unwrap();
+        resolve_choice(&mut state, &mut undo, 1);
+    }
+    let enemy = &mut state.combat.as_mut().unwrap().enemies[0];
+    enemy.is_blocked = true;
+    enemy.attacks_blocked = vec![true];
+
+    let fame_before = state.players[0].fame;
+    // Block → AssignDamage → Attack
+    for _ in 0..2 {
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    }
+    // Dueling's bound Attack 1 plus 2 from the hero.
+    state.players[0].combat_accumulator.attack.normal += 2;
+    state.players[0].combat_accumulator.attack.normal_elements.physical += 2;
+    execute_attack(&mut state, &mut undo, CombatType::Melee, 1);
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::EndCombatPhase, epoch).unwrap();
+    assert!(state.combat.is_none());
+    state.players[0].fame - fame_before
+}
+
+#[test]
+fn dueling_fame_bonus_through_full_combat_hero_only() {
+    assert_eq!(duel_prowlers_to_the_end(false), 3, "Prowlers 2 + Dueling 1");
+}
+
+#[test]
+fn dueling_fame_bonus_denied_when_unit_helped_via_choice_ability() {
+    assert_eq!(duel_prowlers_to_the_end(true), 2, "Prowlers 2, no Dueling bonus");
+}
+
 #[test]
 fn dueling_multiple_enemies_creates_pending() {
     let (mut state, mut undo) = setup_with_skill(Hero::Wolfhawk, "wolfhawk_dueling");
@@ -822,6 +1046,76 @@ fn invocation_deduplicates_same_cards() {
     }
 }
 
+/// Activate Invocation and return the offered (card, is_wound, color) options.
+fn invocation_options(state: &mut GameState, undo: &mut UndoStack) -> Vec<(String, bool, ManaColor)> {
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        state, undo, 0,
+        &LegalAction::UseSkill { skill_id: mk_types::ids::SkillId::from("arythea_invocation") },
+        epoch,
+    ).unwrap();
+    match &state.players[0].pending.active {
+        Some(ActivePending::Choice(c)) => match &c.resolution {
+            mk_types::pending::ChoiceResolution::InvocationDiscard { options } => options
+                .iter()
+                .map(|o| (o.card_id.as_str().to_string(), o.is_wound, o.mana_color))
+                .collect(),
+            other => panic!("Expected InvocationDiscard, got {:?}", other),
+        },
+        other => panic!("Expected choice pending, got {:?}", other),
+    }
+}
+
+#[test]
+fn invocation_all_wound_hand_offers_red_and_black() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Arythea, "arythea_invocation");
+    state.players[0].hand = vec![CardId::from("wound"), CardId::from("wound")];
+    state.wound_pile_count = Some(5);
+
+    let options = invocation_options(&mut state, &mut undo);
+    assert_eq!(options, vec![
+        ("wound".to_string(), true, ManaColor::Red),
+        ("wound".to_string(), true, ManaColor::Black),
+    ]);
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 1 }, epoch).unwrap();
+    // One wound back to the pile, none to the discard
+    assert_eq!(state.players[0].hand, vec![CardId::from("wound")]);
+    assert!(state.players[0].discard.is_empty());
+    assert_eq!(state.wound_pile_count, Some(6));
+    assert_eq!(state.players[0].pure_mana.last().unwrap().color, ManaColor::Black);
+}
+
+#[test]
+fn invocation_mixed_hand_offers_per_card_colors() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Arythea, "arythea_invocation");
+    state.players[0].hand = vec![
+        CardId::from("wound"), CardId::from("march"), CardId::from("wound"), CardId::from("rage"),
+    ];
+    state.wound_pile_count = Some(5);
+
+    let options = invocation_options(&mut state, &mut undo);
+    assert_eq!(options, vec![
+        ("wound".to_string(), true, ManaColor::Red),
+        ("wound".to_string(), true, ManaColor::Black),
+        ("march".to_string(), false, ManaColor::White),
+        ("march".to_string(), false, ManaColor::Green),
+        ("rage".to_string(), false, ManaColor::White),
+        ("rage".to_string(), false, ManaColor::Green),
+    ]);
+
+    // Discarding a non-wound puts it in the discard and leaves the wound pile alone
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 4 }, epoch).unwrap();
+    assert_eq!(state.players[0].discard, vec![CardId::from("rage")]);
+    assert_eq!(state.wound_pile_count, Some(5));
+    assert_eq!(state.players[0].hand.len(), 3);
+    assert_eq!(state.players[0].pure_mana.last().unwrap().color, ManaColor::White);
+}
+
 #[test]
 fn invocation_skipped_when_hand_empty() {
     let (mut state, _undo) = setup_with_skill(Hero::Arythea, "arythea_invocation");
@@ -986,6 +1280,105 @@ fn polarization_gold_night() {
     assert!(!state.players[0].pure_mana[0].cannot_power_spells);
 }
 
+/// Activate Polarization with one each of Red/Black/Gold tokens, a Green crystal,
+/// and Blue/Black/Gold Source dice, returning the offered (source, from, to) set.
+fn polarization_option_set(
+    time_of_day: TimeOfDay,
+) -> (GameState, UndoStack, Vec<(mk_types::pending::PolarizationSourceType, ManaColor, ManaColor)>) {
+    let (mut state, mut undo) = setup_with_skill(Hero::Arythea, "arythea_polarization");
+    state.time_of_day = time_of_day;
+    state.players[0].crystals = Crystals { green: 1, ..Crystals::default() };
+    state.players[0].pure_mana = [ManaColor::Red, ManaColor::Black, ManaColor::Gold]
+        .into_iter()
+        .map(|color| ManaToken { color, source: ManaTokenSource::Effect, cannot_power_spells: false })
+        .collect();
+    state.source.dice = [ManaColor::Blue, ManaColor::Black, ManaColor::Gold]
+        .into_iter()
+        .enumerate()
+        .map(|(i, color)| SourceDie {
+            id: mk_types::ids::SourceDieId::from(format!("die_{}", i)),
+            color,
+            is_depleted: crate::mana::is_depleted_for_time(color, time_of_day),
+            taken_by_player_id: None,
+        })
+        .collect();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::UseSkill { skill_id: mk_types::ids::SkillId::from("arythea_polarization") },
+        epoch,
+    ).unwrap();
+    let set = match &state.players[0].pending.active {
+        Some(ActivePending::Choice(c)) => match &c.resolution {
+            mk_types::pending::ChoiceResolution::PolarizationConvert { options } => options
+                .iter()
+                .map(|o| {
+                    // Only Black-by-day conversions are barred from powering spells
+                    assert_eq!(o.cannot_power_spells, o.source_color == ManaColor::Black);
+                    (o.source_type, o.source_color, o.target_color)
+                })
+                .collect(),
+            other => panic!("Expected PolarizationConvert, got {:?}", other),
+        },
+        other => panic!("Expected choice pending, got {:?}", other),
+    };
+    (state, undo, set)
+}
+
+#[test]
+fn polarization_day_option_set() {
+    use mk_types::pending::PolarizationSourceType::{Crystal, Die, Token};
+    let (_, _, set) = polarization_option_set(TimeOfDay::Day);
+    let basics = [ManaColor::Red, ManaColor::Blue, ManaColor::Green, ManaColor::White];
+    let mut expected = vec![(Token, ManaColor::Red, ManaColor::Blue)];
+    expected.extend(basics.iter().map(|&c| (Token, ManaColor::Black, c)));
+    expected.push((Crystal, ManaColor::Green, ManaColor::White));
+    expected.push((Die, ManaColor::Blue, ManaColor::Red));
+    expected.extend(basics.iter().map(|&c| (Die, ManaColor::Black, c)));
+    assert_eq!(set, expected);
+}
+
+#[test]
+fn polarization_night_option_set() {
+    use mk_types::pending::PolarizationSourceType::{Crystal, Die, Token};
+    let (_, _, set) = polarization_option_set(TimeOfDay::Night);
+    assert_eq!(set, vec![
+        (Token, ManaColor::Red, ManaColor::Blue),
+        (Token, ManaColor::Gold, ManaColor::Black),
+        (Crystal, ManaColor::Green, ManaColor::White),
+        (Die, ManaColor::Blue, ManaColor::Red),
+        (Die, ManaColor::Gold, ManaColor::Black),
+    ]);
+}
+
+#[test]
+fn polarization_crystal_option_stays_crystal_and_die_option_uses_source() {
+    use mk_types::pending::PolarizationSourceType::{Crystal, Die};
+    let (mut state, mut undo, set) = polarization_option_set(TimeOfDay::Day);
+    let crystal_idx = set.iter().position(|o| o.0 == Crystal).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: crystal_idx }, epoch).unwrap();
+    assert_eq!(state.players[0].crystals.green, 0);
+    assert_eq!(state.players[0].crystals.white, 1);
+    assert_eq!(state.players[0].pure_mana.len(), 3, "no token gained");
+
+    // A Black die by day converts and counts as the turn's Source die
+    let (mut state, mut undo, set) = polarization_option_set(TimeOfDay::Day);
+    let die_idx = set.iter()
+        .position(|&o| o == (Die, ManaColor::Black, ManaColor::Green))
+        .unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: die_idx }, epoch).unwrap();
+    let green = state.players[0].pure_mana.last().unwrap();
+    assert_eq!(green.color, ManaColor::Green);
+    assert!(green.cannot_power_spells);
+    assert!(state.players[0].used_die_ids.iter().any(|d| d.as_str() == "die_1"));
+    assert!(state.players[0].flags.contains(PlayerFlags::USED_MANA_FROM_SOURCE));
+}
+
 // =============================================================================
 // Curse tests
 // =============================================================================
@@ -1140,6 +1533,136 @@ fn curse_multi_attack_index() {
     assert!(modifier.is_some(), "Should have Attack -2 with attack_index=0");
 }
 
+/// The single Curse EnemyStat modifier pushed so far, as (stat, amount, minimum, attack_index).
+fn curse_modifier(state: &GameState) -> (mk_types::modifier::EnemyStat, i32, u32, Option<u32>) {
+    let stats: Vec<_> = state.active_modifiers.iter().filter_map(|m| match &m.effect {
+        mk_types::modifier::ModifierEffect::EnemyStat { stat, amount, minimum, attack_index, .. } => {
+            Some((*stat, *amount, *minimum, *attack_index))
+        }
+        _ => None,
+    }).collect();
+    assert_eq!(stats.len(), 1, "Curse should push exactly one EnemyStat modifier");
+    stats[0]
+}
+
+#[test]
+fn curse_single_attack_non_ai_applies_unindexed_attack_reduction() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_curse");
+    let tokens = vec![EnemyTokenId::from("prowlers_1")];
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &tokens, false, None, Default::default(),
+    ).unwrap();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::UseSkill { skill_id: mk_types::ids::SkillId::from("krang_curse") },
+        epoch,
+    ).unwrap();
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => assert!(matches!(
+            choice.resolution,
+            mk_types::pending::ChoiceResolution::CurseMode { has_arcane_immunity: false, has_multi_attack: false, .. }
+        )),
+        ref other => panic!("Expected Curse mode choice, got {:?}", other),
+    }
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 0 },
+        epoch,
+    ).unwrap();
+
+    // Single attack: no attack-index step, modifier covers the whole attack
+    assert!(!state.players[0].pending.has_active());
+    assert_eq!(
+        curse_modifier(&state),
+        (mk_types::modifier::EnemyStat::Attack, -2, 0, None),
+    );
+}
+
+#[test]
+fn curse_ai_enemy_applies_only_attack_reduction() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_curse");
+    // Shadow: ArcaneImmunity, single attack
+    let tokens = vec![EnemyTokenId::from("shadow_1")];
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &tokens, false, None, Default::default(),
+    ).unwrap();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::UseSkill { skill_id: mk_types::ids::SkillId::from("krang_curse") },
+        epoch,
+    ).unwrap();
+
+    assert!(!state.players[0].pending.has_active());
+    assert_eq!(
+        curse_modifier(&state),
+        (mk_types::modifier::EnemyStat::Attack, -2, 0, None),
+    );
+}
+
+#[test]
+fn curse_ai_multi_attack_enemy_offers_attack_only_then_index() {
+    let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_curse");
+    // Dragon Summoner: ArcaneImmunity, 2 attacks
+    let tokens = vec![EnemyTokenId::from("dragon_summoner_1")];
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &tokens, false, None, Default::default(),
+    ).unwrap();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::UseSkill { skill_id: mk_types::ids::SkillId::from("krang_curse") },
+        epoch,
+    ).unwrap();
+
+    // Mode choice has no Armor -1 option
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => {
+            assert_eq!(choice.options.len(), 1, "AI blocks Armor -1");
+            assert!(matches!(
+                choice.resolution,
+                mk_types::pending::ChoiceResolution::CurseMode { has_arcane_immunity: true, has_multi_attack: true, .. }
+            ));
+        }
+        ref other => panic!("Expected Curse mode choice, got {:?}", other),
+    }
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 0 },
+        epoch,
+    ).unwrap();
+
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => {
+            assert_eq!(choice.options.len(), 2);
+            assert!(matches!(
+                choice.resolution,
+                mk_types::pending::ChoiceResolution::CurseAttackIndex { attack_count: 2, .. }
+            ));
+        }
+        ref other => panic!("Expected Curse attack-index choice, got {:?}", other),
+    }
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 1 },
+        epoch,
+    ).unwrap();
+
+    assert!(!state.players[0].pending.has_active());
+    assert_eq!(
+        curse_modifier(&state),
+        (mk_types::modifier::EnemyStat::Attack, -2, 0, Some(1)),
+    );
+}
+
 // =============================================================================
 // Forked Lightning tests
 // =============================================================================
@@ -1226,7 +1749,7 @@ fn forked_lightning_three_targets() {
     ).unwrap();
     let combat = state.combat.as_ref().unwrap();
     assert_eq!(combat.per_enemy_attack.len(), 3, "All 3 enemies should have per-enemy attack");
-    for (_, bonus) in combat.per_enemy_attack.iter() {
+    for bonus in combat.per_enemy_attack.values() {
         assert_eq!(bonus.ranged, 1);
         assert_eq!(bonus.ranged_elements.cold_fire, 1);
     }
@@ -1288,6 +1811,113 @@ fn forked_lightning_done_early() {
     assert_eq!(state.players[0].combat_accumulator.attack.ranged, 0);
 }
 
+/// Enter combat against four Prowlers and activate Braevalar's Forked Lightning.
+fn forked_lightning_vs_four_prowlers() -> (GameState, UndoStack) {
+    let (mut state, mut undo) = setup_with_skill(Hero::Braevalar, "braevalar_forked_lightning");
+    let tokens = vec![
+        EnemyTokenId::from("prowlers_1"),
+        EnemyTokenId::from("prowlers_2"),
+        EnemyTokenId::from("prowlers_3"),
+        EnemyTokenId::from("prowlers_4"),
+    ];
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &tokens, false, None, Default::default(),
+    ).unwrap();
+    activate_skill(&mut state, &mut undo, "braevalar_forked_lightning");
+    (state, undo)
+}
+
+#[test]
+fn forked_lightning_four_enemies_caps_at_three_targets() {
+    let (mut state, mut undo) = forked_lightning_vs_four_prowlers();
+
+    // Options per pick: 4 targets (no "Done"), then 3 + "Done", then 2 + "Done"
+    for expected_options in [4, 4, 3] {
+        match state.players[0].pending.active {
+            Some(ActivePending::Choice(ref choice)) => {
+                assert_eq!(choice.options.len(), expected_options);
+            }
+            ref other => panic!("Expected Forked Lightning choice, got {:?}", other),
+        }
+        let epoch = state.action_epoch;
+        apply_legal_action(
+            &mut state, &mut undo, 0,
+            &LegalAction::ResolveChoice { choice_index: 0 },
+            epoch,
+        ).unwrap();
+    }
+
+    // Third hit spends the last point even though enemy_3 is still untargeted
+    assert!(!state.players[0].pending.has_active());
+    let combat = state.combat.as_ref().unwrap();
+    assert_eq!(combat.per_enemy_attack.len(), 3);
+    for id in ["enemy_0", "enemy_1", "enemy_2"] {
+        assert_eq!(combat.per_enemy_attack.get(id).unwrap().ranged, 1, "{id} should be hit once");
+    }
+    assert!(!combat.per_enemy_attack.contains_key("enemy_3"));
+}
+
+#[test]
+fn forked_lightning_skips_enemy_defeated_mid_loop() {
+    let (mut state, mut undo) = forked_lightning_vs_four_prowlers();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 0 },
+        epoch,
+    ).unwrap();
+
+    // enemy_1 goes down between picks: the eligible list is re-read from the roster
+    state.combat.as_mut().unwrap().enemies[1].is_defeated = true;
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 0 },
+        epoch,
+    ).unwrap();
+
+    let combat = state.combat.as_ref().unwrap();
+    assert!(!combat.per_enemy_attack.contains_key("enemy_1"));
+    assert_eq!(combat.per_enemy_attack.get("enemy_2").unwrap().ranged, 1);
+
+    // Last pick offers only enemy_3 + "Done"
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => assert_eq!(choice.options.len(), 2),
+        ref other => panic!("Expected Forked Lightning choice, got {:?}", other),
+    }
+}
+
+#[test]
+fn forked_lightning_four_enemies_done_after_second_pick() {
+    let (mut state, mut undo) = forked_lightning_vs_four_prowlers();
+    for _ in 0..2 {
+        let epoch = state.action_epoch;
+        apply_legal_action(
+            &mut state, &mut undo, 0,
+            &LegalAction::ResolveChoice { choice_index: 0 },
+            epoch,
+        ).unwrap();
+    }
+
+    // Third pick: 2 untargeted enemies + "Done"; choose "Done"
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => assert_eq!(choice.options.len(), 3),
+        ref other => panic!("Expected Forked Lightning choice, got {:?}", other),
+    }
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 2 },
+        epoch,
+    ).unwrap();
+
+    assert!(!state.players[0].pending.has_active());
+    let combat = state.combat.as_ref().unwrap();
+    assert_eq!(combat.per_enemy_attack.len(), 2);
+    assert!(combat.per_enemy_attack.contains_key("enemy_0"));
+    assert!(combat.per_enemy_attack.contains_key("enemy_1"));
+}
+
 /// FAQ S3: Against a single enemy, only 1 of the 3 Forked Lightning points applies.
 /// The per-enemy attack is bound to the targeted enemy, so a single enemy gets +1 only.
 #[test]
@@ -1354,7 +1984,7 @@ fn forked_lightning_per_enemy_attack_only_counts_for_target() {
     // enemy_0 has +1 ranged, enemy_1 has none
     let combat = state.combat.as_ref().unwrap();
     assert_eq!(combat.per_enemy_attack.get("enemy_0").unwrap().ranged, 1);
-    assert!(combat.per_enemy_attack.get("enemy_1").is_none());
+    assert!(!combat.per_enemy_attack.contains_key("enemy_1"));
 
     // Sufficiency check: enemy_0 alone gets the +1 bonus
     let target_0 = vec![mk_types::ids::CombatInstanceId::from("enemy_0")];
@@ -1517,6 +2147,68 @@ fn know_your_prey_convert_element() {
     assert!(modifier.is_some(), "Should have ConvertAttackElement(Ice→Physical) modifier");
 }
 
+#[test]
+fn know_your_prey_physical_only_enemies_not_eligible() {
+    // Prowlers: no abilities, resistances or elemental attack.
+    // Orc Summoners: Summon is not something Know Your Prey strips.
+    assert!(!has_strippable_attributes_pub("prowlers"));
+    assert!(!has_strippable_attributes_pub("orc_summoners"));
+
+    let (mut state, _undo) = setup_with_skill(Hero::Wolfhawk, "wolfhawk_know_your_prey");
+    let tokens = vec![EnemyTokenId::from("prowlers_1"), EnemyTokenId::from("orc_summoners_1")];
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &tokens, false, None, Default::default(),
+    ).unwrap();
+    let actions = crate::legal_actions::enumerate_legal_actions(&state, 0).actions;
+    assert!(!actions.iter().any(|a| matches!(a,
+        LegalAction::UseSkill { skill_id } if skill_id.as_str() == "wolfhawk_know_your_prey"
+    )));
+}
+
+#[test]
+fn know_your_prey_fortified_resistant_enemy_offers_each_attribute() {
+    use mk_types::pending::KnowYourPreyApplyOption;
+    let (mut state, mut undo) = setup_with_skill(Hero::Wolfhawk, "wolfhawk_know_your_prey");
+    // Prowlers are not eligible, so the Lava Dragon is targeted directly
+    let tokens = vec![EnemyTokenId::from("prowlers_1"), EnemyTokenId::from("lava_dragon_1")];
+    crate::combat::execute_enter_combat(
+        &mut state, 0, &tokens, false, None, Default::default(),
+    ).unwrap();
+    activate_skill(&mut state, &mut undo, "wolfhawk_know_your_prey");
+
+    // Lava Dragon: Fortified + Brutal, Fire resistance, Fire attack
+    let options = match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => match choice.resolution {
+            mk_types::pending::ChoiceResolution::KnowYourPreyOption { ref enemy_instance_id, ref options } => {
+                assert_eq!(enemy_instance_id, "enemy_1");
+                options.clone()
+            }
+            ref other => panic!("Expected Know Your Prey options, got {:?}", other),
+        },
+        ref other => panic!("Expected Know Your Prey choice, got {:?}", other),
+    };
+    assert_eq!(options, vec![
+        KnowYourPreyApplyOption::NullifyAbility { ability: EnemyAbilityType::Brutal },
+        KnowYourPreyApplyOption::NullifyAbility { ability: EnemyAbilityType::Fortified },
+        KnowYourPreyApplyOption::RemoveResistance { element: ResistanceElement::Fire },
+        KnowYourPreyApplyOption::ConvertElement { from: Element::Fire, to: Element::Physical },
+    ]);
+
+    resolve_choice(&mut state, &mut undo, 1);
+    assert_eq!(state.active_modifiers.len(), 1);
+    let modifier = &state.active_modifiers[0];
+    assert!(matches!(
+        &modifier.scope,
+        mk_types::modifier::ModifierScope::OneEnemy { enemy_id } if enemy_id == "enemy_1"
+    ));
+    assert!(matches!(
+        modifier.effect,
+        mk_types::modifier::ModifierEffect::AbilityNullifier {
+            ability: Some(EnemyAbilityType::Fortified), ignore_arcane_immunity: false,
+        }
+    ));
+}
+
 #[test]
 fn verify_card_actions_after_tactic_selection() {
     // Reproduces the exact server flow: create game, select tactic, enumerate.
@@ -1625,6 +2317,34 @@ fn wolfs_howl_scales_with_empty_slots() {
     assert_eq!(val, 6);
 }
 
+#[test]
+fn wolfs_howl_modifier_is_four_plus_empty_command_slots() {
+    // (level, units, expected): slots are 1/2/3/4/5 at levels 1/3/5/7/9
+    for (level, units, expected) in [(3, 0, 6), (7, 1, 7), (9, 5, 4), (1, 2, 4)] {
+        let (mut state, mut undo) = setup_with_skill(Hero::Wolfhawk, "wolfhawk_wolfs_howl");
+        state.players[0].level = level;
+        for i in 0..units {
+            state.players[0].units.push(mk_types::state::PlayerUnit {
+                instance_id: mk_types::ids::UnitInstanceId::from(format!("unit_{i}")),
+                unit_id: mk_types::ids::UnitId::from("peasants"),
+                level: 1,
+                state: UnitState::Ready,
+                wounded: false,
+                used_resistance_this_combat: false,
+                used_ability_indices: vec![],
+                mana_token: None,
+            });
+        }
+        activate_skill(&mut state, &mut undo, "wolfhawk_wolfs_howl");
+
+        let values: Vec<u32> = state.active_modifiers.iter().filter_map(|m| match m.effect {
+            mk_types::modifier::ModifierEffect::SidewaysValue { new_value, .. } => Some(new_value),
+            _ => None,
+        }).collect();
+        assert_eq!(values, vec![expected], "level {level} with {units} units");
+    }
+}
+
 #[test]
 fn wolfs_howl_mutual_exclusivity() {
     let (mut state, mut undo) = setup_with_skill(Hero::Wolfhawk, "wolfhawk_wolfs_howl");
@@ -1721,6 +2441,70 @@ fn puppet_master_block_element_from_resistance() {
     assert_eq!(derive_block_element_from_enemy("prowlers"), Element::Physical);
 }
 
+/// Expend a single kept token through Puppet Master and return the accumulator.
+fn expend_puppet_master_token(
+    enemy_id: &str,
+    attack: u32,
+    attack_element: Element,
+    armor: u32,
+    choice_index: usize,
+) -> CombatAccumulator {
+    let (mut state, mut undo) = setup_with_skill(Hero::Krang, "krang_puppet_master");
+    state.combat = Some(Box::new(CombatState::default()));
+    state.players[0].kept_enemy_tokens.push(mk_types::state::KeptEnemyToken {
+        enemy_id: mk_types::ids::EnemyId::from(enemy_id),
+        name: enemy_id.to_string(),
+        attack,
+        attack_element,
+        armor,
+    });
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::UseSkill { skill_id: mk_types::ids::SkillId::from("krang_puppet_master") },
+        epoch,
+    ).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index },
+        epoch,
+    ).unwrap();
+    state.players[0].combat_accumulator.clone()
+}
+
+#[test]
+fn puppet_master_block_uses_resistance_element_and_rounds_up() {
+    // Fire resistance → Ice block; armor 5 → block 3
+    let acc = expend_puppet_master_token("skeletal_warriors", 3, Element::Physical, 5, 1);
+    assert_eq!(acc.block, 3);
+    assert_eq!(acc.block_elements.ice, 3);
+    // Ice resistance → Fire block; armor 4 → block 2
+    let acc = expend_puppet_master_token("crystal_sprites", 3, Element::Physical, 4, 1);
+    assert_eq!(acc.block, 2);
+    assert_eq!(acc.block_elements.fire, 2);
+    // Both → ColdFire block; armor 1 → block 1
+    let acc = expend_puppet_master_token("orc_war_beasts", 3, Element::Physical, 1, 1);
+    assert_eq!(acc.block, 1);
+    assert_eq!(acc.block_elements.cold_fire, 1);
+    // Neither → Physical block
+    let acc = expend_puppet_master_token("prowlers", 4, Element::Physical, 7, 1);
+    assert_eq!(acc.block, 4);
+    assert_eq!(acc.block_elements.physical, 4);
+    assert_eq!(acc.attack.normal, 0);
+}
+
+#[test]
+fn puppet_master_attack_keeps_enemy_element_and_rounds_up() {
+    let acc = expend_puppet_master_token("prowlers", 5, Element::Fire, 3, 0);
+    assert_eq!(acc.attack.normal, 3);
+    assert_eq!(acc.attack.normal_elements.fire, 3);
+    let acc = expend_puppet_master_token("prowlers", 7, Element::ColdFire, 3, 0);
+    assert_eq!(acc.attack.normal, 4);
+    assert_eq!(acc.attack.normal_elements.cold_fire, 4);
+    assert_eq!(acc.block, 0);
+}
+
 #[test]
 fn puppet_master_token_removed_after_use() {
     let (mut state, mut undo) = setup_puppet_master_state();
@@ -1886,6 +2670,84 @@ fn shapeshift_modifier_applied() {
     ));
 }
 
+#[test]
+fn shapeshift_choice_card_classified_by_first_option() {
+    use mk_types::modifier::ShapeshiftTarget;
+    // Block first, Attack second → classified as Block, keeping the block element
+    let block_first = CardEffect::Choice {
+        options: vec![
+            CardEffect::GainBlock { amount: 3, element: Element::Ice },
+            CardEffect::GainAttack { amount: 2, combat_type: CombatType::Melee, element: Element::Physical },
+        ],
+    };
+    assert_eq!(
+        classify_effect_for_shapeshift(&block_first),
+        Some((ShapeshiftTarget::Block, 3, Some(Element::Ice))),
+    );
+    assert_eq!(
+        classify_basic_action_for_shapeshift("rage"),
+        Some((ShapeshiftTarget::Attack, 2, Some(Element::Physical))),
+    );
+}
+
+#[test]
+fn shapeshift_rage_to_move_redirects_played_value() {
+    use mk_types::modifier::ShapeshiftTarget;
+    // Move is only usable in combat against Cumbersome enemies during Block
+    let (mut state, mut undo) = setup_combat_with_skill(Hero::Braevalar, "braevalar_shapeshift", &["orc_stonethrowers"]);
+    state.combat.as_mut().unwrap().phase = CombatPhase::Block;
+    state.players[0].hand = vec![CardId::from("rage")];
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::UseSkill { skill_id: mk_types::ids::SkillId::from("braevalar_shapeshift") },
+        epoch,
+    ).unwrap();
+    // Rage is auto-selected; it can become the two non-Attack types
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => {
+            assert_eq!(choice.options.len(), 2, "Move and Block");
+            assert!(matches!(
+                choice.resolution,
+                mk_types::pending::ChoiceResolution::ShapeshiftTypeSelect {
+                    original_type: ShapeshiftTarget::Attack, amount: 2, ..
+                }
+            ));
+        }
+        ref other => panic!("Expected Shapeshift type choice, got {:?}", other),
+    }
+    // Move (index 0)
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::ResolveChoice { choice_index: 0 },
+        epoch,
+    ).unwrap();
+
+    let move_before = state.players[0].move_points;
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state, &mut undo, 0,
+        &LegalAction::PlayCardBasic { hand_index: 0, card_id: CardId::from("rage") },
+        epoch,
+    ).unwrap();
+    // Attack 2 became Move 2; Block 2 is still offered
+    match state.players[0].pending.active {
+        Some(ActivePending::Choice(ref choice)) => {
+            assert_eq!(choice.options.len(), 2);
+            assert!(matches!(choice.options[0], CardEffect::GainMove { amount: 2 }));
+            assert!(matches!(choice.options[1], CardEffect::GainBlock { amount: 2, .. }));
+        }
+        ref other => panic!("Expected Rage choice, got {:?}", other),
+    }
+    resolve_choice(&mut state, &mut undo, 0);
+    assert_eq!(state.players[0].move_points, move_before + 2);
+    assert_eq!(state.players[0].combat_accumulator.attack.normal, 0);
+    assert!(!state.active_modifiers.iter().any(|m|
+        matches!(m.effect, mk_types::modifier::ModifierEffect::ShapeshiftActive { .. })),
+        "Shapeshift modifier is consumed by the play");
+}
+
 // =========================================================================
 // Batch 6: Deep Gap Coverage
 // =========================================================================
@@ -1957,6 +2819,61 @@ fn puppet_master_not_available_outside_combat() {
         LegalAction::UseSkill { ref skill_id } if skill_id.as_str() == "krang_puppet_master")));
 }
 
+#[test]
+fn puppet_master_keeps_defeated_token_at_combat_end() {
+    let (mut state, mut undo) =
+        setup_combat_with_skill(Hero::Krang, "krang_puppet_master", &["prowlers", "diggers"]);
+    let combat = state.combat.as_mut().unwrap();
+    combat.enemies[0].is_defeated = true;
+    combat.enemies[1].is_defeated = true;
+    state.enemy_tokens.green_discard.push(mk_types::ids::EnemyTokenId::from("prowlers_1"));
+    super::combat_end::end_combat(&mut state, 0);
+
+    match &state.players[0].pending.active {
+        Some(ActivePending::Choice(c)) => {
+            assert_eq!(c.options.len(), 3, "Skip + one per defeated enemy");
+            assert!(matches!(&c.resolution,
+                mk_types::pending::ChoiceResolution::PuppetMasterKeepToken { enemy_ids } if enemy_ids.len() == 2));
+        }
+        other => panic!("Expected PuppetMasterKeepToken choice, got {:?}", other),
+    }
+    resolve_choice(&mut state, &mut undo, 1);
+
+    let kept = &state.players[0].kept_enemy_tokens;
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].enemy_id.as_str(), "prowlers");
+    assert_eq!((kept[0].attack, kept[0].armor), (4, 3));
+    assert!(state.enemy_tokens.green_discard.is_empty(), "Kept token leaves the discard pile");
+    // Keeping is this turn's use of the skill.
+    assert!(state.players[0].skill_cooldowns.used_this_turn
+        .iter().any(|s| s.as_str() == "krang_puppet_master"));
+}
+
+#[test]
+fn puppet_master_keep_can_be_skipped() {
+    let (mut state, mut undo) =
+        setup_combat_with_skill(Hero::Krang, "krang_puppet_master", &["prowlers"]);
+    state.combat.as_mut().unwrap().enemies[0].is_defeated = true;
+    super::combat_end::end_combat(&mut state, 0);
+    resolve_choice(&mut state, &mut undo, 0);
+
+    assert!(state.players[0].kept_enemy_tokens.is_empty());
+    assert!(state.players[0].skill_cooldowns.used_this_turn.is_empty());
+    assert!(!state.players[0].pending.has_active());
+}
+
+#[test]
+fn puppet_master_keep_not_offered_after_skill_used_this_turn() {
+    let (mut state, _undo) =
+        setup_combat_with_skill(Hero::Krang, "krang_puppet_master", &["prowlers"]);
+    state.players[0].skill_cooldowns.used_this_turn
+        .push(mk_types::ids::SkillId::from("krang_puppet_master"));
+    state.combat.as_mut().unwrap().enemies[0].is_defeated = true;
+    super::combat_end::end_combat(&mut state, 0);
+
+    assert!(!state.players[0].pending.has_active());
+}
+
 // ---- Dueling: additional tests ----
 
 #[test]
@@ -172,6 +172,54 @@ fn rethink_empty_set_keeps_hand_unchanged() {
     assert!(!state.players[0].pending.has_active());
 }
 
+/// Apply `action` for `player_idx` after checking it is in the enumerated legal set.
+fn apply_enumerated(state: &mut GameState, undo: &mut UndoStack, player_idx: usize, action: &LegalAction) {
+    let set = enumerate_legal_actions_with_undo(state, player_idx, undo);
+    assert!(set.actions.contains(action), "{action:?} not legal: {:?}", set.actions);
+    apply_legal_action(state, undo, player_idx, action, set.epoch).unwrap();
+}
+
+#[test]
+fn pity_offer_survives_rethink_pick_and_surfaces_on_first_turn() {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    state.scenario_config.variants = vec![Variant::EnvyAndPity];
+    state.players[0].fame = 10;
+    state.players[1].fame = 4;
+    state.time_of_day = TimeOfDay::Night;
+    state.players[1].discard.push(CardId::from("wound"));
+    crate::end_turn::end_round(&mut state);
+
+    // Last-place player 1 takes Rethink (goes first), player 0 takes Planning
+    let mut undo = UndoStack::new();
+    while state.round_phase == RoundPhase::TacticsSelection {
+        let selector = state.current_tactic_selector.clone().unwrap();
+        let idx = state.players.iter().position(|p| p.id == selector).unwrap();
+        let tactic = if idx == 1 { "rethink" } else { "planning" };
+        apply_enumerated(&mut state, &mut undo, idx, &LegalAction::SelectTactic {
+            tactic_id: mk_types::ids::TacticId::from(tactic),
+        });
+    }
+    assert_eq!(state.turn_order[state.current_player_index as usize], state.players[1].id);
+    assert!(matches!(
+        state.players[1].pending.active,
+        Some(ActivePending::SubsetSelection(ref ss)) if ss.kind == mk_types::pending::SubsetSelectionKind::Rethink
+    ));
+
+    // Rethink resolves first; the Pity offer follows instead of being lost
+    apply_enumerated(&mut state, &mut undo, 1, &LegalAction::SubsetConfirm);
+    assert!(matches!(
+        state.players[1].pending.active,
+        Some(ActivePending::Choice(ref c))
+            if matches!(c.resolution, mk_types::pending::ChoiceResolution::PityWoundRemoval)
+    ));
+
+    apply_enumerated(&mut state, &mut undo, 1, &LegalAction::ResolveChoice { choice_index: 1 });
+    let player = &state.players[1];
+    assert!(!player.hand.iter().chain(player.deck.iter()).any(|c| c.as_str() == "wound"));
+    assert!(!player.pending.has_active());
+    assert!(!player.pending.has_deferred());
+}
+
 #[test]
 fn select_mana_steal_creates_pending() {
     let mut state = create_solo_game(42, Hero::Arythea);
@@ -258,7 +306,7 @@ fn mana_steal_die_counts_as_mana_source_for_card_playability() {
 
     // Ensure march (green-powered, GainMove) is in hand
     let march_id = CardId::from("march");
-    if !state.players[0].hand.iter().any(|c| *c == march_id) {
+    if !state.players[0].hand.contains(&march_id) {
         state.players[0].hand.push(march_id.clone());
     }
 
@@ -329,7 +377,7 @@ fn mana_steal_die_appears_in_collect_mana_sources() {
         }
     }
 
-    let sources = crate::card_play::collect_mana_sources(&state, 0, BasicManaColor::Red);
+    let sources = crate::card_play::collect_mana_sources(&state, 0, BasicManaColor::Red, false);
     let has_die_source = sources.iter().any(|s| s.source_type == ManaSourceType::Die);
     assert!(
         has_die_source,
@@ -412,6 +460,60 @@ fn preparation_resolution_moves_card_to_hand() {
     assert!(!state.players[0].pending.has_active());
 }
 
+#[test]
+fn preparation_takes_chosen_copy_from_deck_with_duplicates() {
+    let mut state = setup_playing_game(vec!["march"]);
+    let deck: Vec<CardId> = ["rage", "march", "stamina", "march", "rage"]
+        .iter()
+        .map(|c| CardId::from(*c))
+        .collect();
+    state.players[0].deck = deck.clone();
+    state.players[0].pending.active = Some(ActivePending::TacticDecision(
+        PendingTacticDecision::Preparation { deck_snapshot: deck },
+    ));
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::ResolveTacticDecision {
+            data: TacticDecisionData::Preparation { deck_card_index: 3 },
+        },
+        epoch,
+    )
+    .unwrap();
+
+    let player = &state.players[0];
+    assert_eq!(player.hand, vec![CardId::from("march"), CardId::from("march")]);
+    let mut remaining: Vec<&str> = player.deck.iter().map(|c| c.as_str()).collect();
+    remaining.sort_unstable();
+    assert_eq!(remaining, vec!["march", "rage", "rage", "stamina"]);
+    assert!(!player.pending.has_active());
+}
+
+#[test]
+fn preparation_rejects_stale_snapshot() {
+    let mut state = setup_playing_game(vec!["march"]);
+    state.players[0].deck = vec![CardId::from("rage"), CardId::from("stamina")];
+    state.players[0].pending.active = Some(ActivePending::TacticDecision(
+        PendingTacticDecision::Preparation {
+            deck_snapshot: vec![CardId::from("stamina"), CardId::from("rage")],
+        },
+    ));
+
+    let result = super::tactics::apply_resolve_tactic_decision(
+        &mut state,
+        0,
+        &TacticDecisionData::Preparation { deck_card_index: 0 },
+    );
+
+    assert!(result.is_err(), "a snapshot that no longer matches the deck is rejected");
+    assert_eq!(state.players[0].deck.len(), 2);
+    assert_eq!(state.players[0].hand, vec![CardId::from("march")]);
+}
+
 #[test]
 fn activate_the_right_moment_sets_extra_turn() {
     let mut state = setup_playing_game(vec!["march"]);
@@ -463,6 +565,86 @@ fn activate_long_night_moves_discard_to_deck() {
     assert!(state.players[0].flags.contains(PlayerFlags::TACTIC_FLIPPED));
 }
 
+#[test]
+fn long_night_moves_three_random_discards_and_keeps_the_rest() {
+    let mut state = setup_playing_game(vec!["march"]);
+    state.players[0].selected_tactic = Some(mk_types::ids::TacticId::from("long_night"));
+    state.players[0].deck.clear();
+    let discard: Vec<CardId> = ["stamina", "rage", "swiftness", "concentration", "promise"]
+        .iter()
+        .map(|c| CardId::from(*c))
+        .collect();
+    state.players[0].discard = discard.clone();
+    let hand_before = state.players[0].hand.clone();
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ActivateTactic, epoch).unwrap();
+
+    let player = &state.players[0];
+    assert_eq!(player.deck.len(), 3, "exactly 3 cards go back into the deck");
+    assert_eq!(player.discard.len(), 2);
+    assert_eq!(player.hand, hand_before, "Long Night draws nothing");
+    let mut all: Vec<CardId> = player.deck.iter().chain(player.discard.iter()).cloned().collect();
+    all.sort();
+    let mut expected = discard;
+    expected.sort();
+    assert_eq!(all, expected, "cards are moved, never created or lost");
+    assert!(player.flags.contains(PlayerFlags::TACTIC_FLIPPED));
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(!legal.actions.contains(&LegalAction::ActivateTactic));
+}
+
+#[test]
+fn long_night_moves_whole_discard_when_fewer_than_three() {
+    let mut state = setup_playing_game(vec!["march"]);
+    state.players[0].selected_tactic = Some(mk_types::ids::TacticId::from("long_night"));
+    state.players[0].deck.clear();
+    state.players[0].discard = vec![CardId::from("stamina"), CardId::from("rage")];
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ActivateTactic, epoch).unwrap();
+
+    assert_eq!(state.players[0].deck.len(), 2);
+    assert!(state.players[0].discard.is_empty());
+}
+
+#[test]
+fn midnight_meditation_leaves_discard_pile_alone() {
+    let mut state = setup_playing_game(vec![
+        "march", "stamina", "rage", "swiftness", "concentration", "promise",
+    ]);
+    state.players[0].selected_tactic =
+        Some(mk_types::ids::TacticId::from("midnight_meditation"));
+    state.players[0].deck = vec![CardId::from("determination"), CardId::from("tranquility")];
+    state.players[0].discard = vec![CardId::from("crystallize"), CardId::from("mana_draw")];
+    let discard_before = state.players[0].discard.clone();
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ActivateTactic, epoch).unwrap();
+    match &state.players[0].pending.active {
+        Some(ActivePending::SubsetSelection(ss)) => assert_eq!(ss.max_selections, 5),
+        other => panic!("Expected SubsetSelection, got {:?}", other),
+    }
+
+    // Selecting the maximum of 5 finalizes the selection automatically.
+    for index in 0..5 {
+        let epoch = state.action_epoch;
+        apply_legal_action(&mut state, &mut undo, 0, &LegalAction::SubsetSelect { index }, epoch)
+            .unwrap();
+    }
+    assert!(!state.players[0].pending.has_active());
+
+    let player = &state.players[0];
+    assert_eq!(player.hand.len(), 6, "draws as many as were shuffled back");
+    assert!(player.hand.contains(&CardId::from("promise")), "unselected card stays");
+    assert_eq!(player.deck.len(), 2, "5 shuffled in + 2 deck - 5 drawn");
+    assert_eq!(player.discard, discard_before, "discard pile is not shuffled in");
+    assert!(player.flags.contains(PlayerFlags::TACTIC_FLIPPED));
+}
+
 #[test]
 fn activate_midnight_meditation_creates_pending() {
     let mut state = setup_playing_game(vec!["march", "stamina", "rage"]);
@@ -659,6 +841,76 @@ fn sparing_power_take_moves_stored_to_hand() {
     assert!(!state.players[0].flags.contains(PlayerFlags::BEFORE_TURN_TACTIC_PENDING));
 }
 
+#[test]
+fn sparing_power_lifecycle_across_turns() {
+    let mut state = create_solo_game(42, Hero::Arythea);
+    state.available_tactics.push(mk_types::ids::TacticId::from("sparing_power"));
+    let mut undo = UndoStack::new();
+
+    let resolve = |state: &mut GameState, undo: &mut UndoStack, data: TacticDecisionData| {
+        let epoch = state.action_epoch;
+        apply_legal_action(state, undo, 0, &LegalAction::ResolveTacticDecision { data }, epoch)
+            .unwrap();
+    };
+    let play_sideways_and_end_turn = |state: &mut GameState, undo: &mut UndoStack| {
+        let legal = enumerate_legal_actions_with_undo(state, 0, undo);
+        let play = legal
+            .actions
+            .iter()
+            .find(|a| matches!(a, LegalAction::PlayCardSideways { .. }))
+            .cloned()
+            .expect("should have a sideways play");
+        apply_legal_action(state, undo, 0, &play, legal.epoch).unwrap();
+        let epoch = state.action_epoch;
+        apply_legal_action(state, undo, 0, &LegalAction::EndTurn, epoch).unwrap();
+    };
+    let prompted = |state: &GameState| {
+        matches!(
+            state.players[0].pending.active,
+            Some(ActivePending::TacticDecision(PendingTacticDecision::SparingPower))
+        )
+    };
+
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::SelectTactic {
+            tactic_id: mk_types::ids::TacticId::from("sparing_power"),
+        },
+        epoch,
+    )
+    .unwrap();
+
+    // First turn of the round: prompt appears without any end-turn in between.
+    assert!(prompted(&state), "prompt should appear before the first turn");
+    let first = state.players[0].deck[0].clone();
+    resolve(&mut state, &mut undo, TacticDecisionData::SparingPowerStash);
+    play_sideways_and_end_turn(&mut state, &mut undo);
+
+    // Second turn: prompted again, stash another.
+    assert!(prompted(&state), "prompt should appear before every turn while unflipped");
+    let second = state.players[0].deck[0].clone();
+    resolve(&mut state, &mut undo, TacticDecisionData::SparingPowerStash);
+    assert_eq!(state.players[0].tactic_state.sparing_power_stored, vec![first.clone(), second.clone()]);
+    play_sideways_and_end_turn(&mut state, &mut undo);
+
+    // Third turn: take everything stored.
+    assert!(prompted(&state));
+    let hand_before = state.players[0].hand.len();
+    resolve(&mut state, &mut undo, TacticDecisionData::SparingPowerTake);
+    let player = &state.players[0];
+    assert_eq!(player.hand.len(), hand_before + 2);
+    assert!(player.hand.contains(&first) && player.hand.contains(&second));
+    assert!(player.tactic_state.sparing_power_stored.is_empty());
+    assert!(player.flags.contains(PlayerFlags::TACTIC_FLIPPED));
+    play_sideways_and_end_turn(&mut state, &mut undo);
+
+    // Flipped: no more prompts.
+    assert!(!prompted(&state));
+}
+
 #[test]
 fn turn_order_sorted_by_tactic_number() {
     let mut state = create_solo_game(42, Hero::Arythea);
@@ -837,6 +1089,21 @@ fn activate_trm_during_combat() {
     );
 }
 
+#[test]
+fn activate_trm_during_combat_keeps_combat_running() {
+    let mut state = setup_combat_game(&["prowlers"]);
+    state.players[0].selected_tactic =
+        Some(mk_types::ids::TacticId::from("the_right_moment"));
+
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ActivateTactic, epoch).unwrap();
+
+    assert!(state.combat.is_some(), "activating mid-combat should not end combat");
+    assert!(state.players[0].tactic_state.extra_turn_pending);
+    assert!(state.players[0].flags.contains(PlayerFlags::TACTIC_FLIPPED));
+}
+
 #[test]
 fn long_night_enumerated_when_deck_empty() {
     let mut state = setup_playing_game(vec!["march"]);
@@ -256,6 +256,87 @@ fn training_no_matching_clears_pending() {
     assert!(state.players[0].removed_cards.iter().any(|c| c.as_str() == "rage"));
 }
 
+#[test]
+fn training_rejects_throwing_wound() {
+    let mut state = setup_playing_game(vec!["training", "wound", "march"]);
+    state.offers.advanced_actions = vec![CardId::from("refreshing_walk")];
+    state.decks.advanced_action_deck = vec![];
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::PlayCardBasic {
+        hand_index: 0, card_id: CardId::from("training"),
+    }, epoch).unwrap();
+    // Hand is [wound, march]; throwing the wound is refused and nothing moves.
+    let epoch = state.action_epoch;
+    let result = apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveTraining {
+        selection_index: 0,
+    }, epoch);
+    assert!(result.is_err());
+    assert_eq!(state.players[0].hand.len(), 2);
+    assert!(state.players[0].removed_cards.is_empty());
+    assert!(matches!(
+        state.players[0].pending.active,
+        Some(ActivePending::Training(ref t)) if t.phase == BookOfWisdomPhase::SelectCard
+    ));
+}
+
+#[test]
+fn training_no_matching_gains_nothing() {
+    let mut state = setup_playing_game(vec!["training", "rage"]);
+    state.offers.advanced_actions = vec![CardId::from("refreshing_walk"), CardId::from("ice_bolt")];
+    state.decks.advanced_action_deck = vec![];
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::PlayCardBasic {
+        hand_index: 0, card_id: CardId::from("training"),
+    }, epoch).unwrap();
+    let hand_before = state.players[0].hand.len();
+    let discard_before = state.players[0].discard.len();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveTraining {
+        selection_index: 0,
+    }, epoch).unwrap();
+    assert_eq!(state.players[0].hand.len(), hand_before - 1, "rage thrown, nothing gained");
+    assert_eq!(state.players[0].discard.len(), discard_before);
+    assert_eq!(state.offers.advanced_actions.len(), 2, "offer untouched");
+}
+
+#[test]
+fn training_multi_match_takes_chosen_offer_card() {
+    let mut state = setup_playing_game(vec!["training", "march"]);
+    state.offers.advanced_actions = vec![
+        CardId::from("refreshing_walk"),
+        CardId::from("blood_rage"),
+        CardId::from("path_finding"),
+    ];
+    state.decks.advanced_action_deck = vec![];
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::PlayCardBasic {
+        hand_index: 0, card_id: CardId::from("training"),
+    }, epoch).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveTraining {
+        selection_index: 0,
+    }, epoch).unwrap();
+    // Only the two green AAs are offered, in offer order.
+    match &state.players[0].pending.active {
+        Some(ActivePending::Training(t)) => {
+            let offered: Vec<&str> = t.available_offer_cards.iter().map(|c| c.as_str()).collect();
+            assert_eq!(offered, vec!["refreshing_walk", "path_finding"]);
+        }
+        other => panic!("Expected Training SelectFromOffer, got {:?}", other),
+    }
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveTraining {
+        selection_index: 1,
+    }, epoch).unwrap();
+    assert!(state.players[0].discard.iter().any(|c| c.as_str() == "path_finding"));
+    let offer: Vec<&str> = state.offers.advanced_actions.iter().map(|c| c.as_str()).collect();
+    assert_eq!(offer, vec!["refreshing_walk", "blood_rage"]);
+    assert!(state.players[0].pending.active.is_none());
+}
+
 #[test]
 fn training_legal_actions_phase1() {
     let mut state = setup_playing_game(vec!["training", "march", "rage", "wound"]);
@@ -569,6 +650,39 @@ fn maximal_choice_card_creates_pending() {
     ));
 }
 
+#[test]
+fn maximal_choice_card_resolves_choice_once_per_multiplier() {
+    // rage basic = Choice(Attack 2 Melee / Block 2), ×3 → three separate choices
+    let mut state = setup_playing_game(vec!["maximal_effect", "rage"]);
+    state.offers.advanced_actions = vec![];
+    state.decks.advanced_action_deck = vec![];
+    state.combat = Some(Box::new(CombatState {
+        phase: CombatPhase::Attack,
+        ..CombatState::default()
+    }));
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::PlayCardBasic {
+        hand_index: 0, card_id: CardId::from("maximal_effect"),
+    }, epoch).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveMaximalEffect {
+        hand_index: 0,
+    }, epoch).unwrap();
+
+    for copy in 0..3 {
+        assert!(
+            matches!(&state.players[0].pending.active, Some(ActivePending::Choice(_))),
+            "copy {} should prompt its own choice",
+            copy + 1
+        );
+        resolve_choice(&mut state, &mut undo, 0);
+    }
+
+    assert!(state.players[0].pending.active.is_none());
+    assert_eq!(state.players[0].combat_accumulator.attack.normal, 6);
+}
+
 #[test]
 fn maximal_compound_triples_move() {
     // stamina basic = GainMove(2), ×3 = 6
@@ -624,3 +738,55 @@ fn maximal_pending_cleared_after_resolve() {
     assert!(state.players[0].pending.active.is_none());
 }
 
+// =========================================================================
+// Decompose card tests
+// =========================================================================
+
+#[test]
+fn decompose_legal_actions_only_action_cards() {
+    let mut state = setup_playing_game(vec!["decompose", "fireball", "march", "wound", "rage"]);
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::PlayCardBasic {
+        hand_index: 0, card_id: CardId::from("decompose"),
+    }, epoch).unwrap();
+    let actions = enumerate_legal_actions_with_undo(&state, 0, &UndoStack::new());
+    let indices: Vec<usize> = actions.actions.iter()
+        .filter_map(|a| match a {
+            LegalAction::ResolveDecompose { hand_index } => Some(*hand_index),
+            _ => None,
+        })
+        .collect();
+    // fireball (0) and wound (2) are not eligible; march (1) and rage (3) are
+    assert_eq!(indices, vec![1, 3]);
+}
+
+#[test]
+fn decompose_green_card_caps_crystals_and_removes_card() {
+    let mut state = setup_playing_game(vec!["decompose", "march"]);
+    state.players[0].crystals.green = 2;
+    state.players[0].pure_mana.clear();
+    let marches_in_deck = state.players[0].deck.iter().filter(|c| c.as_str() == "march").count();
+    let mut undo = UndoStack::new();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::PlayCardBasic {
+        hand_index: 0, card_id: CardId::from("decompose"),
+    }, epoch).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveDecompose {
+        hand_index: 0,
+    }, epoch).unwrap();
+
+    let p = &state.players[0];
+    assert!(p.pending.active.is_none());
+    // Two green crystals: one fills the cap, the other overflows into a token
+    assert_eq!(p.crystals.green, 3);
+    assert_eq!((p.crystals.red, p.crystals.blue, p.crystals.white), (0, 0, 0));
+    assert_eq!(p.pure_mana.len(), 1);
+    assert_eq!(p.pure_mana[0].color, ManaColor::Green);
+    // march leaves the game rather than going to discard
+    assert!(p.hand.is_empty());
+    assert_eq!(p.removed_cards, vec![CardId::from("march")]);
+    assert!(!p.discard.iter().any(|c| c.as_str() == "march"));
+    assert_eq!(p.deck.iter().filter(|c| c.as_str() == "march").count(), marches_in_deck);
+}
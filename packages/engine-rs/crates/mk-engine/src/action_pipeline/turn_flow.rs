@@ -168,6 +168,13 @@ pub(super) fn apply_announce_end_of_round(
     state: &mut GameState,
     player_idx: usize,
 ) -> Result<ApplyResult, ApplyError> {
+    if let Some(announcer) = &state.end_of_round_announced_by {
+        return Err(ApplyError::InternalError(format!(
+            "End of round already announced by {}",
+            announcer.as_str()
+        )));
+    }
+
     let player_id = state.players[player_idx].id.clone();
     state.end_of_round_announced_by = Some(player_id.clone());
 
@@ -268,6 +275,10 @@ pub(super) fn apply_complete_rest(
             player.hand.remove(idx);
             player.discard.push(chosen_card);
         }
+    } else if !player.hand.is_empty() {
+        return Err(ApplyError::InternalError(
+            "CompleteRest: must discard a card when hand is not empty".into(),
+        ));
     }
     // else: empty hand — no discard needed.
 
@@ -305,9 +316,12 @@ pub(super) fn apply_undo(
 ) -> Result<ApplyResult, ApplyError> {
     match undo_stack.undo() {
         Some(restored) => {
+            // Keep the current epoch: the caller increments it after this returns,
+            // so the restored state gets a new epoch instead of reusing one that
+            // an action set from before the undo may still carry.
+            let epoch = state.action_epoch;
             *state = restored;
-            // Note: epoch will be incremented by the caller after this returns,
-            // which is correct — the restored state gets a new epoch.
+            state.action_epoch = epoch;
             Ok(ApplyResult {
                 needs_reenumeration: true,
                 game_ended: false,
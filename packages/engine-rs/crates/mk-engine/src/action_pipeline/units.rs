@@ -25,6 +25,12 @@ pub(super) fn apply_recruit_unit(
     unit_id: &mk_types::ids::UnitId,
     influence_cost: u32,
 ) -> Result<ApplyResult, ApplyError> {
+    let level = mk_data::units::get_unit(unit_id.as_str())
+        .ok_or_else(|| {
+            ApplyError::InternalError(format!("RecruitUnit: unknown unit id '{}'", unit_id.as_str()))
+        })?
+        .level;
+
     // Apply blanket reputation + shield bonus (once per turn)
     crate::action_pipeline::sites::apply_interaction_bonus_if_needed(state, player_idx);
 
@@ -41,10 +47,6 @@ pub(super) fn apply_recruit_unit(
         mk_types::ids::UnitInstanceId::from(format!("unit_{}", state.next_instance_counter));
     state.next_instance_counter += 1;
 
-    let level = mk_data::units::get_unit(unit_id.as_str())
-        .map(|u| u.level)
-        .unwrap_or(1);
-
     let unit = PlayerUnit {
         instance_id,
         unit_id: unit_id.clone(),
@@ -238,7 +240,7 @@ pub(super) fn apply_activate_unit(
         crate::card_play::check_mana_enhancement_trigger(state, player_idx, consumed_color);
     }
 
-    // Compute UnitCombatBonus and UnitBlockBonus from active modifiers
+    // Compute UnitCombatBonus, UnitAttackBonus and UnitBlockBonus from active modifiers
     use mk_types::modifier::{ModifierEffect, ModifierScope};
     let player_id = &state.players[player_idx].id;
     let (unit_attack_bonus, unit_block_bonus): (i32, i32) = state
@@ -248,16 +250,13 @@ pub(super) fn apply_activate_unit(
             m.created_by_player_id == *player_id
                 && matches!(m.scope, ModifierScope::AllUnits)
         })
-        .fold((0i32, 0i32), |(atk, blk), m| {
-            if let ModifierEffect::UnitCombatBonus {
+        .fold((0i32, 0i32), |(atk, blk), m| match &m.effect {
+            ModifierEffect::UnitCombatBonus {
                 attack_bonus,
                 block_bonus,
-            } = &m.effect
-            {
-                (atk + *attack_bonus, blk + *block_bonus)
-            } else {
-                (atk, blk)
-            }
+            } => (atk + *attack_bonus, blk + *block_bonus),
+            ModifierEffect::UnitAttackBonus { amount } => (atk + *amount, blk),
+            _ => (atk, blk),
         });
 
     let extra_block_bonus: i32 = state
@@ -276,6 +275,12 @@ pub(super) fn apply_activate_unit(
         })
         .sum();
 
+    // Dueling: any unit ability used in combat forfeits the fame bonus. Marked
+    // up front so abilities that resolve through a pending choice count too.
+    if state.combat.is_some() {
+        skills_complex::mark_dueling_unit_involvement(state, player_idx);
+    }
+
     // Apply the ability effect
     use mk_data::units::UnitAbility;
     match slot.ability {
@@ -322,6 +327,7 @@ pub(super) fn apply_activate_unit(
             });
             player.healing_points += value.saturating_sub(to_heal);
             player.wounds_healed_from_hand_this_turn += healed;
+            crate::effect_queue::return_wounds_to_pile(state, healed);
         }
         UnitAbility::GainMana { color } => {
             state.players[player_idx].pure_mana.push(ManaToken {
@@ -680,11 +686,6 @@ pub(super) fn apply_activate_unit(
     // Mark unit spent
     state.players[player_idx].units[unit_idx].state = UnitState::Spent;
 
-    // Dueling: mark unit involvement if a combat ability was used
-    if state.combat.is_some() {
-        skills_complex::mark_dueling_unit_involvement(state, player_idx);
-    }
-
     Ok(ApplyResult {
         needs_reenumeration: true,
         game_ended: false,
@@ -967,7 +968,7 @@ pub(super) fn apply_resolve_select_enemy(
         continuation,
     } = pending
     {
-        if choice_index >= eligible_enemy_ids.len() {
+        if choice_index >= eligible_enemy_ids.len().max(1) {
             return Err(ApplyError::InternalError(format!(
                 "SelectCombatEnemy: invalid choice_index {} (eligible len {})",
                 choice_index,
@@ -975,8 +976,12 @@ pub(super) fn apply_resolve_select_enemy(
             )));
         }
 
-        let enemy_id = &eligible_enemy_ids[choice_index];
-        apply_select_enemy_effects(state, player_idx, &unit_instance_id, enemy_id, &template)?;
+        // A target defeated since the pending was created fizzles the effect
+        if let Some(enemy_id) = eligible_enemy_ids.get(choice_index) {
+            if is_live_combat_enemy(state, enemy_id) {
+                apply_select_enemy_effects(state, player_idx, &unit_instance_id, enemy_id, &template)?;
+            }
+        }
 
         // Replay any continuation effects from the effect queue
         if !continuation.is_empty() {
@@ -1019,6 +1024,11 @@ pub(super) fn apply_resolve_select_enemy(
                         }));
                 }
                 DrainResult::PendingSet => {}
+                DrainResult::ResolutionLimitExceeded => {
+                    return Err(ApplyError::InternalError(
+                        DrainResult::resolution_limit_message(),
+                    ));
+                }
             }
         }
 
@@ -1036,6 +1046,15 @@ pub(super) fn apply_resolve_select_enemy(
 }
 
 
+/// Whether `enemy_instance_id` is an undefeated enemy in the current combat.
+pub(crate) fn is_live_combat_enemy(state: &GameState, enemy_instance_id: &str) -> bool {
+    state.combat.as_ref().is_some_and(|c| {
+        c.enemies
+            .iter()
+            .any(|e| e.instance_id.as_str() == enemy_instance_id && !e.is_defeated)
+    })
+}
+
 /// Public wrapper for apply_select_enemy_effects (used by effect_queue).
 pub fn apply_select_enemy_effects_pub(
     state: &mut GameState,
@@ -1258,12 +1277,13 @@ pub(super) fn apply_select_enemy_effects(
         };
 
         if let Some(combat) = state.combat.as_mut() {
-            if let Some(enemy) = combat.enemies.iter_mut().find(|e| e.instance_id.as_str() == enemy_instance_id) {
-                enemy.is_defeated = true;
+            if let Some(idx) = combat.enemies.iter().position(|e| e.instance_id.as_str() == enemy_instance_id) {
+                combat.enemies[idx].is_defeated = true;
+                super::combat_actions::log_enemy_defeated(combat, idx);
+                super::combat_actions::record_enemy_fame(&mut state.players[player_idx], combat, idx, fame_gain);
             }
         }
 
-        state.players[player_idx].fame += fame_gain;
         if rep_bonus != 0 {
             state.players[player_idx].reputation = (state.players[player_idx].reputation + rep_bonus)
                 .clamp(-7, 7);
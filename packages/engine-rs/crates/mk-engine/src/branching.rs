@@ -0,0 +1,187 @@
+//! Branching-factor statistics — legal action counts over a random playout.
+//!
+//! Purely analytical: clones the given state, repeatedly enumerates legal
+//! actions for whichever player can act, records how many there were, and
+//! applies one chosen uniformly at random. Used for balance tuning and for
+//! sizing search/RL action spaces.
+//!
+//! The random driver itself, [`RandomPlayout`], is shared with the fuzz,
+//! contract and full-game tests.
+
+use std::collections::BTreeMap;
+
+use mk_types::legal_action::LegalAction;
+use mk_types::rng::RngState;
+use mk_types::state::GameState;
+
+use crate::action_pipeline::{apply_legal_action, ApplyError, ApplyResult};
+use crate::legal_actions::enumerate_legal_actions_with_undo;
+use crate::undo::UndoStack;
+
+/// Seeded uniformly random policy over legal actions, with its own undo stack.
+///
+/// Each step is split into [`choose`](Self::choose) and
+/// [`apply`](Self::apply) so callers can inspect, log or guard either half.
+pub struct RandomPlayout {
+    undo: UndoStack,
+    rng: RngState,
+    skip_undo: bool,
+}
+
+/// One action picked by [`RandomPlayout::choose`].
+#[derive(Debug, Clone)]
+pub struct PlayoutChoice {
+    /// The first player (by index) that had any legal action.
+    pub player_idx: usize,
+    /// Index of `action` in the enumerated action set.
+    pub action_index: usize,
+    pub action: LegalAction,
+    /// Size of the enumerated action set.
+    pub action_count: usize,
+    epoch: u64,
+}
+
+impl RandomPlayout {
+    pub fn new(action_seed: u32) -> Self {
+        Self {
+            undo: UndoStack::new(),
+            rng: RngState::new(action_seed),
+            skip_undo: false,
+        }
+    }
+
+    /// Never pick `Undo` unless it is the only legal action, so every step
+    /// moves the game forward.
+    pub fn forward_only(mut self) -> Self {
+        self.skip_undo = true;
+        self
+    }
+
+    /// Enumerate legal actions for the first player that has any and pick one.
+    /// Returns `None` if no player can act.
+    pub fn choose(&mut self, state: &GameState) -> Option<PlayoutChoice> {
+        let (player_idx, legal) = (0..state.players.len()).find_map(|idx| {
+            let legal = enumerate_legal_actions_with_undo(state, idx, &self.undo);
+            (!legal.actions.is_empty()).then_some((idx, legal))
+        })?;
+
+        let mut candidates: Vec<usize> = (0..legal.actions.len())
+            .filter(|&i| !(self.skip_undo && matches!(legal.actions[i], LegalAction::Undo)))
+            .collect();
+        if candidates.is_empty() {
+            candidates = (0..legal.actions.len()).collect();
+        }
+        let action_index = candidates[self.rng.random_index(candidates.len())?];
+        Some(PlayoutChoice {
+            player_idx,
+            action_index,
+            action: legal.actions[action_index].clone(),
+            action_count: legal.actions.len(),
+            epoch: legal.epoch,
+        })
+    }
+
+    /// Apply a choice made by [`choose`](Self::choose) on the same state.
+    pub fn apply(&mut self, state: &mut GameState, choice: &PlayoutChoice) -> Result<ApplyResult, ApplyError> {
+        apply_legal_action(state, &mut self.undo, choice.player_idx, &choice.action, choice.epoch)
+    }
+}
+
+/// Distribution of legal action counts observed during one playout.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BranchingHistogram {
+    /// Legal action count → number of decision steps with that many actions.
+    pub counts: BTreeMap<usize, u32>,
+    /// Number of decision steps recorded.
+    pub steps: u32,
+    /// Whether the playout reached the end of the game (vs. the step cap).
+    pub game_ended: bool,
+}
+
+impl BranchingHistogram {
+    /// Mean number of legal actions per decision step (0.0 if no steps).
+    pub fn mean(&self) -> f64 {
+        if self.steps == 0 {
+            return 0.0;
+        }
+        let total: u64 = self
+            .counts
+            .iter()
+            .map(|(&actions, &steps)| actions as u64 * steps as u64)
+            .sum();
+        total as f64 / self.steps as f64
+    }
+
+    /// Largest legal action count observed.
+    pub fn max(&self) -> Option<usize> {
+        self.counts.keys().next_back().copied()
+    }
+
+    fn record(&mut self, action_count: usize) {
+        *self.counts.entry(action_count).or_insert(0) += 1;
+        self.steps += 1;
+    }
+}
+
+/// Play uniformly random legal actions from `state` for up to `max_steps`
+/// steps and return the legal action count distribution.
+///
+/// Stops early when the game ends, no player has any legal action, or an
+/// action fails to apply. The input state is not modified.
+pub fn branching_histogram(state: &GameState, action_seed: u32, max_steps: usize) -> BranchingHistogram {
+    let mut state = state.clone();
+    let mut playout = RandomPlayout::new(action_seed);
+    let mut histogram = BranchingHistogram::default();
+
+    for _ in 0..max_steps {
+        if state.game_ended {
+            break;
+        }
+        let Some(choice) = playout.choose(&state) else {
+            break;
+        };
+        histogram.record(choice.action_count);
+        if playout.apply(&mut state, &choice).is_err() {
+            break;
+        }
+    }
+
+    histogram.game_ended = state.game_ended;
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::setup::{create_solo_game, place_initial_tiles};
+    use mk_types::enums::Hero;
+
+    #[test]
+    fn solo_playout_produces_plausible_histogram() {
+        let mut state = create_solo_game(42, Hero::Arythea);
+        place_initial_tiles(&mut state);
+
+        let histogram = branching_histogram(&state, 7, 500);
+
+        assert!(histogram.steps > 0);
+        assert_eq!(histogram.counts.values().sum::<u32>(), histogram.steps);
+        assert!(!histogram.counts.contains_key(&0), "only steps with actions are recorded");
+        let mean = histogram.mean();
+        assert!((1.0..=200.0).contains(&mean), "implausible mean branching factor {mean}");
+        assert!(histogram.max().unwrap() as f64 >= mean);
+    }
+
+    #[test]
+    fn same_seed_gives_same_histogram() {
+        let mut state = create_solo_game(3, Hero::Tovak);
+        place_initial_tiles(&mut state);
+        assert_eq!(branching_histogram(&state, 11, 200), branching_histogram(&state, 11, 200));
+    }
+
+    #[test]
+    fn empty_histogram_has_zero_mean() {
+        let histogram = BranchingHistogram::default();
+        assert_eq!(histogram.mean(), 0.0);
+        assert_eq!(histogram.max(), None);
+    }
+}
@@ -4,15 +4,16 @@
 
 use mk_data::cards::{get_card, PoweredBy};
 use mk_types::action::ManaSourceInfo;
+use mk_types::effect::CardEffect;
 use mk_types::enums::*;
 use mk_types::modifier::{
     ActiveModifier, ModifierEffect, ModifierScope, ModifierSource, RuleOverride,
-    SidewaysCondition,
+    ShapeshiftTarget, SidewaysCondition,
 };
 use mk_types::pending::{ActivePending, ChoiceResolution, ContinuationEntry, PendingChoice};
 use mk_types::state::*;
 
-use crate::effect_queue::{DrainResult, EffectQueue};
+use crate::effect_queue::{DrainResult, EffectQueue, WOUND_CARD_ID};
 use crate::undo::UndoStack;
 
 // =============================================================================
@@ -24,11 +25,14 @@ use crate::undo::UndoStack;
 pub enum CardPlayError {
     CardNotInHand,
     InvalidHandIndex,
-    CardNotFound,
+    /// No card definition exists for this id.
+    CardNotFound(mk_types::ids::CardId),
     NotPowerable,
     ManaSourceRequired,
     InvalidManaSource,
     NotInCombat,
+    /// The card's effect exceeded `MAX_EFFECT_RESOLUTIONS` (effect loop).
+    ResolutionLimitExceeded,
 }
 
 // =============================================================================
@@ -79,9 +83,10 @@ pub(crate) fn play_card_with_undo(
     let card_id = player.hand[hand_index].clone();
 
     // Look up card definition
-    let card_def = get_card(card_id.as_str()).ok_or(CardPlayError::CardNotFound)?;
+    let card_def = get_card(card_id.as_str()).ok_or_else(|| CardPlayError::CardNotFound(card_id.clone()))?;
 
     // Determine which effect to use and handle mana payment
+    let is_spell = card_def.card_type == DeedCardType::Spell;
     let (effect, consumed_color, mana_choice) = if powered {
         if card_def.powered_by == PoweredBy::Free {
             // Free-powered artifacts: no mana payment required
@@ -89,30 +94,29 @@ pub(crate) fn play_card_with_undo(
         } else {
             // Validate the card can be powered
             let required_color = override_mana_color
-                .or(match card_def.powered_by {
-                    PoweredBy::Single(c) => Some(c),
-                    _ => None,
-                })
+                .or(card_def.powered_by.primary_color())
                 .ok_or(CardPlayError::NotPowerable)?;
 
-            // Collect all available mana sources
-            let sources = collect_mana_sources(state, player_idx, required_color);
-            if sources.is_empty() {
-                return Err(CardPlayError::ManaSourceRequired);
+            // Spells: the stronger effect additionally costs black mana (night only).
+            if is_spell {
+                let (black, _) = spell_powered_sources(state, player_idx, required_color)
+                    .ok_or(CardPlayError::ManaSourceRequired)?;
+                consume_mana_source(state, player_idx, &black, true);
             }
 
-            if sources.len() == 1 {
-                // Single source — auto-consume immediately
-                let consumed = consume_specific_mana_source(state, player_idx, &sources[0]);
-                (card_def.powered_effect.clone(), Some(consumed), None)
-            } else {
-                // Multiple sources — defer to pending choice
-                (card_def.powered_effect.clone(), None, Some(sources))
-            }
+            pay_for_effect(state, player_idx, required_color, &card_def.powered_effect, is_spell)?
         }
+    } else if is_spell {
+        // Spells cost mana of their own color even for the basic effect.
+        let spell_color = card_def
+            .powered_by
+            .primary_color()
+            .ok_or(CardPlayError::NotPowerable)?;
+        pay_for_effect(state, player_idx, spell_color, &card_def.basic_effect, true)?
     } else {
         (card_def.basic_effect.clone(), None, None)
     };
+    let effect = apply_shapeshift(state, player_idx, &card_id, effect);
 
     // Move card from hand to play area
     let player = &mut state.players[player_idx];
@@ -198,6 +202,7 @@ pub(crate) fn play_card_with_undo(
             // A custom pending (e.g., DiscardForBonus) was set directly on the player.
             Ok(CardPlayResult::PendingChoice)
         }
+        DrainResult::ResolutionLimitExceeded => Err(CardPlayError::ResolutionLimitExceeded),
     };
 
     // Destroy artifact on powered play if flagged
@@ -220,6 +225,90 @@ pub(crate) fn play_card_with_undo(
     result
 }
 
+/// Effect to resolve, the mana color consumed (if paid immediately), and the
+/// candidate sources when the player must choose one.
+type ManaPayment = (CardEffect, Option<ManaColor>, Option<Vec<ManaSourceInfo>>);
+
+/// Pay one mana of `color` for `effect`.
+///
+/// A single available source is consumed immediately; with several the choice
+/// is deferred to a `ManaSourceSelect` pending (returned as the third element).
+fn pay_for_effect(
+    state: &mut GameState,
+    player_idx: usize,
+    color: BasicManaColor,
+    effect: &CardEffect,
+    for_spell: bool,
+) -> Result<ManaPayment, CardPlayError> {
+    let sources = collect_mana_sources(state, player_idx, color, for_spell);
+    match sources.len() {
+        0 => Err(CardPlayError::ManaSourceRequired),
+        1 => {
+            let consumed = consume_mana_source(state, player_idx, &sources[0], for_spell);
+            Ok((effect.clone(), Some(consumed), None))
+        }
+        _ => Ok((effect.clone(), None, Some(sources))),
+    }
+}
+
+/// Consume a Shapeshift modifier targeting `card_id`, if any, and convert the
+/// card's Move/Attack/Block into the chosen type for the same amount.
+///
+/// For Choice cards the first option is converted, matching how Shapeshift
+/// classifies them (see `classify_effect_for_shapeshift`).
+fn apply_shapeshift(
+    state: &mut GameState,
+    player_idx: usize,
+    card_id: &mk_types::ids::CardId,
+    effect: CardEffect,
+) -> CardEffect {
+    let player_id = &state.players[player_idx].id;
+    let Some(pos) = state.active_modifiers.iter().position(|m| {
+        m.created_by_player_id == *player_id
+            && matches!(&m.effect, ModifierEffect::ShapeshiftActive { target_card_id, .. } if target_card_id == card_id)
+    }) else {
+        return effect;
+    };
+    match state.active_modifiers.remove(pos).effect {
+        ModifierEffect::ShapeshiftActive { target_type, combat_type, element, .. } => {
+            shapeshift_effect(effect, target_type, combat_type, element)
+        }
+        _ => effect,
+    }
+}
+
+fn shapeshift_effect(
+    effect: CardEffect,
+    target_type: ShapeshiftTarget,
+    combat_type: Option<CombatType>,
+    element: Option<Element>,
+) -> CardEffect {
+    let amount = match effect {
+        CardEffect::GainMove { amount }
+        | CardEffect::GainAttack { amount, .. }
+        | CardEffect::GainBlock { amount, .. } => amount,
+        CardEffect::Choice { mut options } => {
+            if let Some(first) = options.first_mut() {
+                *first = shapeshift_effect(first.clone(), target_type, combat_type, element);
+            }
+            return CardEffect::Choice { options };
+        }
+        other => return other,
+    };
+    match target_type {
+        ShapeshiftTarget::Move => CardEffect::GainMove { amount },
+        ShapeshiftTarget::Attack => CardEffect::GainAttack {
+            amount,
+            combat_type: combat_type.unwrap_or(CombatType::Melee),
+            element: element.unwrap_or(Element::Physical),
+        },
+        ShapeshiftTarget::Block => CardEffect::GainBlock {
+            amount,
+            element: element.unwrap_or(Element::Physical),
+        },
+    }
+}
+
 // =============================================================================
 // Mana source collection (for pending choice)
 // =============================================================================
@@ -228,17 +317,24 @@ pub(crate) fn play_card_with_undo(
 ///
 /// Returns a deduplicated list: at most one entry per source type/color combo.
 /// Token sources are fungible (we pick the first matching index at consumption time).
+/// With `for_spell`, tokens marked `cannot_power_spells` (Polarization's black
+/// conversion during the day) are skipped.
 pub(crate) fn collect_mana_sources(
     state: &GameState,
     player_idx: usize,
     required_color: BasicManaColor,
+    for_spell: bool,
 ) -> Vec<ManaSourceInfo> {
     let target_mana = ManaColor::from(required_color);
     let player = &state.players[player_idx];
+    let mut tokens = player
+        .pure_mana
+        .iter()
+        .filter(|t| !(for_spell && t.cannot_power_spells));
     let mut sources = Vec::new();
 
     // 1. Matching-color mana token
-    if player.pure_mana.iter().any(|t| t.color == target_mana) {
+    if tokens.clone().any(|t| t.color == target_mana) {
         sources.push(ManaSourceInfo {
             source_type: ManaSourceType::Token,
             color: target_mana,
@@ -247,11 +343,7 @@ pub(crate) fn collect_mana_sources(
     }
 
     // 2. Gold mana token (wild)
-    if player
-        .pure_mana
-        .iter()
-        .any(|t| t.color == ManaColor::Gold)
-    {
+    if tokens.any(|t| t.color == ManaColor::Gold) {
         sources.push(ManaSourceInfo {
             source_type: ManaSourceType::Token,
             color: ManaColor::Gold,
@@ -274,29 +366,9 @@ pub(crate) fn collect_mana_sources(
         });
     }
 
-    // 4. Mana source dice (1 per turn limit)
-    if !player
-        .flags
-        .contains(PlayerFlags::USED_MANA_FROM_SOURCE)
-    {
-        let player_id = &player.id;
-        let stolen_die_id = player
-            .tactic_state
-            .stored_mana_die
-            .as_ref()
-            .map(|s| &s.die_id);
-
-        for die in &state.source.dice {
-            if !is_die_available_with_overrides(die, state, player_idx) {
-                continue;
-            }
-            // Allow unclaimed dice OR the player's own stolen Mana Steal die
-            let is_available = die.taken_by_player_id.is_none()
-                || (die.taken_by_player_id.as_ref() == Some(player_id)
-                    && stolen_die_id == Some(&die.id));
-            if !is_available {
-                continue;
-            }
+    // 4. Mana source dice (per-turn limit, see can_use_source)
+    if can_use_source(state, player_idx) {
+        for die in takeable_dice(state, player_idx) {
             if die.color == target_mana || die.color == ManaColor::Gold {
                 sources.push(ManaSourceInfo {
                     source_type: ManaSourceType::Die,
@@ -310,6 +382,74 @@ pub(crate) fn collect_mana_sources(
     sources
 }
 
+/// Source dice the player could take, ignoring the per-turn die limit:
+/// unclaimed dice (not depleted by time of day, unless overridden) or the
+/// player's own stolen Mana Steal die.
+fn takeable_dice(state: &GameState, player_idx: usize) -> impl Iterator<Item = &SourceDie> {
+    let player = &state.players[player_idx];
+    let stolen_die_id = player
+        .tactic_state
+        .stored_mana_die
+        .as_ref()
+        .map(|s| &s.die_id);
+    state.source.dice.iter().filter(move |die| {
+        is_die_available_with_overrides(die, state, player_idx)
+            && (die.taken_by_player_id.is_none()
+                || (die.taken_by_player_id.as_ref() == Some(&player.id)
+                    && stolen_die_id == Some(&die.id)))
+    })
+}
+
+/// Mana for a spell's powered effect: one black plus one of the spell's color.
+///
+/// Black is only usable at night (or with `AllowBlackAtDay`); a black token is
+/// preferred over a black die so the die limit stays free for the color.
+/// Returns the black source and the color sources still payable after it, or
+/// `None` if either half cannot be paid.
+pub(crate) fn spell_powered_sources(
+    state: &GameState,
+    player_idx: usize,
+    color: BasicManaColor,
+) -> Option<(ManaSourceInfo, Vec<ManaSourceInfo>)> {
+    if state.time_of_day != TimeOfDay::Night
+        && !is_rule_active(state, player_idx, RuleOverride::AllowBlackAtDay)
+    {
+        return None;
+    }
+    let player = &state.players[player_idx];
+
+    let black = if player
+        .pure_mana
+        .iter()
+        .any(|t| t.color == ManaColor::Black && !t.cannot_power_spells)
+    {
+        ManaSourceInfo {
+            source_type: ManaSourceType::Token,
+            color: ManaColor::Black,
+            die_id: None,
+        }
+    } else if can_use_source(state, player_idx) {
+        let die = takeable_dice(state, player_idx).find(|d| d.color == ManaColor::Black)?;
+        ManaSourceInfo {
+            source_type: ManaSourceType::Die,
+            color: ManaColor::Black,
+            die_id: Some(die.id.as_str().to_string()),
+        }
+    } else {
+        return None;
+    };
+
+    let mut sources = collect_mana_sources(state, player_idx, color, true);
+    if black.source_type == ManaSourceType::Die {
+        // Taking the black die uses up the die allowance unless an extra die
+        // (one per ExtraSourceDie override) is still left afterwards.
+        if remaining_source_dice(state, player_idx) < 2 {
+            sources.retain(|s| s.source_type != ManaSourceType::Die);
+        }
+    }
+    (!sources.is_empty()).then_some((black, sources))
+}
+
 /// Consume a specific mana source identified by `ManaSourceInfo`.
 ///
 /// Called when the player selects which mana source to use (from pending choice
@@ -318,6 +458,17 @@ pub(crate) fn consume_specific_mana_source(
     state: &mut GameState,
     player_idx: usize,
     source: &ManaSourceInfo,
+) -> ManaColor {
+    consume_mana_source(state, player_idx, source, false)
+}
+
+/// Like `consume_specific_mana_source`, but when paying for a spell a token
+/// marked `cannot_power_spells` is never the one taken.
+pub(crate) fn consume_mana_source(
+    state: &mut GameState,
+    player_idx: usize,
+    source: &ManaSourceInfo,
+    for_spell: bool,
 ) -> ManaColor {
     match source.source_type {
         ManaSourceType::Token => {
@@ -325,7 +476,7 @@ pub(crate) fn consume_specific_mana_source(
             if let Some(idx) = player
                 .pure_mana
                 .iter()
-                .position(|t| t.color == source.color)
+                .position(|t| t.color == source.color && !(for_spell && t.cannot_power_spells))
             {
                 player.pure_mana.remove(idx);
             }
@@ -404,6 +555,27 @@ pub(crate) fn is_die_available_with_overrides(
     }
 }
 
+/// Whether the player may still take a die from the Source this turn: one die
+/// per turn plus one per active `ExtraSourceDie` override (Mana Storm, Source
+/// Opening), and none at all once the Source is blocked (Who Needs Magic?).
+pub(crate) fn can_use_source(state: &GameState, player_idx: usize) -> bool {
+    remaining_source_dice(state, player_idx) > 0
+}
+
+/// Source dice the player may still take this turn: one, plus one per
+/// `ExtraSourceDie` override, minus the dice already taken.
+pub(crate) fn remaining_source_dice(state: &GameState, player_idx: usize) -> usize {
+    if is_rule_active(state, player_idx, RuleOverride::SourceBlocked) {
+        return 0;
+    }
+    let player = &state.players[player_idx];
+    let dice_taken = player
+        .used_die_ids
+        .len()
+        .max(usize::from(player.flags.contains(PlayerFlags::USED_MANA_FROM_SOURCE)));
+    (1 + rule_override_count(state, player_idx, RuleOverride::ExtraSourceDie)).saturating_sub(dice_taken)
+}
+
 /// Check whether a modifier applies to the given player based on its scope.
 fn modifier_applies_to_player(m: &ActiveModifier, player_id: &mk_types::ids::PlayerId) -> bool {
     match m.scope {
@@ -424,6 +596,47 @@ pub fn is_rule_active(state: &GameState, player_idx: usize, rule: RuleOverride)
     })
 }
 
+/// Count the active modifiers granting `rule` to the given player (stacking overrides).
+fn rule_override_count(state: &GameState, player_idx: usize, rule: RuleOverride) -> usize {
+    let player_id = &state.players[player_idx].id;
+    state
+        .active_modifiers
+        .iter()
+        .filter(|m| {
+            modifier_applies_to_player(m, player_id)
+                && matches!(&m.effect, ModifierEffect::RuleOverride { rule: r } if *r == rule)
+        })
+        .count()
+}
+
+/// Resolve the sideways value of a specific card for a player.
+///
+/// Single entry point for "what is this card worth sideways?": looks up the
+/// card's type and color, then defers to [`get_effective_sideways_value`],
+/// which takes the highest applicable `SidewaysValue` modifier (never a sum).
+/// Wounds are worth 0 unless `WoundsPlayableSideways` is active.
+/// Unknown cards are worth 0.
+pub fn effective_sideways_value(
+    state: &GameState,
+    player_idx: usize,
+    card_id: &mk_types::ids::CardId,
+) -> u32 {
+    let Some(card_def) = get_card(card_id.as_str()) else {
+        return 0;
+    };
+    let is_wound = card_id.as_str() == WOUND_CARD_ID;
+    if is_wound && !is_rule_active(state, player_idx, RuleOverride::WoundsPlayableSideways) {
+        return 0;
+    }
+    get_effective_sideways_value(
+        state,
+        player_idx,
+        is_wound,
+        card_def.card_type,
+        card_def.powered_by.primary_color(),
+    )
+}
+
 /// Compute the effective sideways value for a card, considering active modifiers.
 ///
 /// Base value: 0 for wounds, `card_def.sideways_value` for others.
@@ -500,16 +713,11 @@ pub fn play_card_sideways(
 
     let card_id = player.hand[hand_index].clone();
 
-    // Look up card definition for sideways value
-    let card_def = get_card(card_id.as_str()).ok_or(CardPlayError::CardNotFound)?;
-    let is_wound = card_id.as_str() == "wound";
-    let value = get_effective_sideways_value(
-        state,
-        player_idx,
-        is_wound,
-        card_def.card_type,
-        card_def.powered_by.primary_color(),
-    );
+    if get_card(card_id.as_str()).is_none() {
+        return Err(CardPlayError::CardNotFound(card_id));
+    }
+    let is_wound = card_id.as_str() == WOUND_CARD_ID;
+    let value = effective_sideways_value(state, player_idx, &card_id);
 
     // Move card from hand to play area
     let player = &mut state.players[player_idx];
@@ -1003,7 +1211,10 @@ mod tests {
         state.players[0].hand.push(CardId::from("nonexistent_card"));
 
         let result = play_card(&mut state, 0, 0, false, None);
-        assert_eq!(result.unwrap_err(), CardPlayError::CardNotFound);
+        assert_eq!(
+            result.unwrap_err(),
+            CardPlayError::CardNotFound(CardId::from("nonexistent_card"))
+        );
     }
 
     #[test]
@@ -1289,8 +1500,10 @@ mod tests {
         // In Block phase with a cumbersome enemy, move should be available
         // (can be spent as block against cumbersome). Influence still filtered.
         let mut state = setup_game(vec!["improvisation", "march"]);
-        let mut combat = CombatState::default();
-        combat.phase = CombatPhase::Block;
+        let mut combat = CombatState {
+            phase: CombatPhase::Block,
+            ..CombatState::default()
+        };
         combat.enemies.push(mk_types::state::CombatEnemy {
             enemy_id: mk_types::ids::EnemyId::from("orc_stonethrowers"),
             instance_id: "enemy_0".into(),
@@ -1505,6 +1718,73 @@ mod tests {
         assert_eq!(val, 1);
     }
 
+    // ---- effective_sideways_value tests ----
+
+    fn push_sideways_modifier(
+        state: &mut GameState,
+        id: &str,
+        new_value: u32,
+        condition: Option<SidewaysCondition>,
+        for_card_types: Vec<DeedCardType>,
+    ) {
+        use mk_types::modifier::*;
+        let pid = state.players[0].id.clone();
+        state.active_modifiers.push(ActiveModifier {
+            id: mk_types::ids::ModifierId::from(id),
+            source: ModifierSource::Skill { skill_id: mk_types::ids::SkillId::from(id), player_id: pid.clone() },
+            duration: ModifierDuration::Turn,
+            scope: ModifierScope::SelfScope,
+            effect: ModifierEffect::SidewaysValue {
+                new_value, for_wounds: false, condition, mana_color: None, for_card_types,
+            },
+            created_at_round: 1,
+            created_by_player_id: pid,
+        });
+    }
+
+    #[test]
+    fn effective_sideways_value_takes_max_of_stacked_modifiers() {
+        let mut state = setup_game(vec!["march", "fireball"]);
+        push_sideways_modifier(&mut state, "base", 2, None, vec![]);
+        push_sideways_modifier(
+            &mut state, "typed", 3, None,
+            vec![DeedCardType::AdvancedAction, DeedCardType::Spell, DeedCardType::Artifact],
+        );
+        push_sideways_modifier(&mut state, "no_mana", 4, Some(SidewaysCondition::NoManaUsed), vec![]);
+
+        let march = mk_types::ids::CardId::from("march");
+        let fireball = mk_types::ids::CardId::from("fireball");
+        assert_eq!(effective_sideways_value(&state, 0, &march), 4);
+        assert_eq!(effective_sideways_value(&state, 0, &fireball), 4);
+
+        // Source used: NoManaUsed drops out, remaining best applies per card type.
+        state.players[0].flags.insert(PlayerFlags::USED_MANA_FROM_SOURCE);
+        assert_eq!(effective_sideways_value(&state, 0, &march), 2);
+        assert_eq!(effective_sideways_value(&state, 0, &fireball), 3);
+    }
+
+    #[test]
+    fn effective_sideways_value_wound_needs_rule() {
+        use crate::action_pipeline;
+        let mut state = setup_game(vec!["wound"]);
+        let wound = mk_types::ids::CardId::from("wound");
+        // Non-wound modifiers never apply to wounds.
+        push_sideways_modifier(&mut state, "base", 2, None, vec![]);
+        assert_eq!(effective_sideways_value(&state, 0, &wound), 0);
+
+        let skill_id = mk_types::ids::SkillId::from("arythea_power_of_pain");
+        state.players[0].skills.push(skill_id.clone());
+        action_pipeline::apply_power_of_pain_pub(&mut state, 0, &skill_id);
+        assert_eq!(effective_sideways_value(&state, 0, &wound), 2);
+    }
+
+    #[test]
+    fn effective_sideways_value_unknown_card_is_zero() {
+        let state = setup_game(vec!["march"]);
+        let unknown = mk_types::ids::CardId::from("not_a_card");
+        assert_eq!(effective_sideways_value(&state, 0, &unknown), 0);
+    }
+
     #[test]
     fn power_of_pain_wound_sideways_enhanced() {
         use crate::action_pipeline;
@@ -1628,7 +1908,9 @@ mod tests {
     fn restoration_powered_on_forest_heals_5() {
         // OnTerrain{Forest} → then: Heal 5
         let mut state = setup_game(vec!["restoration"]);
-        give_mana(&mut state, ManaColor::Green);
+        give_mana(&mut state, ManaColor::Green); // spell cost
+        state.time_of_day = TimeOfDay::Night;
+        give_mana(&mut state, ManaColor::Black); // powered cost
         // Add 5 wounds to hand
         for _ in 0..5 {
             state.players[0].hand.push(CardId::from("wound"));
@@ -1651,7 +1933,9 @@ mod tests {
     fn restoration_powered_off_forest_heals_3() {
         // OnTerrain{Forest} condition false → else: Heal 3
         let mut state = setup_game(vec!["restoration"]);
-        give_mana(&mut state, ManaColor::Green);
+        give_mana(&mut state, ManaColor::Green); // spell cost
+        state.time_of_day = TimeOfDay::Night;
+        give_mana(&mut state, ManaColor::Black); // powered cost
         // Add 5 wounds to hand
         for _ in 0..5 {
             state.players[0].hand.push(CardId::from("wound"));
@@ -1694,7 +1978,8 @@ mod tests {
     fn fireball_powered_wound_and_siege_fire_8() {
         let mut state = setup_game(vec!["fireball"]);
         give_mana(&mut state, ManaColor::Red); // spell cost
-        give_mana(&mut state, ManaColor::Red); // powered cost
+        state.time_of_day = TimeOfDay::Night;
+        give_mana(&mut state, ManaColor::Black); // powered cost
         setup_combat_with_enemies(&mut state, &["prowlers"]);
         let result = play_card(&mut state, 0, 0, true, None).unwrap();
         assert!(matches!(result, CardPlayResult::Complete));
@@ -1900,7 +2185,8 @@ mod tests {
     fn burning_shield_powered_destroy_mode_modifier() {
         let mut state = setup_game(vec!["burning_shield"]);
         give_mana(&mut state, ManaColor::Red); // spell cost
-        give_mana(&mut state, ManaColor::Red); // powered cost
+        state.time_of_day = TimeOfDay::Night;
+        give_mana(&mut state, ManaColor::Black); // powered cost
         setup_combat_with_enemies(&mut state, &["prowlers"]);
         let result = play_card(&mut state, 0, 0, true, None).unwrap();
         assert!(matches!(result, CardPlayResult::Complete));
@@ -2009,7 +2295,8 @@ mod tests {
     fn mist_form_powered_resistances_and_wound_immunity() {
         let mut state = setup_game(vec!["mist_form"]);
         give_mana(&mut state, ManaColor::Blue); // spell cost
-        give_mana(&mut state, ManaColor::Blue); // powered cost
+        state.time_of_day = TimeOfDay::Night;
+        give_mana(&mut state, ManaColor::Black); // powered cost
         let result = play_card(&mut state, 0, 0, true, None).unwrap();
         assert!(matches!(result, CardPlayResult::Complete));
         // Powered Mist Form grants resistances + wound immunity, no move
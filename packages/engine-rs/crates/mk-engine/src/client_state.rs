@@ -10,28 +10,50 @@ use mk_types::ids::{CardId, PlayerId, TacticId};
 use mk_types::pending::{ActivePending, ChoiceResolution};
 use mk_types::state::*;
 
+/// How much private information a client view reveals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedactionMode {
+    /// Normal player view: only this player's hand is shown; unrevealed enemy
+    /// tokens and tiles stay hidden.
+    PerPlayer(PlayerId),
+    /// Spectator/analysis view: every hand, enemy token and tile is shown.
+    FullReveal,
+}
+
 /// Convert full game state to a client-visible view for a specific player.
 ///
-/// The `for_player_id` determines which player sees full hand cards vs counts.
+/// The `for_player_id` determines which player sees full hand and discard cards vs counts.
 pub fn to_client_state(state: &GameState, for_player_id: &PlayerId) -> ClientGameState {
+    to_client_state_with_redaction(state, &RedactionMode::PerPlayer(for_player_id.clone()))
+}
+
+/// Convert full game state to a client-visible view with the given redaction.
+pub fn to_client_state_with_redaction(state: &GameState, mode: &RedactionMode) -> ClientGameState {
     let current_player_id = resolve_current_player_id(state);
+    let reveal_all = *mode == RedactionMode::FullReveal;
+    let shows_cards = |player: &PlayerState| match mode {
+        RedactionMode::PerPlayer(id) => player.id == *id,
+        RedactionMode::FullReveal => true,
+    };
 
     ClientGameState {
         phase: state.phase,
         round_phase: state.round_phase,
         time_of_day: state.time_of_day,
         round: state.round,
-        current_player_id,
+        action_epoch: state.action_epoch,
+        current_player_id: current_player_id.clone(),
         turn_order: state.turn_order.clone(),
         end_of_round_announced_by: state.end_of_round_announced_by.clone(),
+        turn_context: resolve_turn_context(state, &current_player_id),
 
         players: state
             .players
             .iter()
-            .map(|p| to_client_player(p, p.id == *for_player_id, state))
+            .map(|p| to_client_player(p, shows_cards(p), state))
             .collect(),
 
-        map: to_client_map(&state.map),
+        map: to_client_map(&state.map, reveal_all),
         source: to_client_source(&state.source, &state.players),
         offers: to_client_offers(&state.offers),
         deck_counts: to_client_deck_counts(&state.decks),
@@ -66,16 +88,41 @@ fn resolve_current_player_id(state: &GameState) -> PlayerId {
     }
 }
 
+/// Name the current player's situation, in the same precedence the
+/// `ValidActions` projection uses (pending choices block everything else).
+fn resolve_turn_context(state: &GameState, current_player_id: &PlayerId) -> TurnContext {
+    if state.game_ended {
+        return TurnContext::GameEnded;
+    }
+    let has_pending = state
+        .players
+        .iter()
+        .find(|p| p.id == *current_player_id)
+        .is_some_and(|p| p.pending.has_active());
+    if has_pending {
+        return TurnContext::PendingChoice;
+    }
+    if state.round_phase == RoundPhase::TacticsSelection {
+        return TurnContext::TacticsSelection;
+    }
+    match state.combat.as_ref() {
+        Some(combat) => TurnContext::CombatTurn {
+            combat_phase: combat.phase,
+        },
+        None => TurnContext::NormalTurn,
+    }
+}
+
 // =============================================================================
 // Player filtering
 // =============================================================================
 
-fn to_client_player(player: &PlayerState, is_self: bool, state: &GameState) -> ClientPlayer {
+fn to_client_player(player: &PlayerState, show_cards: bool, state: &GameState) -> ClientPlayer {
     let hand_count = player.hand.len();
-    let hand = if is_self {
-        player.hand.clone()
+    let (hand, discard) = if show_cards {
+        (player.hand.clone(), player.discard.clone())
     } else {
-        Vec::new()
+        (Vec::new(), Vec::new())
     };
 
     ClientPlayer {
@@ -93,6 +140,7 @@ fn to_client_player(player: &PlayerState, is_self: bool, state: &GameState) -> C
         hand,
         hand_count,
         deck_count: player.deck.len(),
+        discard,
         discard_count: player.discard.len(),
         play_area: player.play_area.clone(),
 
@@ -492,6 +540,9 @@ fn pending_options(active: &ActivePending, player: &PlayerState, state: &GameSta
             if matches!(choice.resolution, ChoiceResolution::SongOfWindLake) {
                 return vec!["Skip".to_string(), "Pay Blue mana for lake movement".to_string()];
             }
+            if matches!(choice.resolution, ChoiceResolution::PityWoundRemoval) {
+                return vec!["Skip".to_string(), "Remove a wound".to_string()];
+            }
             if let ChoiceResolution::RitualOfPainDiscard { max_wounds } = &choice.resolution {
                 return (0..=*max_wounds)
                     .map(|n| if n == 0 { "Skip".to_string() } else { format!("Discard {} wound(s)", n) })
@@ -514,6 +565,15 @@ fn pending_options(active: &ActivePending, player: &PlayerState, state: &GameSta
                     .map(|eid| format!("Use {} token", eid.name))
                     .collect();
             }
+            if let ChoiceResolution::PuppetMasterKeepToken { enemy_ids } = &choice.resolution {
+                return std::iter::once("Skip".to_string())
+                    .chain(enemy_ids.iter().map(|eid| {
+                        let name = get_enemy(eid.as_str())
+                            .map_or(eid.as_str(), |d| d.name);
+                        format!("Keep {} token", name)
+                    }))
+                    .collect();
+            }
             if let ChoiceResolution::PuppetMasterUseMode { attack_value, attack_element, block_value, block_element, .. } = &choice.resolution {
                 return vec![
                     format!("{} {:?} Attack", attack_value, attack_element),
@@ -695,7 +755,7 @@ fn effect_summary(effect: &mk_types::effect::CardEffect) -> String {
 // Map filtering
 // =============================================================================
 
-fn to_client_map(map: &MapState) -> ClientMapState {
+fn to_client_map(map: &MapState, reveal_all: bool) -> ClientMapState {
     ClientMapState {
         hexes: map
             .hexes
@@ -721,7 +781,7 @@ fn to_client_map(map: &MapState) -> ClientMapState {
                         .map(|e| ClientHexEnemy {
                             color: e.color,
                             is_revealed: e.is_revealed,
-                            token_id: if e.is_revealed {
+                            token_id: if e.is_revealed || reveal_all {
                                 Some(e.token_id.clone())
                             } else {
                                 None
@@ -739,7 +799,7 @@ fn to_client_map(map: &MapState) -> ClientMapState {
             .map(|t| ClientTilePlacement {
                 center_coord: t.center_coord,
                 revealed: t.revealed,
-                tile_id: if t.revealed {
+                tile_id: if t.revealed || reveal_all {
                     Some(t.tile_id)
                 } else {
                     None
@@ -850,6 +910,7 @@ fn to_client_combat(
         declared_attack_targets: combat.declared_attack_targets.clone(),
         declared_attack_type: combat.declared_attack_type,
         declared_attack_armor_needed,
+        combat_log: combat.combat_log.clone(),
     }
 }
 
@@ -922,6 +983,18 @@ mod tests {
         assert_eq!(client.current_player_id, player_id);
     }
 
+    #[test]
+    fn client_state_carries_action_epoch() {
+        let mut state = create_solo_game(42, Hero::Arythea);
+        state.action_epoch = 17;
+        let player_id = state.players[0].id.clone();
+        let client = to_client_state(&state, &player_id);
+
+        assert_eq!(client.action_epoch, 17);
+        let json = serde_json::to_value(&client).unwrap();
+        assert_eq!(json["actionEpoch"], 17);
+    }
+
     #[test]
     fn self_hand_visible() {
         let state = create_solo_game(42, Hero::Arythea);
@@ -946,6 +1019,36 @@ mod tests {
         assert!(player.hand_count > 0, "hand_count should still show count");
     }
 
+    #[test]
+    fn full_reveal_exposes_all_hands_and_hidden_tokens() {
+        let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+        let hex_key = state.map.hexes.keys().next().unwrap().clone();
+        state.map.hexes.get_mut(&hex_key).unwrap().enemies.push(HexEnemy {
+            token_id: mk_types::ids::EnemyTokenId::from("diggers_1"),
+            color: EnemyColor::Green,
+            is_revealed: false,
+        });
+        let p0 = state.players[0].id.clone();
+
+        let per_player = to_client_state_with_redaction(&state, &RedactionMode::PerPlayer(p0.clone()));
+        assert_eq!(per_player.players[0].hand, state.players[0].hand);
+        assert!(per_player.players[1].hand.is_empty(), "opponent's hand is hidden");
+        assert_eq!(per_player.players[1].hand_count, state.players[1].hand.len());
+        assert_eq!(per_player.map.hexes[&hex_key].enemies[0].token_id, None);
+        assert_eq!(
+            serde_json::to_value(&per_player).unwrap(),
+            serde_json::to_value(to_client_state(&state, &p0)).unwrap()
+        );
+
+        let full = to_client_state_with_redaction(&state, &RedactionMode::FullReveal);
+        for (client, player) in full.players.iter().zip(&state.players) {
+            assert_eq!(client.hand, player.hand);
+        }
+        let enemy = &full.map.hexes[&hex_key].enemies[0];
+        assert!(!enemy.is_revealed);
+        assert_eq!(enemy.token_id.as_ref().map(|t| t.as_str()), Some("diggers_1"));
+    }
+
     #[test]
     fn deck_counts_correct() {
         let state = create_solo_game(42, Hero::Arythea);
@@ -957,6 +1060,31 @@ mod tests {
         assert_eq!(player.discard_count, state.players[0].discard.len());
     }
 
+    #[test]
+    fn opponent_piles_show_counts_only_and_own_discard_is_visible() {
+        let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+        for player in &mut state.players {
+            let card = player.deck.remove(0);
+            player.discard.push(card);
+        }
+        let p0 = state.players[0].id.clone();
+        let json = serde_json::to_value(to_client_state(&state, &p0)).unwrap();
+
+        let own = &json["players"][0];
+        assert_eq!(own["discard"], serde_json::json!(state.players[0].discard));
+        assert_eq!(own["deckCount"], state.players[0].deck.len());
+
+        let opponent = &json["players"][1];
+        assert_eq!(opponent["deckCount"], state.players[1].deck.len());
+        assert_eq!(opponent["discardCount"], 1);
+        assert_eq!(opponent["discard"], serde_json::json!([]));
+        assert_eq!(opponent["hand"], serde_json::json!([]));
+
+        for player in [own, opponent] {
+            assert!(player.get("deck").is_none(), "deck order is never serialized");
+        }
+    }
+
     #[test]
     fn map_tiles_filtered() {
         let state = create_solo_game(42, Hero::Arythea);
@@ -1020,6 +1148,45 @@ mod tests {
         assert!(client.players[0].pending.is_none());
     }
 
+    #[test]
+    fn turn_context_tracks_phase() {
+        let mut state = create_solo_game(42, Hero::Arythea);
+        let player_id = state.players[0].id.clone();
+        assert_eq!(
+            to_client_state(&state, &player_id).turn_context,
+            TurnContext::TacticsSelection
+        );
+
+        state.round_phase = RoundPhase::PlayerTurns;
+        assert_eq!(
+            to_client_state(&state, &player_id).turn_context,
+            TurnContext::NormalTurn
+        );
+
+        state.combat = Some(Box::new(CombatState {
+            phase: CombatPhase::Block,
+            ..CombatState::default()
+        }));
+        assert_eq!(
+            to_client_state(&state, &player_id).turn_context,
+            TurnContext::CombatTurn {
+                combat_phase: CombatPhase::Block
+            }
+        );
+
+        state.players[0].pending.active = Some(ActivePending::PlunderDecision);
+        assert_eq!(
+            to_client_state(&state, &player_id).turn_context,
+            TurnContext::PendingChoice
+        );
+
+        state.game_ended = true;
+        assert_eq!(
+            to_client_state(&state, &player_id).turn_context,
+            TurnContext::GameEnded
+        );
+    }
+
     #[test]
     fn serializes_to_json() {
         let state = create_solo_game(42, Hero::Arythea);
@@ -1032,4 +1199,31 @@ mod tests {
         let _: ClientGameState =
             serde_json::from_str(&json).expect("should deserialize from JSON");
     }
+
+    #[test]
+    fn combat_accumulator_exposes_ranged_attack_from_played_card() {
+        use mk_types::ids::{CardId, EnemyTokenId};
+
+        let mut state = create_solo_game(42, Hero::Arythea);
+        state.round_phase = RoundPhase::PlayerTurns;
+        state.players[0].hand = vec![CardId::from("swiftness")];
+        state.players[0].crystals.white = 1;
+        state.source.dice.clear();
+        crate::combat::execute_enter_combat(
+            &mut state, 0, &[EnemyTokenId::from("prowlers_1")], false, None, Default::default(),
+        )
+        .unwrap();
+        let player_id = state.players[0].id.clone();
+        assert_eq!(to_client_state(&state, &player_id).players[0].combat_accumulator.attack.ranged, 0);
+
+        // Swiftness powered: Ranged Attack 3
+        crate::card_play::play_card(&mut state, 0, 0, true, None).unwrap();
+
+        let client = to_client_state(&state, &player_id);
+        let attack = &client.players[0].combat_accumulator.attack;
+        assert_eq!(attack.ranged, 3);
+        assert_eq!(attack.ranged_elements.physical, 3);
+        let json = serde_json::to_value(&client).unwrap();
+        assert_eq!(json["players"][0]["combatAccumulator"]["attack"]["ranged"], 3);
+    }
 }
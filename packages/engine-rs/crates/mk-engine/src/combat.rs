@@ -95,6 +95,7 @@ pub fn execute_enter_combat(
         wounds_added_to_hand_this_combat: false,
         attacks_this_phase: 0,
         fame_gained: 0,
+        fame_by_enemy: BTreeMap::new(),
         is_at_fortified_site: is_fortified,
         units_allowed: options.units_allowed,
         night_mana_rules: options.night_mana_rules,
@@ -120,8 +121,9 @@ pub fn execute_enter_combat(
         declared_block_target: None,
         declared_block_attack_index: None,
         has_paralyze_damage_to_hero: false,
-        ranged_siege_defeats: 0,
+        ranged_siege_defeated: Vec::new(),
         city_color: None,
+        combat_log: Vec::new(),
     };
 
     // Clear healing points (rulebook: unspent healing disappears when entering combat)
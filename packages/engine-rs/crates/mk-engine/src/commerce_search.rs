@@ -413,6 +413,7 @@ fn filter_combat_actions(actions: &LegalActionSet) -> LegalActionSet {
         epoch: actions.epoch,
         player_idx: actions.player_idx,
         actions: filtered,
+        empty_reason: actions.empty_reason,
     }
 }
 
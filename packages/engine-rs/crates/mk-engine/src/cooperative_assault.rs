@@ -258,6 +258,72 @@ fn execute_agreement(
     Ok(())
 }
 
+// =============================================================================
+// Spoils
+// =============================================================================
+
+/// Settle cooperative assault spoils at the end of combat.
+///
+/// Fame is credited to the combatant incrementally as enemies fall, so the fame
+/// awarded for each defeated enemy assigned to another participant (including
+/// per-enemy bonuses, see `CombatState::fame_by_enemy`) is moved to that
+/// participant here and taken off the combat's `fame_gained`. Returns the
+/// number of defeated enemies per participant (keyed by player index), or
+/// `None` for non-cooperative combat.
+pub(crate) fn settle_spoils(
+    state: &mut GameState,
+    combatant_idx: usize,
+) -> Option<BTreeMap<usize, usize>> {
+    let combat = state.combat.as_ref()?;
+    let assignments = combat.enemy_assignments.as_ref()?;
+
+    let mut defeated_counts: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut fame_owed: BTreeMap<usize, u32> = BTreeMap::new();
+    for (player_id, instance_ids) in assignments {
+        let Some(p_idx) = state.players.iter().position(|p| p.id.as_str() == player_id) else {
+            continue;
+        };
+        let count = defeated_counts.entry(p_idx).or_insert(0);
+        for enemy in &combat.enemies {
+            if !enemy.is_defeated || !instance_ids.iter().any(|id| id == enemy.instance_id.as_str()) {
+                continue;
+            }
+            *count += 1;
+            if p_idx != combatant_idx {
+                let fame = combat
+                    .fame_by_enemy
+                    .get(enemy.instance_id.as_str())
+                    .copied()
+                    .unwrap_or(0);
+                *fame_owed.entry(p_idx).or_insert(0) += fame;
+            }
+        }
+    }
+
+    for (p_idx, fame) in fame_owed {
+        let moved = fame.min(state.players[combatant_idx].fame);
+        state.players[combatant_idx].fame -= moved;
+        state.players[p_idx].fame += moved;
+        if let Some(ref mut combat) = state.combat {
+            combat.fame_gained = combat.fame_gained.saturating_sub(moved);
+        }
+    }
+
+    Some(defeated_counts)
+}
+
+/// Pick the leader of a cooperatively conquered city: the participant who
+/// defeated the most enemies, with ties going to the combatant (initiator).
+pub(crate) fn city_leader(defeated_counts: &BTreeMap<usize, usize>, combatant_idx: usize) -> usize {
+    let combatant_count = defeated_counts.get(&combatant_idx).copied().unwrap_or(0);
+    defeated_counts
+        .iter()
+        .filter(|&(_, &count)| count > combatant_count)
+        .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(&idx, _)| idx)
+        .unwrap_or(combatant_idx)
+}
+
 // =============================================================================
 // Cancel
 // =============================================================================
@@ -890,7 +956,7 @@ mod tests {
         let mut state2 = setup_coop_game(city_pos, 4);
         place_players_adjacent(&mut state2, city_pos);
         // Sync RNG
-        state2.rng = state1.rng.clone();
+        state2.rng = state1.rng;
 
         apply_propose(&mut state1, 0, city_pos, &[1], &[(0, 2), (1, 2)]).unwrap();
         apply_respond(&mut state1, 1, true).unwrap();
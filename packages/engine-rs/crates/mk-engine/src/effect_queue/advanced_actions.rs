@@ -170,7 +170,6 @@ pub(super) fn apply_rush_of_adrenaline(
             if wounds_taken > 0 {
                 if let Some(wound_idx) = player.hand.iter().position(|c| c.as_str() == WOUND_CARD_ID) {
                     player.hand.remove(wound_idx);
-                    player.removed_cards.push(CardId::from(WOUND_CARD_ID));
                     thrown = true;
                     // Draw 1 card for the thrown wound
                     draw_one_card(player);
@@ -184,6 +183,10 @@ pub(super) fn apply_rush_of_adrenaline(
                 draw_one_card(player);
             }
 
+            if thrown {
+                return_wounds_to_pile(state, 1);
+            }
+
             // Apply modifier for future wounds
             let remaining = 3u32.saturating_sub(retroactive);
             if remaining > 0 {
@@ -487,7 +490,7 @@ pub(super) fn apply_magic_talent_powered(state: &mut GameState, player_idx: usiz
             let spell_id = spell_id.clone();
             if offer_idx < state.offers.spells.len() {
                 state.offers.spells.remove(offer_idx);
-                replenish_spell_offer(state);
+                crate::offers::replenish_spell_offer(state);
             }
             state.players[player_idx].discard.push(spell_id);
             ResolveResult::Applied
@@ -504,7 +507,7 @@ pub(super) fn apply_magic_talent_powered(state: &mut GameState, player_idx: usiz
 
 pub(super) fn apply_blood_of_ancients_basic(state: &mut GameState, player_idx: usize) -> ResolveResult {
     // Take wound to hand
-    state.players[player_idx].hand.push(CardId::from(WOUND_CARD_ID));
+    add_wounds_to_hand(state, player_idx, 1);
     state.players[player_idx].wounds_received_this_turn.hand += 1;
 
     // Build mana options: for each basic color with available mana AND matching AAs
@@ -552,7 +555,7 @@ pub(super) fn apply_blood_of_ancients_basic(state: &mut GameState, player_idx: u
             let (offer_idx, aa_id) = &matching[0];
             let aa_id = aa_id.clone();
             state.offers.advanced_actions.remove(*offer_idx);
-            replenish_aa_offer(state);
+            crate::offers::replenish_aa_offer(state);
             state.players[player_idx].hand.push(aa_id);
         }
         // If multiple matching AAs, would need another choice — but for auto-select we just take first
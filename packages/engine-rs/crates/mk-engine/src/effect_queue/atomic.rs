@@ -208,6 +208,7 @@ pub(super) fn apply_gain_healing(state: &mut GameState, player_idx: usize, amoun
 
     player.healing_points += amount.saturating_sub(to_heal);
     player.wounds_healed_from_hand_this_turn += healed;
+    return_wounds_to_pile(state, healed);
 
     // Hook: Golden Grail modifiers
     if healed > 0 {
@@ -331,7 +332,7 @@ pub(super) fn apply_take_wound(state: &mut GameState, player_idx: usize) -> Reso
         player.flags.remove(PlayerFlags::WOUND_IMMUNITY_ACTIVE);
         return ResolveResult::Applied; // Wound blocked
     }
-    player.hand.push(CardId::from(WOUND_CARD_ID));
+    add_wounds_to_hand(state, player_idx, 1);
     ResolveResult::Applied
 }
 
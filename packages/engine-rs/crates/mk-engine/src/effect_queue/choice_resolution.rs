@@ -323,6 +323,15 @@ pub(crate) fn resolve_pending_choice_with_undo(
                 );
             }
         }
+        ChoiceResolution::PuppetMasterKeepToken { ref enemy_ids } => {
+            let skill_id = choice.skill_id.clone().unwrap_or_else(|| {
+                mk_types::ids::SkillId::from("krang_puppet_master")
+            });
+            let ids = enemy_ids.clone();
+            crate::action_pipeline::execute_puppet_master_keep(
+                state, player_idx, &skill_id, &ids, choice_index,
+            );
+        }
         ChoiceResolution::PuppetMasterUseMode {
             token_index, attack_value, attack_element, block_value, block_element,
         } => {
@@ -495,8 +504,12 @@ pub(crate) fn resolve_pending_choice_with_undo(
                 let card_id = source_card_id.clone();
 
                 // Consume the selected mana source
+                let for_spell = card_id
+                    .as_ref()
+                    .and_then(|cid| mk_data::cards::get_card(cid.as_str()))
+                    .is_some_and(|def| def.card_type == DeedCardType::Spell);
                 let consumed_color =
-                    crate::card_play::consume_specific_mana_source(state, player_idx, &source);
+                    crate::card_play::consume_mana_source(state, player_idx, &source, for_spell);
 
                 // Resolve the powered effect via effect queue
                 let mut queue = EffectQueue::new();
@@ -528,6 +541,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                             }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
 
                 // Mana trigger hooks
@@ -622,6 +636,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -650,6 +665,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -676,6 +692,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -713,6 +730,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -774,6 +792,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -793,6 +812,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                     let player = &mut state.players[player_idx];
                     if let Some(wound_idx) = player.hand.iter().position(|c| c.as_str() == WOUND_CARD_ID) {
                         player.hand.remove(wound_idx);
+                        return_wounds_to_pile(state, 1);
                     }
                 }
                 PeacefulMomentOption::RefreshUnit => {
@@ -843,6 +863,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -864,7 +885,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                     let (offer_idx, aa_id) = &matching_aas[0];
                     let aa_id = aa_id.clone();
                     state.offers.advanced_actions.remove(*offer_idx);
-                    replenish_aa_offer(state);
+                    crate::offers::replenish_aa_offer(state);
                     state.players[player_idx].hand.push(aa_id);
                 } else if matching_aas.len() > 1 {
                     let options: Vec<CardEffect> = matching_aas.iter().map(|_| CardEffect::Noop).collect();
@@ -898,6 +919,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -914,7 +936,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                 let (offer_idx, aa_id) = &matching_aas[choice_index];
                 let aa_id = aa_id.clone();
                 state.offers.advanced_actions.remove(*offer_idx);
-                replenish_aa_offer(state);
+                crate::offers::replenish_aa_offer(state);
                 state.players[player_idx].hand.push(aa_id);
             }
             // Continue with any continuation
@@ -937,18 +959,18 @@ pub(crate) fn resolve_pending_choice_with_undo(
                     }));
                 }
                 DrainResult::PendingSet => {}
+                DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
             }
             return Ok(());
         }
         ChoiceResolution::BloodPoweredWoundSelect => {
             // Choice 0 = wound to hand, choice 1 = wound to discard
-            let player = &mut state.players[player_idx];
             if choice_index == 0 {
-                player.hand.push(CardId::from(WOUND_CARD_ID));
-                player.wounds_received_this_turn.hand += 1;
+                add_wounds_to_hand(state, player_idx, 1);
+                state.players[player_idx].wounds_received_this_turn.hand += 1;
             } else {
-                player.discard.push(CardId::from(WOUND_CARD_ID));
-                player.wounds_received_this_turn.discard += 1;
+                add_wounds_to_discard(state, player_idx, 1);
+                state.players[player_idx].wounds_received_this_turn.discard += 1;
             }
             // Now present all AAs in offer
             let aa_count = state.offers.advanced_actions.len();
@@ -994,6 +1016,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                             }));
                         }
                         DrainResult::PendingSet => {}
+                        DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                     }
                     return Ok(());
                 }
@@ -1025,6 +1048,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                             }));
                         }
                         DrainResult::PendingSet => {}
+                        DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                     }
                     let _ = offer_index; // spell stays in offer
                     return Ok(());
@@ -1043,7 +1067,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                 // Remove spell from offer and add to discard
                 if offer_index < state.offers.spells.len() {
                     state.offers.spells.remove(offer_index);
-                    replenish_spell_offer(state);
+                    crate::offers::replenish_spell_offer(state);
                 }
                 state.players[player_idx].discard.push(spell_id.clone());
                 // Continue
@@ -1066,6 +1090,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -1077,7 +1102,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
             if choice_index < available_colors.len() {
                 let color = available_colors[choice_index];
                 resolve_mana_radiance(state, player_idx, color);
-                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo);
+                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo)?;
                 return Ok(());
             }
         }
@@ -1091,7 +1116,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
             ];
             if choice_index < colors.len() {
                 apply_gain_crystal_color(state, player_idx, colors[choice_index]);
-                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo);
+                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo)?;
                 return Ok(());
             }
         }
@@ -1135,6 +1160,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                                     }));
                                 }
                                 DrainResult::PendingSet => {}
+                                DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                             }
                             return Ok(());
                         } else if ability_entries.len() > 1 {
@@ -1157,7 +1183,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }
                     }
                 }
-                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo);
+                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo)?;
                 return Ok(());
             }
         }
@@ -1185,6 +1211,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                         }));
                     }
                     DrainResult::PendingSet => {}
+                    DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
                 }
                 return Ok(());
             }
@@ -1193,7 +1220,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
             if choice_index < eligible_unit_indices.len() {
                 let offer_idx = eligible_unit_indices[choice_index];
                 execute_free_recruit(state, player_idx, offer_idx);
-                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo);
+                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo)?;
                 return Ok(());
             }
         }
@@ -1206,7 +1233,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
             let done_offset = if targets_so_far > 0 { 1 } else { 0 };
             if targets_so_far > 0 && choice_index == 0 {
                 // Done — stop targeting
-                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo);
+                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo)?;
                 return Ok(());
             }
             let enemy_idx = choice_index - done_offset;
@@ -1259,7 +1286,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
                             source_card_id,
                             choice.continuation,
                             undo,
-                        );
+                        )?;
                     }
                 }
                 return Ok(());
@@ -1269,7 +1296,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
             if choice_index < eligible_enemy_ids.len() {
                 let enemy_id = eligible_enemy_ids[choice_index].clone();
                 execute_possess_enemy(state, player_idx, &enemy_id);
-                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo);
+                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo)?;
                 return Ok(());
             }
         }
@@ -1279,7 +1306,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
         } => {
             if choice_index == 0 {
                 // "Done"
-                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo);
+                resume_continuation(state, player_idx, source_card_id, choice.continuation, undo)?;
                 return Ok(());
             }
             let unit_idx_in_list = choice_index - 1;
@@ -1309,11 +1336,11 @@ pub(crate) fn resolve_pending_choice_with_undo(
                                 source_card_id,
                                 choice.continuation,
                                 undo,
-                            );
+                            )?;
                         }
                     }
                 } else {
-                    resume_continuation(state, player_idx, source_card_id, choice.continuation, undo);
+                    resume_continuation(state, player_idx, source_card_id, choice.continuation, undo)?;
                 }
                 return Ok(());
             }
@@ -1338,6 +1365,24 @@ pub(crate) fn resolve_pending_choice_with_undo(
             // For basic/powered, fall through to normal queue drain which handles
             // the Compound { [effect, GainFame(1)] }.
         }
+        ChoiceResolution::PityWoundRemoval => {
+            // Choice 1 = remove a Wound (hand first, then deck). Choice 0 = skip (Noop).
+            if choice_index == 1 {
+                let player = &mut state.players[player_idx];
+                let removed = if let Some(pos) = player.hand.iter().position(|c| c.as_str() == WOUND_CARD_ID) {
+                    player.hand.remove(pos);
+                    true
+                } else if let Some(pos) = player.deck.iter().position(|c| c.as_str() == WOUND_CARD_ID) {
+                    player.deck.remove(pos);
+                    true
+                } else {
+                    false
+                };
+                if removed {
+                    return_wounds_to_pile(state, 1);
+                }
+            }
+        }
     }
 
     // Build a new queue with the chosen option + continuation
@@ -1387,6 +1432,7 @@ pub(crate) fn resolve_pending_choice_with_undo(
             // A custom pending was set directly (e.g., DiscardForBonus inside a choice).
             Ok(())
         }
+        DrainResult::ResolutionLimitExceeded => Err(DrainResult::limit_error()),
     }
 }
 
@@ -1480,6 +1526,7 @@ pub(crate) fn resolve_discard_for_bonus_with_undo(
             Ok(())
         }
         DrainResult::PendingSet => Ok(()),
+        DrainResult::ResolutionLimitExceeded => Err(DrainResult::limit_error()),
     }
 }
 
@@ -1606,5 +1653,6 @@ pub(crate) fn resolve_decompose_with_undo(
             Ok(())
         }
         DrainResult::PendingSet => Ok(()),
+        DrainResult::ResolutionLimitExceeded => Err(DrainResult::limit_error()),
     }
 }
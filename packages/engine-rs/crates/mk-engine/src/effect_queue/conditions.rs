@@ -69,7 +69,7 @@ pub(crate) fn is_resolvable(state: &GameState, player_idx: usize, effect: &CardE
                 return true;
             }
             // Check source dice: basic color, available, not already used this turn
-            if player.flags.contains(PlayerFlags::USED_MANA_FROM_SOURCE) {
+            if !crate::card_play::can_use_source(state, player_idx) {
                 return false;
             }
             let player_id = &player.id;
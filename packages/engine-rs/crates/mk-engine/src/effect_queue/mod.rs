@@ -57,7 +57,9 @@ pub(crate) use self::choice_resolution::{
     resolve_discard_for_bonus_with_undo,
 };
 pub(crate) use self::conditions::is_resolvable;
-pub(crate) use self::utils::{gain_crystal_color, replenish_aa_offer, replenish_spell_offer};
+pub(crate) use self::utils::{
+    add_wounds_to_discard, add_wounds_to_hand, gain_crystal_color, return_wounds_to_pile,
+};
 
 // =============================================================================
 // Constants
@@ -74,6 +76,10 @@ const MIN_REPUTATION: i8 = -7;
 /// Maximum crystals per color.
 const MAX_CRYSTALS_PER_COLOR: u8 = 3;
 
+/// Maximum effect resolutions in a single drain. Real card and skill effects
+/// stay far below this; exceeding it means an effect keeps re-entering the queue.
+pub const MAX_EFFECT_RESOLUTIONS: usize = 10_000;
+
 // =============================================================================
 // Queue types
 // =============================================================================
@@ -100,6 +106,24 @@ pub enum DrainResult {
     /// A custom pending (not Choice) was set directly on the player.
     /// The player must resolve this pending before continuing.
     PendingSet,
+    /// More than `MAX_EFFECT_RESOLUTIONS` effects resolved without completing.
+    /// The remaining queue is discarded; callers report this as an internal error.
+    ResolutionLimitExceeded,
+}
+
+impl DrainResult {
+    /// Error message for `ResolutionLimitExceeded`, shared by all drain callers.
+    pub fn resolution_limit_message() -> String {
+        format!(
+            "effect queue exceeded {} resolutions in one drain (effect loop?)",
+            MAX_EFFECT_RESOLUTIONS
+        )
+    }
+
+    /// Choice-resolution error for `ResolutionLimitExceeded`.
+    pub(crate) fn limit_error() -> ResolveChoiceError {
+        ResolveChoiceError::InternalError(Self::resolution_limit_message())
+    }
 }
 
 /// Internal result from resolving a single effect.
@@ -179,7 +203,13 @@ impl EffectQueue {
         undo: Option<&mut UndoStack>,
     ) -> DrainResult {
         let mut undo_holder = undo;
+        let mut resolutions = 0usize;
         while let Some(queued) = self.queue.pop_front() {
+            resolutions += 1;
+            if resolutions > MAX_EFFECT_RESOLUTIONS {
+                self.queue.clear();
+                return DrainResult::ResolutionLimitExceeded;
+            }
             let source = queued.source_card_id.clone();
             match resolve::resolve_one(state, player_idx, &queued.effect, &mut undo_holder) {
                 ResolveResult::Applied => continue,
@@ -265,7 +295,10 @@ impl EffectQueue {
 /// Clears the healing-window flags, reads accumulated influence, and pushes a
 /// `PeacefulMomentConvert` effect through the queue. If options exist, a pending
 /// choice is set; otherwise, nothing happens (e.g. influence was 0).
-pub fn enter_peaceful_moment_conversion(state: &mut GameState, player_idx: usize) {
+pub fn enter_peaceful_moment_conversion(
+    state: &mut GameState,
+    player_idx: usize,
+) -> Result<(), ResolveChoiceError> {
     enter_peaceful_moment_conversion_with_undo(state, player_idx, None)
 }
 
@@ -273,7 +306,7 @@ pub(crate) fn enter_peaceful_moment_conversion_with_undo(
     state: &mut GameState,
     player_idx: usize,
     undo: Option<&mut UndoStack>,
-) {
+) -> Result<(), ResolveChoiceError> {
     let allow_refresh = state.players[player_idx]
         .flags
         .contains(PlayerFlags::PEACEFUL_MOMENT_ALLOW_REFRESH);
@@ -327,5 +360,7 @@ pub(crate) fn enter_peaceful_moment_conversion_with_undo(
         DrainResult::PendingSet => {
             // Custom pending was set — nothing more to do.
         }
+        DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
     }
+    Ok(())
 }
@@ -365,14 +365,14 @@ pub(super) fn pure_magic_effect_for_color(
 /// Find an available basic-color source die for crystallization.
 ///
 /// Returns `Some(ManaSourceInfo)` for the first matching die (basic color, unclaimed
-/// or stolen, `USED_MANA_FROM_SOURCE` not set).
+/// or stolen, and the Source still usable this turn).
 pub(super) fn find_crystallizable_die(
     state: &GameState,
     player_idx: usize,
     target_color: BasicManaColor,
 ) -> Option<mk_types::action::ManaSourceInfo> {
     let player = &state.players[player_idx];
-    if player.flags.contains(PlayerFlags::USED_MANA_FROM_SOURCE) {
+    if !crate::card_play::can_use_source(state, player_idx) {
         return None;
     }
     let player_id = &player.id;
@@ -437,7 +437,7 @@ pub(super) fn apply_convert_mana_to_crystal(state: &mut GameState, player_idx: u
     }
 
     // Also collect basic colors from available source dice
-    if !player.flags.contains(PlayerFlags::USED_MANA_FROM_SOURCE) {
+    if crate::card_play::can_use_source(state, player_idx) {
         let player_id = &player.id;
         let stolen_die_id = player
             .tactic_state
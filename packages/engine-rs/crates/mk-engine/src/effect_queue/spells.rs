@@ -514,6 +514,7 @@ pub(super) fn resolve_cure(state: &mut GameState, player_idx: usize, amount: u32
     let actual_draw = (wounds_removed as usize).min(player.deck.len());
     let drawn: Vec<CardId> = player.deck.drain(..actual_draw).collect();
     player.hand.extend(drawn);
+    return_wounds_to_pile(state, wounds_removed);
 
     ResolveResult::Applied
 }
@@ -760,7 +761,7 @@ pub(super) fn apply_mana_meltdown(
 
 /// Execute Mana Radiance: take wounds = crystals of chosen color, gain 2 crystals of that color.
 pub(super) fn resolve_mana_radiance(state: &mut GameState, player_idx: usize, color: BasicManaColor) {
-    let player = &mut state.players[player_idx];
+    let player = &state.players[player_idx];
     let crystal_count = match color {
         BasicManaColor::Red => player.crystals.red,
         BasicManaColor::Blue => player.crystals.blue,
@@ -768,9 +769,7 @@ pub(super) fn resolve_mana_radiance(state: &mut GameState, player_idx: usize, co
         BasicManaColor::White => player.crystals.white,
     };
     // Take wounds = number of crystals of that color
-    for _ in 0..crystal_count {
-        player.hand.push(CardId::from(WOUND_CARD_ID));
-    }
+    add_wounds_to_hand(state, player_idx, crystal_count as u32);
     // Gain 2 crystals of chosen color (capped at max)
     let slot = match color {
         BasicManaColor::Red => &mut state.players[player_idx].crystals.red,
@@ -1144,6 +1143,7 @@ pub(super) fn apply_meditation(
             version,
             phase: MeditationPhase::SelectCards,
             selected_card_ids: Vec::new(),
+            selected_discard_indices: Vec::new(),
         }));
     } else {
         // Basic: randomly pick 2 cards from discard
@@ -1185,6 +1185,7 @@ pub(super) fn apply_meditation(
             version,
             phase: MeditationPhase::PlaceCards,
             selected_card_ids,
+            selected_discard_indices: selected,
         }));
     }
 
@@ -262,6 +262,23 @@ use mk_types::state::*;
         assert_eq!(state.players[0].wounds_healed_from_hand_this_turn, 1);
     }
 
+    #[test]
+    fn take_wound_draws_from_finite_pile_only() {
+        let mut state = test_state();
+        let mut queue = EffectQueue::new();
+        queue.push(CardEffect::TakeWound, None);
+        queue.drain(&mut state, 0);
+        assert_eq!(state.wound_pile_count, None, "unlimited pile is untouched");
+
+        // An empty finite pile stays at 0; the wound is still taken.
+        state.wound_pile_count = Some(0);
+        let mut queue = EffectQueue::new();
+        queue.push(CardEffect::TakeWound, None);
+        queue.drain(&mut state, 0);
+        assert_eq!(state.wound_pile_count, Some(0));
+        assert_eq!(state.players[0].hand.iter().filter(|c| c.as_str() == "wound").count(), 2);
+    }
+
     #[test]
     fn gain_attack_requires_combat() {
         let mut state = test_state();
@@ -1296,6 +1313,106 @@ use mk_types::state::*;
         assert!(!state.players[0].pending.has_active());
     }
 
+    #[test]
+    fn compound_pauses_for_mid_sequence_choice_then_resumes_suffix() {
+        let mut state = test_state();
+        state.combat = Some(Box::new(CombatState {
+            phase: CombatPhase::Attack,
+            ..CombatState::default()
+        }));
+        let deck_before = state.players[0].deck.len();
+
+        // "Gain 2 move; choose attack 3 or block 3; draw a card"
+        let mut queue = EffectQueue::new();
+        queue.push(
+            CardEffect::Compound {
+                effects: vec![
+                    CardEffect::GainMove { amount: 2 },
+                    CardEffect::Choice {
+                        options: vec![
+                            CardEffect::GainAttack {
+                                amount: 3,
+                                combat_type: CombatType::Melee,
+                                element: Element::Physical,
+                            },
+                            CardEffect::GainBlock { amount: 3, element: Element::Physical },
+                        ],
+                    },
+                    CardEffect::DrawCards { count: 1 },
+                ],
+            },
+            None,
+        );
+        let (options, continuation) = match queue.drain(&mut state, 0) {
+            DrainResult::NeedsChoice { options, continuation, .. } => (options, continuation),
+            _ => panic!("Expected NeedsChoice"),
+        };
+        // Prefix resolved, suffix held back
+        assert_eq!(state.players[0].move_points, 2);
+        assert!(state.players[0].hand.is_empty());
+
+        state.players[0].pending.active = Some(ActivePending::Choice(PendingChoice {
+            card_id: None,
+            skill_id: None,
+            unit_instance_id: None,
+            options,
+            continuation: continuation
+                .into_iter()
+                .map(|q| ContinuationEntry { effect: q.effect, source_card_id: q.source_card_id })
+                .collect(),
+            movement_bonus_applied: false,
+            resolution: ChoiceResolution::Standard,
+        }));
+
+        resolve_pending_choice(&mut state, 0, 0).unwrap(); // attack
+        assert_eq!(state.players[0].combat_accumulator.attack.normal, 3);
+        assert_eq!(state.players[0].hand.len(), 1);
+        assert_eq!(state.players[0].deck.len(), deck_before - 1);
+        assert_eq!(state.players[0].move_points, 2);
+        assert!(!state.players[0].pending.has_active());
+    }
+
+    /// A compound that doubles at each level: 2^14 leaf resolutions, well past the cap.
+    fn runaway_compound() -> CardEffect {
+        let mut effect = CardEffect::GainMove { amount: 1 };
+        for _ in 0..14 {
+            effect = CardEffect::Compound { effects: vec![effect.clone(), effect] };
+        }
+        effect
+    }
+
+    #[test]
+    fn drain_stops_runaway_effect_at_resolution_limit() {
+        let mut state = test_state();
+        let mut queue = EffectQueue::new();
+        queue.push(runaway_compound(), None);
+
+        assert!(matches!(queue.drain(&mut state, 0), DrainResult::ResolutionLimitExceeded));
+        assert!(queue.is_empty(), "remaining queue is discarded");
+        assert!((state.players[0].move_points as usize) < MAX_EFFECT_RESOLUTIONS);
+    }
+
+    #[test]
+    fn choice_leading_into_runaway_effect_reports_internal_error() {
+        let mut state = test_state();
+        state.players[0].pending.active = Some(ActivePending::Choice(PendingChoice {
+            card_id: None,
+            skill_id: None,
+            unit_instance_id: None,
+            options: vec![runaway_compound(), CardEffect::Noop],
+            continuation: vec![],
+            movement_bonus_applied: false,
+            resolution: ChoiceResolution::Standard,
+        }));
+
+        match resolve_pending_choice(&mut state, 0, 0) {
+            Err(ResolveChoiceError::InternalError(msg)) => {
+                assert!(msg.contains("effect loop"), "unclear error: {msg}");
+            }
+            other => panic!("Expected InternalError, got {other:?}"),
+        }
+    }
+
     #[test]
     fn resolve_pending_choice_chain_produces_new_pending() {
         let mut state = test_state();
@@ -3931,12 +4048,14 @@ use mk_types::state::*;
         let mut state = test_state();
         state.players[0].wounds_received_this_turn.hand = 1;
         state.players[0].hand.push(CardId::from("wound"));
+        state.wound_pile_count = Some(5);
         let initial_deck = state.players[0].deck.len();
         let mut queue = EffectQueue::new();
         queue.push(CardEffect::RushOfAdrenaline { mode: EffectMode::Powered }, None);
         queue.drain(&mut state, 0);
-        // Wound thrown to removed_cards
-        assert!(state.players[0].removed_cards.iter().any(|c| c.as_str() == "wound"));
+        // Thrown wound goes back to the wound pile, not removed_cards
+        assert_eq!(state.wound_pile_count, Some(6));
+        assert!(!state.players[0].removed_cards.iter().any(|c| c.as_str() == "wound"));
         // Wound removed from hand
         assert!(!state.players[0].hand.iter().any(|c| c.as_str() == "wound"));
         // 1 draw for the thrown wound
@@ -3954,7 +4073,7 @@ use mk_types::state::*;
         queue.push(CardEffect::RushOfAdrenaline { mode: EffectMode::Powered }, None);
         queue.drain(&mut state, 0);
         // thrown=true, 1 draw for throw + 2 retroactive = 3 draws total
-        assert!(state.players[0].removed_cards.iter().any(|c| c.as_str() == "wound"));
+        assert!(!state.players[0].hand.iter().any(|c| c.as_str() == "wound"));
         // Modifier: remaining = 3 - 2 = 1
         let m = state.active_modifiers.iter().find(|m| {
             matches!(&m.effect, ModifierEffect::RushOfAdrenalineActive { .. })
@@ -4030,8 +4149,8 @@ use mk_types::state::*;
         let mut queue = EffectQueue::new();
         queue.push(CardEffect::RushOfAdrenaline { mode: EffectMode::Powered }, None);
         queue.drain(&mut state, 0);
-        // Wound thrown
-        assert!(state.players[0].removed_cards.iter().any(|c| c.as_str() == "wound"));
+        // Wound thrown back to the pile
+        assert!(!state.players[0].removed_cards.iter().any(|c| c.as_str() == "wound"));
         // No draw happened (empty deck)
         assert!(state.players[0].hand.is_empty());
     }
@@ -5522,7 +5641,7 @@ use mk_types::state::*;
         state.players[0].hand.push(CardId::from("wound"));
         state.players[0].influence_points = 5;
         state.players[0].flags.insert(PlayerFlags::IS_PEACEFUL_MOMENT_HEALING);
-        enter_peaceful_moment_conversion(&mut state, 0);
+        enter_peaceful_moment_conversion(&mut state, 0).unwrap();
         // Flags cleared
         assert!(!state.players[0].flags.contains(PlayerFlags::IS_PEACEFUL_MOMENT_HEALING));
         assert!(!state.players[0].flags.contains(PlayerFlags::PEACEFUL_MOMENT_ALLOW_REFRESH));
@@ -5549,7 +5668,7 @@ use mk_types::state::*;
         let mut unit = make_unit("u0", 1, false);
         unit.state = UnitState::Spent;
         state.players[0].units.push(unit);
-        enter_peaceful_moment_conversion(&mut state, 0);
+        enter_peaceful_moment_conversion(&mut state, 0).unwrap();
         match &state.players[0].pending.active {
             Some(ActivePending::Choice(pc)) => {
                 if let ChoiceResolution::PeacefulMomentConversion { allow_refresh, option_map, .. } = &pc.resolution {
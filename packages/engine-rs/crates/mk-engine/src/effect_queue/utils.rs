@@ -10,7 +10,7 @@ use mk_types::state::*;
 
 use crate::undo::UndoStack;
 
-use super::{DrainResult, EffectQueue, QueuedEffect, MAX_CRYSTALS_PER_COLOR, WOUND_CARD_ID};
+use super::{DrainResult, EffectQueue, QueuedEffect, ResolveChoiceError, MAX_CRYSTALS_PER_COLOR, WOUND_CARD_ID};
 
 pub(super) fn discard_eligible_cards(
     state: &mut GameState,
@@ -116,10 +116,39 @@ pub(super) fn increment_crystal(c: &mut Crystals, color: BasicManaColor) {
     *slot += 1;
 }
 
-/// Replenish the spell offer from the deck.
-pub fn replenish_spell_offer(state: &mut GameState) {
-    if let Some(card_id) = state.decks.spell_deck.pop() {
-        state.offers.spells.push(card_id);
+// =============================================================================
+// Wound pile
+// =============================================================================
+//
+// Every Wound card entering a player's hand or discard comes from the shared
+// wound pile, and every Wound healed or thrown away goes back to it. A
+// `wound_pile_count` of `None` means the pile is unlimited and is never
+// touched; a finite pile saturates at 0 (the wound is still taken).
+
+/// Take `count` Wounds from the pile into the player's hand.
+pub(crate) fn add_wounds_to_hand(state: &mut GameState, player_idx: usize, count: u32) {
+    take_from_wound_pile(state, count);
+    let hand = &mut state.players[player_idx].hand;
+    hand.extend((0..count).map(|_| CardId::from(WOUND_CARD_ID)));
+}
+
+/// Take `count` Wounds from the pile into the player's discard pile.
+pub(crate) fn add_wounds_to_discard(state: &mut GameState, player_idx: usize, count: u32) {
+    take_from_wound_pile(state, count);
+    let discard = &mut state.players[player_idx].discard;
+    discard.extend((0..count).map(|_| CardId::from(WOUND_CARD_ID)));
+}
+
+/// Return `count` healed or thrown-away Wounds to the pile.
+pub(crate) fn return_wounds_to_pile(state: &mut GameState, count: u32) {
+    if let Some(pile) = state.wound_pile_count.as_mut() {
+        *pile += count;
+    }
+}
+
+fn take_from_wound_pile(state: &mut GameState, count: u32) {
+    if let Some(pile) = state.wound_pile_count.as_mut() {
+        *pile = pile.saturating_sub(count);
     }
 }
 
@@ -213,9 +242,9 @@ pub(super) fn resume_continuation(
     source_card_id: Option<CardId>,
     continuation: Vec<ContinuationEntry>,
     undo: Option<&mut UndoStack>,
-) {
+) -> Result<(), ResolveChoiceError> {
     if continuation.is_empty() {
-        return;
+        return Ok(());
     }
     let mut queue = EffectQueue::new();
     queue.push_continuation(
@@ -252,7 +281,9 @@ pub(super) fn resume_continuation(
                 }));
         }
         DrainResult::PendingSet => {}
+        DrainResult::ResolutionLimitExceeded => return Err(DrainResult::limit_error()),
     }
+    Ok(())
 }
 
 /// Create a scaled copy of an effect with bonus added to its amount.
@@ -342,14 +373,6 @@ pub(super) fn scale_effect(effect: &CardEffect, bonus: u32) -> CardEffect {
     }
 }
 
-/// Replenish the advanced action offer from the deck after one is taken.
-pub fn replenish_aa_offer(state: &mut GameState) {
-    if !state.decks.advanced_action_deck.is_empty() {
-        let new_card = state.decks.advanced_action_deck.remove(0);
-        state.offers.advanced_actions.insert(0, new_card);
-    }
-}
-
 pub(super) fn crystal_count(c: &Crystals, color: BasicManaColor) -> u8 {
     match color {
         BasicManaColor::Red => c.red,
@@ -15,13 +15,16 @@ use mk_data::tactics::get_tactics_for_time;
 use mk_types::enums::*;
 use mk_types::ids::*;
 use mk_types::modifier::{ModifierEffect, RuleOverride};
+use mk_types::effect::CardEffect;
 use mk_types::pending::{
-    ActivePending, DeferredPending, PendingCrystalJoyReclaim, PendingLevelUpReward,
-    PendingSteadyTempoDeckPlacement, MAX_DEEP_MINE_COLORS, MAX_DRAWN_SKILLS,
+    ActivePending, ChoiceResolution, DeferredPending, EffectMode, PendingChoice,
+    PendingCrystalJoyReclaim, PendingLevelUpReward, PendingSteadyTempoDeckPlacement,
+    MAX_DEEP_MINE_COLORS, MAX_DRAWN_SKILLS,
 };
 use mk_types::state::*;
 
 use crate::card_play::is_rule_active;
+use crate::effect_queue::WOUND_CARD_ID;
 use crate::mana;
 use crate::mana::return_player_dice;
 use crate::setup::create_mana_source;
@@ -201,6 +204,15 @@ fn check_crystal_joy_reclaim(state: &mut GameState, player_idx: usize) -> bool {
     true
 }
 
+/// Whether Crystal Joy may reclaim `card_id` from the discard pile:
+/// basic excludes wounds, powered takes any card.
+pub(crate) fn crystal_joy_can_reclaim(version: EffectMode, card_id: &CardId) -> bool {
+    match version {
+        EffectMode::Basic => card_id.as_str() != WOUND_CARD_ID,
+        EffectMode::Powered => true,
+    }
+}
+
 /// Steady Tempo deck placement: offer to place the card on deck.
 /// Returns true if a pending choice was created.
 fn check_steady_tempo_placement(state: &mut GameState, player_idx: usize) -> bool {
@@ -223,7 +235,7 @@ fn apply_banner_protection_choice(state: &mut GameState, player_idx: usize) -> b
     {
         return false;
     }
-    let wounds = state.players[player_idx].wounds_received_this_turn;
+    let wounds = banner_protection_wounds(&state.players[player_idx]);
     if wounds.hand == 0 && wounds.discard == 0 {
         return false;
     }
@@ -231,6 +243,18 @@ fn apply_banner_protection_choice(state: &mut GameState, player_idx: usize) -> b
     true
 }
 
+/// Wounds Banner of Protection may still throw away: this turn's wounds, less
+/// any already healed from hand (never below zero).
+pub(crate) fn banner_protection_wounds(player: &PlayerState) -> WoundsReceived {
+    let received = player.wounds_received_this_turn;
+    WoundsReceived {
+        hand: received
+            .hand
+            .saturating_sub(player.wounds_healed_from_hand_this_turn),
+        discard: received.discard,
+    }
+}
+
 /// Source Opening: if returning player used the extra die, grant crystal to owner + reroll pending.
 /// Returns true if a pending choice was created.
 pub(crate) fn check_source_opening_crystal(state: &mut GameState, player_idx: usize) -> bool {
@@ -851,14 +875,6 @@ fn advance_turn(state: &mut GameState, current_player_idx: usize, is_time_bendin
         }
     }
 
-    // Extra turn check (The Right Moment tactic)
-    if state.players[current_player_idx].tactic_state.extra_turn_pending {
-        state.players[current_player_idx].tactic_state.extra_turn_pending = false;
-        return EndTurnResult::NextPlayer {
-            next_player_idx: current_player_idx,
-        };
-    }
-
     // Time Bending extra turn: same player takes another turn
     if is_time_bending {
         state.turn_number += 1;
@@ -875,6 +891,99 @@ fn advance_turn(state: &mut GameState, current_player_idx: usize, is_time_bendin
         };
     }
 
+    // Extra turn (The Right Moment tactic): the same player goes again, with the
+    // usual start-of-turn setup below. Interactive skills used during the first
+    // turn expire before the second, as with Time Bending.
+    let extra_turn = std::mem::take(
+        &mut state.players[current_player_idx].tactic_state.extra_turn_pending,
+    );
+
+    let next_turn_idx = if extra_turn {
+        expire_interactive_skills_for_player(state, &state.players[current_player_idx].id.clone());
+        state.current_player_index as usize
+    } else {
+        next_turn_order_index(state)
+    };
+
+    state.current_player_index = next_turn_idx as u32;
+    state.turn_number += 1;
+
+    // Find the player index in state.players for this turn_order entry
+    let next_player_id = &state.turn_order[next_turn_idx];
+    let next_player_idx = state
+        .players
+        .iter()
+        .position(|p| &p.id == next_player_id)
+        .expect("Turn order entry not found in players");
+
+    // Expire UntilNextTurn modifiers for the next player (at their turn start)
+    {
+        let player_id = state.players[next_player_idx].id.clone();
+        crate::action_pipeline::expire_modifiers_turn_start(
+            &mut state.active_modifiers,
+            &player_id,
+        );
+    }
+
+    // Expire Mana Enhancement if the new current player is the owner
+    if let Some(ref center) = state.mana_enhancement_center {
+        let next_player_id = &state.players[next_player_idx].id;
+        if center.owner_id == *next_player_id {
+            // In solo, only expire if the return window has passed (placed turn + 1 < current turn)
+            let should_expire = if state.dummy_player.is_some() {
+                state.turn_number > center.placement_turn + 1
+            } else {
+                true // multiplayer: expire when owner's turn comes back
+            };
+            if should_expire {
+                let skill_str = center.skill_id.as_str().to_string();
+                let owner_id = center.owner_id.clone();
+                state.active_modifiers.retain(|m| {
+                    !matches!(&m.source, mk_types::modifier::ModifierSource::Skill { skill_id: sid, player_id: oid }
+                        if sid.as_str() == skill_str && *oid == owner_id)
+                });
+                state.mana_enhancement_center = None;
+            }
+        }
+    }
+
+    // Solo: expire center interactive skills whose return window has passed
+    if state.dummy_player.is_some() {
+        let next_pid = state.players[next_player_idx].id.clone();
+        let pt = state.players[next_player_idx].skill_flip_state.placement_turn;
+        if state.turn_number > pt + 1 {
+            state.active_modifiers.retain(|m| {
+                !matches!(&m.source, mk_types::modifier::ModifierSource::Skill { player_id: owner, .. }
+                    if *owner == next_pid
+                        && matches!(m.duration, mk_types::modifier::ModifierDuration::Round)
+                        && matches!(m.scope, mk_types::modifier::ModifierScope::OtherPlayers))
+            });
+        }
+    }
+
+    // Grant Mana Claim sustained tokens for the next player
+    grant_mana_claim_sustained_tokens(state, next_player_idx);
+
+    // Setup next player: Magical Glade mana
+    apply_magical_glade_mana(state, next_player_idx);
+
+    // Sparing Power before-turn decision (resolved before anything else this turn)
+    apply_sparing_power_before_turn(state, next_player_idx);
+
+    // Setup next player: Plunder decision at unconquered inhabited sites
+    apply_plunder_decision(state, next_player_idx);
+
+    // Envy and Pity offer held since round end (waits behind any of the above)
+    promote_pity_wound_removal(state, next_player_idx);
+
+    EndTurnResult::NextPlayer {
+        next_player_idx,
+    }
+}
+
+/// Find the next real player's turn-order index, auto-executing dummy turns and
+/// skipping players whose Round Order token was flipped by a cooperative assault.
+fn next_turn_order_index(state: &mut GameState) -> usize {
     // Advance through turn_order, auto-executing dummy player turns
     let turn_order_len = state.turn_order.len();
     let mut next_turn_idx = (state.current_player_index as usize + 1) % turn_order_len;
@@ -955,88 +1064,7 @@ fn advance_turn(state: &mut GameState, current_player_idx: usize, is_time_bendin
         }
     }
 
-    state.current_player_index = next_turn_idx as u32;
-    state.turn_number += 1;
-
-    // Find the player index in state.players for this turn_order entry
-    let next_player_id = &state.turn_order[next_turn_idx];
-    let next_player_idx = state
-        .players
-        .iter()
-        .position(|p| &p.id == next_player_id)
-        .expect("Turn order entry not found in players");
-
-    // Expire UntilNextTurn modifiers for the next player (at their turn start)
-    {
-        let player_id = state.players[next_player_idx].id.clone();
-        crate::action_pipeline::expire_modifiers_turn_start(
-            &mut state.active_modifiers,
-            &player_id,
-        );
-    }
-
-    // Expire Mana Enhancement if the new current player is the owner
-    if let Some(ref center) = state.mana_enhancement_center {
-        let next_player_id = &state.players[next_player_idx].id;
-        if center.owner_id == *next_player_id {
-            // In solo, only expire if the return window has passed (placed turn + 1 < current turn)
-            let should_expire = if state.dummy_player.is_some() {
-                state.turn_number > center.placement_turn + 1
-            } else {
-                true // multiplayer: expire when owner's turn comes back
-            };
-            if should_expire {
-                let skill_str = center.skill_id.as_str().to_string();
-                let owner_id = center.owner_id.clone();
-                state.active_modifiers.retain(|m| {
-                    !matches!(&m.source, mk_types::modifier::ModifierSource::Skill { skill_id: sid, player_id: oid }
-                        if sid.as_str() == skill_str && *oid == owner_id)
-                });
-                state.mana_enhancement_center = None;
-            }
-        }
-    }
-
-    // Solo: expire center interactive skills whose return window has passed
-    if state.dummy_player.is_some() {
-        let next_pid = state.players[next_player_idx].id.clone();
-        let pt = state.players[next_player_idx].skill_flip_state.placement_turn;
-        if state.turn_number > pt + 1 {
-            state.active_modifiers.retain(|m| {
-                !matches!(&m.source, mk_types::modifier::ModifierSource::Skill { player_id: owner, .. }
-                    if *owner == next_pid
-                        && matches!(m.duration, mk_types::modifier::ModifierDuration::Round)
-                        && matches!(m.scope, mk_types::modifier::ModifierScope::OtherPlayers))
-            });
-        }
-    }
-
-    // Grant Mana Claim sustained tokens for the next player
-    grant_mana_claim_sustained_tokens(state, next_player_idx);
-
-    // Setup next player: Magical Glade mana
-    apply_magical_glade_mana(state, next_player_idx);
-
-    // Setup next player: Plunder decision at unconquered inhabited sites
-    apply_plunder_decision(state, next_player_idx);
-
-    // Sparing Power before-turn setup: if next player has sparing_power and hasn't flipped
-    {
-        let next_player = &state.players[next_player_idx];
-        if next_player.selected_tactic.as_ref().map(|t| t.as_str()) == Some("sparing_power")
-            && !next_player.flags.contains(PlayerFlags::TACTIC_FLIPPED)
-        {
-            let player = &mut state.players[next_player_idx];
-            player.flags.insert(PlayerFlags::BEFORE_TURN_TACTIC_PENDING);
-            player.pending.active = Some(mk_types::pending::ActivePending::TacticDecision(
-                mk_types::pending::PendingTacticDecision::SparingPower,
-            ));
-        }
-    }
-
-    EndTurnResult::NextPlayer {
-        next_player_idx,
-    }
+    next_turn_idx
 }
 
 /// Check if the round should end after this player's turn.
@@ -1171,6 +1199,9 @@ pub(crate) fn end_round(state: &mut GameState) {
         }
     }
 
+    // Envy and Pity keys off the round that just ended
+    let ended_time_of_day = state.time_of_day;
+
     // 1. Toggle day/night
     state.time_of_day = match state.time_of_day {
         TimeOfDay::Day => TimeOfDay::Night,
@@ -1225,7 +1256,7 @@ pub(crate) fn end_round(state: &mut GameState) {
 
     // Monastery AA offer refresh: return old → draw new per unburned monastery
     let unburned_monastery_count = count_unburned_monasteries(&state.map.hexes);
-    let old_monastery_aas: Vec<CardId> = state.offers.monastery_advanced_actions.drain(..).collect();
+    let old_monastery_aas: Vec<CardId> = std::mem::take(&mut state.offers.monastery_advanced_actions);
     for card in old_monastery_aas {
         state.decks.advanced_action_deck.push(card);
     }
@@ -1236,11 +1267,23 @@ pub(crate) fn end_round(state: &mut GameState) {
         }
     }
 
+    // 4a. Envy: Day round — the Fame leader's Wound is shuffled in with the rest
+    let envy_and_pity =
+        state.scenario_config.has_variant(Variant::EnvyAndPity) && state.players.len() > 1;
+    if envy_and_pity && ended_time_of_day == TimeOfDay::Day {
+        apply_envy_wound(state);
+    }
+
     // 5. Player round reset (reshuffle + draw)
     for player_idx in 0..state.players.len() {
         reset_player_round(state, player_idx);
     }
 
+    // 5b. Pity: Night round — last place may remove a Wound from the rebuilt deck
+    if envy_and_pity && ended_time_of_day == TimeOfDay::Night {
+        offer_pity_wound_removal(state);
+    }
+
     // 5a. Dummy player reset for new round
     if let Some(ref mut dummy) = state.dummy_player {
         crate::dummy_player::reset_dummy_for_new_round(dummy, &mut state.rng);
@@ -1299,6 +1342,71 @@ pub(crate) fn end_round(state: &mut GameState) {
     crate::action_pipeline::expire_modifiers_round_end(&mut state.active_modifiers);
 }
 
+/// Envy and Pity (Day round end): the sole Fame leader adds a Wound to their deck.
+/// No Wound is given when the lead is tied.
+fn apply_envy_wound(state: &mut GameState) {
+    let Some(max_fame) = state.players.iter().map(|p| p.fame).max() else {
+        return;
+    };
+    let leaders: Vec<usize> = (0..state.players.len())
+        .filter(|&i| state.players[i].fame == max_fame)
+        .collect();
+    if let [leader_idx] = leaders[..] {
+        crate::effect_queue::add_wounds_to_discard(state, leader_idx, 1);
+    }
+}
+
+/// Envy and Pity (Night round end): every player tied for the lowest Fame may
+/// remove one Wound from hand or deck.
+///
+/// The round continues with tactic selection, so the offer is deferred and
+/// promoted as a skip/remove choice once the player's first turn starts.
+fn offer_pity_wound_removal(state: &mut GameState) {
+    let Some(min_fame) = state.players.iter().map(|p| p.fame).min() else {
+        return;
+    };
+    for player in state.players.iter_mut().filter(|p| p.fame == min_fame) {
+        let has_wound = player
+            .hand
+            .iter()
+            .chain(player.deck.iter())
+            .any(|c| c.as_str() == WOUND_CARD_ID);
+        if !has_wound || player.pending.deferred.is_full() {
+            continue;
+        }
+        player.pending.deferred.push(DeferredPending::PityWoundRemoval);
+    }
+}
+
+/// Promote a deferred Pity offer once it is the player's turn and nothing else is pending.
+pub(crate) fn promote_pity_wound_removal(state: &mut GameState, player_idx: usize) {
+    let on_turn = state.round_phase == RoundPhase::PlayerTurns
+        && state.turn_order.get(state.current_player_index as usize)
+            == Some(&state.players[player_idx].id);
+    let player = &mut state.players[player_idx];
+    if !on_turn || player.pending.has_active() {
+        return;
+    }
+    let Some(pos) = player
+        .pending
+        .deferred
+        .iter()
+        .position(|d| matches!(d, DeferredPending::PityWoundRemoval))
+    else {
+        return;
+    };
+    player.pending.deferred.remove(pos);
+    player.pending.active = Some(ActivePending::Choice(PendingChoice {
+        card_id: None,
+        skill_id: None,
+        unit_instance_id: None,
+        options: vec![CardEffect::Noop, CardEffect::Noop],
+        continuation: Vec::new(),
+        movement_bonus_applied: false,
+        resolution: ChoiceResolution::PityWoundRemoval,
+    }));
+}
+
 /// Reset a single player for a new round: reshuffle all cards, draw up to hand limit.
 ///
 /// Matches TS `processPlayerRoundReset()` in `playerRoundReset.ts`.
@@ -1308,7 +1416,8 @@ fn reset_player_round(state: &mut GameState, player_idx: usize) {
 
     let player = &mut state.players[player_idx];
 
-    // Collect all cards: hand + discard + play_area + deck (minus removedCards)
+    // Collect all cards: hand + discard + play_area + deck (minus removedCards).
+    // Wounds are not filtered — they shuffle back into the deck like any other card.
     let mut all_cards: Vec<CardId> = Vec::new();
     all_cards.append(&mut player.hand);
     all_cards.append(&mut player.discard);
@@ -1417,6 +1526,11 @@ fn apply_magical_glade_wound(state: &mut GameState, player_idx: usize) -> bool {
             player.discard.remove(idx);
         }
     }
+
+    // Removed wound returns to the wound pile
+    if has_hand_wound || has_discard_wound {
+        crate::effect_queue::return_wounds_to_pile(state, 1);
+    }
     false
 }
 
@@ -1570,6 +1684,25 @@ fn expire_interactive_skills_for_player(state: &mut GameState, player_id: &Playe
     }
 }
 
+/// Sparing Power: while the tactic is unflipped, prompt stash-or-take before each turn.
+///
+/// Also called when the round's first turn begins after tactic selection.
+pub(crate) fn apply_sparing_power_before_turn(state: &mut GameState, player_idx: usize) {
+    let player = &state.players[player_idx];
+    if player.selected_tactic.as_ref().map(|t| t.as_str()) != Some("sparing_power")
+        || player.flags.contains(PlayerFlags::TACTIC_FLIPPED)
+        || player.pending.has_active()
+    {
+        return;
+    }
+
+    let player = &mut state.players[player_idx];
+    player.flags.insert(PlayerFlags::BEFORE_TURN_TACTIC_PENDING);
+    player.pending.active = Some(ActivePending::TacticDecision(
+        mk_types::pending::PendingTacticDecision::SparingPower,
+    ));
+}
+
 /// Plunder decision: if player starts turn at an unconquered, unburned Village.
 /// Only villages can be plundered (rulebook: "You can plunder a village").
 fn apply_plunder_decision(state: &mut GameState, player_idx: usize) {
@@ -1770,6 +1903,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn leftover_healing_only_heals_hand_and_is_lost_at_end_of_turn() {
+        use crate::effect_queue::EffectQueue;
+
+        let mut state = setup_playing_game(vec!["wound", "march"]);
+        state.players[0].discard.push(CardId::from("wound"));
+
+        let mut queue = EffectQueue::new();
+        queue.push(CardEffect::GainHealing { amount: 3 }, None);
+        queue.drain(&mut state, 0);
+
+        let player = &state.players[0];
+        assert_eq!(player.hand, vec![CardId::from("march")], "the hand wound is healed");
+        assert_eq!(player.wounds_healed_from_hand_this_turn, 1);
+        assert_eq!(player.healing_points, 2, "healing beyond the hand wounds is banked");
+        assert_eq!(
+            player.discard.iter().filter(|c| c.as_str() == WOUND_CARD_ID).count(),
+            1,
+            "healing points never reach wounds in the discard pile"
+        );
+
+        play_card(&mut state, 0, 0, false, None).unwrap();
+        end_turn(&mut state, 0).unwrap();
+
+        assert_eq!(state.players[0].healing_points, 0, "unused healing is lost");
+        assert_eq!(
+            state.players[0].discard.iter().filter(|c| c.as_str() == WOUND_CARD_ID).count(),
+            1
+        );
+    }
+
     #[test]
     fn end_turn_clears_mana_tokens() {
         let mut state = setup_playing_game(vec!["march"]);
@@ -1973,6 +2137,44 @@ mod tests {
         assert!(state.players[0].play_area.is_empty());
     }
 
+    #[test]
+    fn round_end_rebuilds_deck_from_all_cards_including_wounds() {
+        let mut state = setup_playing_game(vec!["wound", "rage"]);
+        state.players[0].discard = vec![
+            CardId::from("march"),
+            CardId::from("stamina"),
+            CardId::from("wound"),
+        ];
+        state.players[0].play_area = vec![CardId::from("swiftness")];
+        state.players[0].deck = vec![CardId::from("promise")];
+        let mut replay = state.clone();
+
+        end_round(&mut state);
+
+        // Every card (wounds included) comes back: 5 drawn, rest in deck
+        let player = &state.players[0];
+        assert_eq!(player.hand.len(), 5);
+        assert_eq!(player.deck.len(), 2);
+        assert!(player.discard.is_empty());
+        assert!(player.play_area.is_empty());
+        let mut all: Vec<&str> = player
+            .hand
+            .iter()
+            .chain(player.deck.iter())
+            .map(|c| c.as_str())
+            .collect();
+        all.sort_unstable();
+        assert_eq!(
+            all,
+            vec!["march", "promise", "rage", "stamina", "swiftness", "wound", "wound"]
+        );
+
+        // Same seed → same shuffle
+        end_round(&mut replay);
+        assert_eq!(replay.players[0].hand, state.players[0].hand);
+        assert_eq!(replay.players[0].deck, state.players[0].deck);
+    }
+
     #[test]
     fn round_end_resets_mana_source() {
         let mut state = setup_playing_game(vec!["march"]);
@@ -2074,6 +2276,40 @@ mod tests {
         assert!(state.players[0].units[0].wounded);
     }
 
+    #[test]
+    fn round_end_raises_maintenance_for_familiars_holding_mana() {
+        let mut state = setup_playing_game(vec!["march"]);
+        state.players[0].deck.clear();
+        for (iid, token) in [("unit_0", true), ("unit_1", false)] {
+            state.players[0].units.push(PlayerUnit {
+                instance_id: UnitInstanceId::from(iid),
+                unit_id: UnitId::from("magic_familiars"),
+                level: 2,
+                state: UnitState::Spent,
+                wounded: false,
+                used_resistance_this_combat: false,
+                used_ability_indices: Vec::new(),
+                mana_token: token.then_some(ManaToken {
+                    color: ManaColor::Blue,
+                    source: ManaTokenSource::Effect,
+                    cannot_power_spells: false,
+                }),
+            });
+        }
+
+        play_card(&mut state, 0, 0, false, None).unwrap();
+        end_turn(&mut state, 0).unwrap();
+
+        // Only the familiar holding a mana token needs upkeep
+        match &state.players[0].pending.active {
+            Some(ActivePending::UnitMaintenance(entries)) => {
+                assert_eq!(entries.len(), 1);
+                assert_eq!(entries[0].unit_instance_id.as_str(), "unit_0");
+            }
+            other => panic!("expected UnitMaintenance pending, got {:?}", other),
+        }
+    }
+
     #[test]
     fn round_end_clears_selected_tactic() {
         let mut state = setup_playing_game(vec!["march"]);
@@ -2086,6 +2322,145 @@ mod tests {
         assert!(state.players[0].selected_tactic.is_none());
     }
 
+    // =========================================================================
+    // Envy and Pity variant
+    // =========================================================================
+
+    fn setup_envy_and_pity_game(fames: [u32; 2]) -> GameState {
+        let mut state =
+            crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+        state.scenario_config.variants = vec![Variant::EnvyAndPity];
+        for (player, fame) in state.players.iter_mut().zip(fames) {
+            player.fame = fame;
+        }
+        state
+    }
+
+    fn has_pity_offer(player: &PlayerState) -> bool {
+        player.pending.deferred.iter().any(|d| matches!(d, DeferredPending::PityWoundRemoval))
+    }
+
+    /// Start `player_idx`'s turn so a deferred Pity offer can be promoted.
+    fn start_turn_of(state: &mut GameState, player_idx: usize) {
+        state.round_phase = RoundPhase::PlayerTurns;
+        state.current_player_index = state
+            .turn_order
+            .iter()
+            .position(|id| *id == state.players[player_idx].id)
+            .unwrap() as u32;
+        promote_pity_wound_removal(state, player_idx);
+    }
+
+    fn wound_count(player: &PlayerState) -> usize {
+        player
+            .hand
+            .iter()
+            .chain(player.deck.iter())
+            .filter(|c| c.as_str() == WOUND_CARD_ID)
+            .count()
+    }
+
+    #[test]
+    fn envy_day_round_end_wounds_fame_leader() {
+        let mut state = setup_envy_and_pity_game([10, 4]);
+        end_round(&mut state);
+
+        assert_eq!(wound_count(&state.players[0]), 1);
+        assert_eq!(wound_count(&state.players[1]), 0);
+    }
+
+    #[test]
+    fn envy_no_wound_when_lead_is_tied() {
+        let mut state = setup_envy_and_pity_game([7, 7]);
+        end_round(&mut state);
+
+        assert!(state.players.iter().all(|p| wound_count(p) == 0));
+    }
+
+    #[test]
+    fn envy_disabled_without_scenario_flag() {
+        let mut state = setup_envy_and_pity_game([10, 4]);
+        state.scenario_config.variants.clear();
+        end_round(&mut state);
+
+        assert_eq!(wound_count(&state.players[0]), 0);
+    }
+
+    #[test]
+    fn pity_night_round_end_last_place_may_remove_wound() {
+        let mut state = setup_envy_and_pity_game([10, 4]);
+        state.time_of_day = TimeOfDay::Night;
+        for player in &mut state.players {
+            player.discard.push(CardId::from(WOUND_CARD_ID));
+        }
+        end_round(&mut state);
+
+        // Leader: no offer, and no Envy wound at night
+        assert!(!has_pity_offer(&state.players[0]));
+        assert_eq!(wound_count(&state.players[0]), 1);
+        // Held through tactic selection, offered when the first turn starts
+        assert!(has_pity_offer(&state.players[1]));
+        assert!(state.players[1].pending.active.is_none());
+
+        start_turn_of(&mut state, 1);
+        assert!(matches!(
+            &state.players[1].pending.active,
+            Some(ActivePending::Choice(c)) if matches!(c.resolution, ChoiceResolution::PityWoundRemoval)
+        ));
+
+        crate::effect_queue::resolve_pending_choice(&mut state, 1, 1).unwrap();
+        assert_eq!(wound_count(&state.players[1]), 0);
+        assert!(state.players[1].pending.active.is_none());
+    }
+
+    #[test]
+    fn pity_tied_last_place_all_offered_and_may_skip() {
+        let mut state = setup_envy_and_pity_game([3, 3]);
+        state.time_of_day = TimeOfDay::Night;
+        for player in &mut state.players {
+            player.discard.push(CardId::from(WOUND_CARD_ID));
+        }
+        end_round(&mut state);
+
+        assert!(state.players.iter().all(has_pity_offer));
+
+        start_turn_of(&mut state, 0);
+        assert!(matches!(
+            &state.players[0].pending.active,
+            Some(ActivePending::Choice(c)) if matches!(c.resolution, ChoiceResolution::PityWoundRemoval)
+        ));
+        crate::effect_queue::resolve_pending_choice(&mut state, 0, 0).unwrap();
+        assert_eq!(wound_count(&state.players[0]), 1);
+        assert!(state.players[0].pending.active.is_none());
+    }
+
+    #[test]
+    fn pity_not_offered_without_wounds() {
+        let mut state = setup_envy_and_pity_game([10, 4]);
+        state.time_of_day = TimeOfDay::Night;
+        end_round(&mut state);
+
+        assert!(state.players.iter().all(|p| !has_pity_offer(p) && p.pending.active.is_none()));
+    }
+
+    #[test]
+    fn pity_offer_waits_behind_active_pending() {
+        let mut state = setup_envy_and_pity_game([10, 4]);
+        state.time_of_day = TimeOfDay::Night;
+        state.players[1].discard.push(CardId::from(WOUND_CARD_ID));
+        end_round(&mut state);
+
+        state.players[1].pending.active = Some(ActivePending::BannerProtectionChoice);
+        start_turn_of(&mut state, 1);
+        assert!(matches!(state.players[1].pending.active, Some(ActivePending::BannerProtectionChoice)));
+        assert!(has_pity_offer(&state.players[1]));
+
+        state.players[1].pending.active = None;
+        promote_pity_wound_removal(&mut state, 1);
+        assert!(!has_pity_offer(&state.players[1]));
+        assert!(state.players[1].pending.active.is_some());
+    }
+
     // =========================================================================
     // Multiple turns within a round
     // =========================================================================
@@ -2430,6 +2805,7 @@ mod tests {
         place_on_site(&mut state, SiteType::MagicalGlade);
         // Put a wound in discard but not hand
         state.players[0].discard.push(CardId::from("wound"));
+        state.wound_pile_count = Some(5);
 
         play_card(&mut state, 0, 0, false, None).unwrap();
         end_turn(&mut state, 0).unwrap();
@@ -2439,6 +2815,7 @@ mod tests {
             !state.players[0].discard.iter().any(|c| c.as_str() == "wound"),
             "Glade should auto-remove wound from discard"
         );
+        assert_eq!(state.wound_pile_count, Some(6), "Removed wound returns to the pile");
         assert!(!state.players[0].pending.has_active(), "No pending when only discard has wounds");
     }
 
@@ -3341,6 +3718,45 @@ mod tests {
         assert!(!state.players[0].pending.has_active());
     }
 
+    #[test]
+    fn crystal_joy_basic_rejects_wound_and_reclaims_chosen_card() {
+        use crate::action_pipeline::apply_legal_action;
+        use crate::legal_actions::enumerate_legal_actions;
+        use crate::undo::UndoStack;
+        use mk_types::legal_action::LegalAction;
+
+        let mut state = setup_playing_game(vec!["march"]);
+        state.players[0].deck = (0..5).map(|i| CardId::from(format!("c{}", i))).collect();
+        state.players[0].crystal_joy_reclaim_version =
+            Some(mk_types::pending::EffectMode::Basic);
+        state.players[0].discard = vec![CardId::from("wound"), CardId::from("rage")];
+
+        play_card(&mut state, 0, 0, false, None).unwrap();
+        end_turn(&mut state, 0).unwrap();
+
+        let legal = enumerate_legal_actions(&state, 0);
+        let wound_pick = LegalAction::ResolveCrystalJoyReclaim { discard_index: Some(0) };
+        assert!(!legal.actions.contains(&wound_pick), "wound must not be offered");
+        assert!(legal
+            .actions
+            .contains(&LegalAction::ResolveCrystalJoyReclaim { discard_index: None }));
+
+        // Submitting the wound anyway is rejected and the choice stays open
+        let mut undo = UndoStack::new();
+        assert!(apply_legal_action(&mut state, &mut undo, 0, &wound_pick, legal.epoch).is_err());
+        assert!(matches!(
+            state.players[0].pending.active,
+            Some(ActivePending::CrystalJoyReclaim(_))
+        ));
+
+        let epoch = state.action_epoch;
+        let rage_pick = LegalAction::ResolveCrystalJoyReclaim { discard_index: Some(1) };
+        apply_legal_action(&mut state, &mut undo, 0, &rage_pick, epoch).unwrap();
+        assert!(state.players[0].hand.iter().any(|c| c.as_str() == "rage"));
+        assert!(!state.players[0].discard.iter().any(|c| c.as_str() == "rage"));
+        assert!(state.players[0].discard.iter().any(|c| c.as_str() == "wound"));
+    }
+
     #[test]
     fn crystal_joy_skip_no_card_moved() {
         use crate::action_pipeline::apply_legal_action;
@@ -3505,6 +3921,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn steady_tempo_powered_play_returns_in_next_opening_hand() {
+        use crate::action_pipeline::apply_legal_action;
+        use crate::legal_actions::enumerate_legal_actions;
+        use crate::undo::UndoStack;
+        use mk_types::legal_action::LegalAction;
+
+        let mut state = setup_playing_game(vec!["steady_tempo"]);
+        state.players[0].deck = (0..5).map(|i| CardId::from(format!("c{}", i))).collect();
+        state.players[0].pure_mana.push(ManaToken {
+            color: ManaColor::Blue,
+            source: ManaTokenSource::Effect,
+            cannot_power_spells: false,
+        });
+
+        play_card(&mut state, 0, 0, true, None).unwrap();
+        end_turn(&mut state, 0).unwrap();
+
+        let legal = enumerate_legal_actions(&state, 0);
+        assert!(legal
+            .actions
+            .contains(&LegalAction::ResolveSteadyTempoDeckPlacement { place: true }));
+        let mut undo = UndoStack::new();
+        let action = LegalAction::ResolveSteadyTempoDeckPlacement { place: true };
+        apply_legal_action(&mut state, &mut undo, 0, &action, legal.epoch).unwrap();
+
+        // Placed on top, so it is the first card of the next turn's hand
+        let player = &state.players[0];
+        assert_eq!(player.hand.first().map(|c| c.as_str()), Some("steady_tempo"));
+        assert_eq!(
+            player.hand.iter().filter(|c| c.as_str() == "steady_tempo").count(),
+            1
+        );
+        assert!(!player.discard.iter().any(|c| c.as_str() == "steady_tempo"));
+        assert!(!player.deck.iter().any(|c| c.as_str() == "steady_tempo"));
+        assert!(player.play_area.is_empty());
+    }
+
     // =========================================================================
     // Banner of Protection (via end-turn pending)
     // =========================================================================
@@ -3578,6 +4032,8 @@ mod tests {
             .play_area
             .push(CardId::from("banner_of_protection"));
 
+        state.wound_pile_count = Some(10);
+
         play_card(&mut state, 0, 0, false, None).unwrap();
         end_turn(&mut state, 0).unwrap();
         // Pending BannerProtectionChoice
@@ -3588,6 +4044,9 @@ mod tests {
         let action = LegalAction::ResolveBannerProtection { remove_all: true };
         apply_legal_action(&mut state, &mut undo, 0, &action, legal.epoch).unwrap();
 
+        // Both thrown-away wounds go back to the wound pile
+        assert_eq!(state.wound_pile_count, Some(12));
+
         // Wounds should be removed from hand and discard
         assert!(
             !state.players[0]
@@ -3646,6 +4105,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn banner_protection_spares_wounds_from_earlier_turns() {
+        use crate::action_pipeline::apply_legal_action;
+        use crate::legal_actions::enumerate_legal_actions;
+        use crate::undo::UndoStack;
+        use mk_types::legal_action::LegalAction;
+
+        let total_wounds = |p: &PlayerState| {
+            p.hand.iter().chain(&p.deck).chain(&p.discard).chain(&p.play_area)
+                .filter(|c| c.as_str() == "wound")
+                .count()
+        };
+
+        // Two wounds in hand and two in discard; one of each arrived this turn
+        let mut state = setup_playing_game(vec!["march", "wound", "wound"]);
+        state.players[0].deck = (0..5).map(|i| CardId::from(format!("c{}", i))).collect();
+        state.players[0].discard = vec![CardId::from("wound"), CardId::from("wound")];
+        state.players[0]
+            .flags
+            .insert(PlayerFlags::BANNER_OF_PROTECTION_ACTIVE);
+        state.players[0].wounds_received_this_turn = WoundsReceived {
+            hand: 1,
+            discard: 1,
+        };
+        state.players[0]
+            .play_area
+            .push(CardId::from("banner_of_protection"));
+
+        play_card(&mut state, 0, 0, false, None).unwrap();
+        end_turn(&mut state, 0).unwrap();
+
+        let legal = enumerate_legal_actions(&state, 0);
+        let mut undo = UndoStack::new();
+        let action = LegalAction::ResolveBannerProtection { remove_all: true };
+        apply_legal_action(&mut state, &mut undo, 0, &action, legal.epoch).unwrap();
+
+        assert_eq!(total_wounds(&state.players[0]), 2, "earlier wounds must stay");
+        assert!(state.players[0]
+            .removed_cards
+            .iter()
+            .any(|c| c.as_str() == "banner_of_protection"));
+    }
+
+    #[test]
+    fn banner_protection_healed_wounds_do_not_go_negative() {
+        let mut state = setup_playing_game(vec!["march", "wound"]);
+        state.players[0].wounds_received_this_turn = WoundsReceived {
+            hand: 1,
+            discard: 0,
+        };
+        // Healed more from hand than arrived this turn; the remaining wound is old
+        state.players[0].wounds_healed_from_hand_this_turn = 2;
+
+        assert_eq!(
+            banner_protection_wounds(&state.players[0]),
+            WoundsReceived::default()
+        );
+
+        state.players[0]
+            .flags
+            .insert(PlayerFlags::BANNER_OF_PROTECTION_ACTIVE);
+        play_card(&mut state, 0, 0, false, None).unwrap();
+        let result = end_turn(&mut state, 0).unwrap();
+        assert!(
+            !matches!(result, EndTurnResult::AwaitingEndTurnChoice),
+            "Nothing left to throw away, so no pending"
+        );
+    }
+
     // =========================================================================
     // End-turn step re-entrancy
     // =========================================================================
@@ -3895,6 +4423,21 @@ mod tests {
                 // Pending created even with empty deck (basic can't place, but still offers skip)
                 let pending = &state.players[0].pending.active;
                 assert!(matches!(pending, Some(ActivePending::SteadyTempoDeckPlacement(_))));
+
+                // Placing anyway is rejected and the choice stays open
+                let mut undo = crate::undo::UndoStack::new();
+                let epoch = state.action_epoch;
+                let place = mk_types::legal_action::LegalAction::ResolveSteadyTempoDeckPlacement {
+                    place: true,
+                };
+                assert!(crate::action_pipeline::apply_legal_action(
+                    &mut state, &mut undo, 0, &place, epoch
+                )
+                .is_err());
+                assert!(matches!(
+                    state.players[0].pending.active,
+                    Some(ActivePending::SteadyTempoDeckPlacement(_))
+                ));
             }
             Ok(EndTurnResult::NextPlayer { .. } | EndTurnResult::RoundEnded { .. }) => {
                 // Auto-skipped — also fine
@@ -3979,7 +4522,7 @@ mod tests {
         match result {
             Ok(EndTurnResult::AwaitingEndTurnChoice) => {
                 let pending = &state.players[0].pending.active;
-                assert!(matches!(pending, Some(ActivePending::BannerProtectionChoice { .. })));
+                assert!(matches!(pending, Some(ActivePending::BannerProtectionChoice)));
             }
             _ => panic!("Expected AwaitingEndTurnChoice for banner protection"),
         }
@@ -4005,7 +4548,7 @@ mod tests {
             Ok(EndTurnResult::AwaitingEndTurnChoice) => {
                 // If there's a pending, it shouldn't be BannerProtectionChoice
                 let pending = &state.players[0].pending.active;
-                assert!(!matches!(pending, Some(ActivePending::BannerProtectionChoice { .. })),
+                assert!(!matches!(pending, Some(ActivePending::BannerProtectionChoice)),
                     "No wounds → no banner protection pending");
             }
             other => panic!("Unexpected result: {:?}", other),
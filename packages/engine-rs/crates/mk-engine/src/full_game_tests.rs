@@ -0,0 +1,63 @@
+//! Full-game tests — play every hero's solo game from setup to final scoring.
+//!
+//! Unlike the fuzz tests, which stop after a fixed number of actions, these
+//! keep going until `game_ended`. The policy is a seeded random choice among
+//! the legal actions, skipping `Undo` so every step moves the game forward.
+//! A generous step cap turns a game that never ends into a failure rather
+//! than a hang.
+
+use mk_data::heroes::all_heroes;
+use mk_types::state::GameState;
+
+use crate::branching::RandomPlayout;
+use crate::setup::{create_solo_game, place_initial_tiles};
+
+const GAME_SEEDS: [u32; 4] = [1, 7, 42, 1234];
+
+/// Upper bound on applied actions before a game counts as stuck.
+const MAX_STEPS: usize = 10_000;
+
+/// Play `state` to the end with the forward-only random policy.
+///
+/// Returns the number of applied actions, or a description of the step at
+/// which the game stalled or an action failed to apply.
+fn play_to_end(state: &mut GameState, action_seed: u32) -> Result<usize, String> {
+    let mut playout = RandomPlayout::new(action_seed).forward_only();
+
+    for step in 0..MAX_STEPS {
+        if state.game_ended {
+            return Ok(step);
+        }
+
+        let Some(choice) = playout.choose(state) else {
+            return Err(format!("step {step}: no player has a legal action (round {})", state.round));
+        };
+        playout.apply(state, &choice).map_err(|err| {
+            format!("step {step}: player {} {:?} failed: {err:?}", choice.player_idx, choice.action)
+        })?;
+    }
+
+    Err(format!("no game end within {MAX_STEPS} steps (round {})", state.round))
+}
+
+#[test]
+fn every_hero_plays_solo_games_to_final_scores() {
+    for (hero_idx, hero) in all_heroes().iter().map(|info| info.hero).enumerate() {
+        for &game_seed in &GAME_SEEDS {
+            let action_seed = game_seed.wrapping_mul(31).wrapping_add(hero_idx as u32);
+            let mut state = create_solo_game(game_seed, hero);
+            place_initial_tiles(&mut state);
+
+            if let Err(report) = play_to_end(&mut state, action_seed) {
+                panic!("{hero:?} game_seed={game_seed} action_seed={action_seed}: {report}");
+            }
+
+            let scores = state
+                .final_score_result
+                .as_ref()
+                .unwrap_or_else(|| panic!("{hero:?} game_seed={game_seed}: game ended without final scores"));
+            assert_eq!(scores.player_results.len(), 1, "{hero:?} game_seed={game_seed}");
+            assert_eq!(scores.rankings, vec![state.players[0].id.to_string()], "{hero:?} game_seed={game_seed}");
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Fuzz tests — drive full games with random legal actions, catching panics.
+//!
+//! Each case picks a hero (or hero pair), a game seed and an action seed, then
+//! repeatedly enumerates and applies a random legal action for whichever player
+//! has actions. Any panic or `ApplyError` fails the case with the seeds and the
+//! action log needed to replay it.
+//!
+//! Run more cases locally with `PROPTEST_CASES=2000 cargo test -p mk-engine fuzz_`.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use proptest::prelude::*;
+
+use mk_data::heroes::all_heroes;
+use mk_types::legal_action::LegalAction;
+use mk_types::state::GameState;
+
+use crate::branching::RandomPlayout;
+use crate::setup::{create_solo_game, create_two_player_game, place_initial_tiles};
+
+/// Upper bound on applied actions per game.
+const MAX_STEPS: usize = 400;
+
+/// One applied action: `(step, player_idx, action_index, action)`.
+type LogEntry = (usize, usize, usize, LegalAction);
+
+/// Play random legal actions until the game ends, no player can act, or
+/// `MAX_STEPS` is reached. Returns a reproduction report on the first failure.
+fn random_playout(mut state: GameState, action_seed: u32) -> Result<(), String> {
+    let mut playout = RandomPlayout::new(action_seed);
+    let mut log: Vec<LogEntry> = Vec::new();
+
+    for step in 0..MAX_STEPS {
+        if state.game_ended {
+            break;
+        }
+
+        let chosen = catch_unwind(AssertUnwindSafe(|| playout.choose(&state)));
+        let Some(choice) = chosen
+            .map_err(|panic| failure_report(step, "enumeration panicked", &panic_message(&panic), &log))?
+        else {
+            break;
+        };
+        log.push((step, choice.player_idx, choice.action_index, choice.action.clone()));
+
+        let result = catch_unwind(AssertUnwindSafe(|| playout.apply(&mut state, &choice)));
+        match result {
+            Ok(Ok(_)) => {}
+            Ok(Err(err)) => {
+                return Err(failure_report(step, "apply returned an error", &format!("{err:?}"), &log))
+            }
+            Err(panic) => {
+                return Err(failure_report(step, "apply panicked", &panic_message(&panic), &log))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn panic_message(panic: &Box<dyn std::any::Any + Send>) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "<non-string panic payload>".to_string())
+}
+
+fn failure_report(step: usize, what: &str, detail: &str, log: &[LogEntry]) -> String {
+    let indices: Vec<String> = log.iter().map(|(_, p, i, _)| format!("{p}:{i}")).collect();
+    let recent: Vec<String> = log
+        .iter()
+        .rev()
+        .take(10)
+        .rev()
+        .map(|(s, p, i, a)| format!("  step {s}: player {p} action {i} {a:?}"))
+        .collect();
+    format!(
+        "step {step}: {what}: {detail}\naction log (player:index): [{}]\nlast actions:\n{}",
+        indices.join(", "),
+        recent.join("\n")
+    )
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn fuzz_solo_playout(hero_idx in 0..all_heroes().len(), game_seed in any::<u32>(), action_seed in any::<u32>()) {
+        let hero = all_heroes()[hero_idx].hero;
+        let mut state = create_solo_game(game_seed, hero);
+        place_initial_tiles(&mut state);
+        if let Err(report) = random_playout(state, action_seed) {
+            prop_assert!(false, "{hero:?} game_seed={game_seed} action_seed={action_seed}\n{report}");
+        }
+    }
+
+    #[test]
+    fn fuzz_two_player_playout(
+        hero_idx in 0..all_heroes().len(),
+        other_offset in 1..all_heroes().len(),
+        game_seed in any::<u32>(),
+        action_seed in any::<u32>(),
+    ) {
+        let heroes = all_heroes();
+        let hero1 = heroes[hero_idx].hero;
+        let hero2 = heroes[(hero_idx + other_offset) % heroes.len()].hero;
+        let mut state = create_two_player_game(game_seed, hero1, hero2);
+        place_initial_tiles(&mut state);
+        if let Err(report) = random_playout(state, action_seed) {
+            prop_assert!(
+                false,
+                "{hero1:?}+{hero2:?} game_seed={game_seed} action_seed={action_seed}\n{report}"
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use mk_data::cards::{get_card, CardCategory, PoweredBy};
+use mk_data::cards::{get_card, CardCategory, CardDefinition, PoweredBy};
 use mk_types::effect::CardEffect;
 use mk_types::enums::*;
 use mk_types::ids::CardId;
@@ -6,7 +6,7 @@ use mk_types::legal_action::LegalAction;
 use mk_types::modifier::{ModifierEffect, RuleOverride};
 use mk_types::state::{GameState, PlayerFlags};
 
-use crate::card_play::{get_effective_sideways_value, is_rule_active};
+use crate::card_play::{effective_sideways_value, is_rule_active};
 use crate::combat_resolution;
 use crate::effect_queue::is_resolvable;
 
@@ -73,7 +73,7 @@ pub(super) fn enumerate_normal_cards(
                     || (player.flags.contains(PlayerFlags::HAS_TAKEN_ACTION_THIS_TURN)
                         && is_move_only(&card_def.basic_effect))
                     || is_combat_only(card_def.basic_categories);
-                if !dominated {
+                if !dominated && can_afford_basic(state, player_idx, &card_def) {
                     basic_actions.push(LegalAction::PlayCardBasic {
                         hand_index,
                         card_id: card_id.clone(),
@@ -103,7 +103,7 @@ pub(super) fn enumerate_normal_cards(
                         &card_def.powered_effect,
                         None,
                     )
-                    && can_afford_powered(state, player_idx, color)
+                    && can_afford_single_powered(state, player_idx, &card_def, color)
                 {
                     powered_actions.push(LegalAction::PlayCardPowered {
                         hand_index,
@@ -123,7 +123,7 @@ pub(super) fn enumerate_normal_cards(
                     )
                 {
                     for &color in &ALL_BASIC_MANA_COLORS {
-                        if can_afford_powered(state, player_idx, color) {
+                        if can_afford_powered(state, player_idx, color, false) {
                             powered_actions.push(LegalAction::PlayCardPowered {
                                 hand_index,
                                 card_id: card_id.clone(),
@@ -162,27 +162,7 @@ pub(super) fn enumerate_normal_cards(
         // Normal: both move and influence.
         // Wounds: only if WoundsPlayableSideways rule active + effective value > 0.
         if !is_resting {
-            let eff_value = if is_wound {
-                if is_rule_active(state, player_idx, RuleOverride::WoundsPlayableSideways) {
-                    get_effective_sideways_value(
-                        state,
-                        player_idx,
-                        true,
-                        card_def.card_type,
-                        card_def.powered_by.primary_color(),
-                    )
-                } else {
-                    0
-                }
-            } else {
-                get_effective_sideways_value(
-                    state,
-                    player_idx,
-                    false,
-                    card_def.card_type,
-                    card_def.powered_by.primary_color(),
-                )
-            };
+            let eff_value = effective_sideways_value(state, player_idx, card_id);
             if eff_value > 0 {
                 if has_rested {
                     // Resting should not preserve site interaction context.
@@ -257,6 +237,7 @@ pub(super) fn enumerate_combat_cards(
                     &card_def.basic_effect,
                     Some(combat_phase),
                 )
+                && can_afford_basic(state, player_idx, &card_def)
             {
                 basic_actions.push(LegalAction::PlayCardBasic {
                     hand_index,
@@ -279,7 +260,7 @@ pub(super) fn enumerate_combat_cards(
                                 &card_def.powered_effect,
                                 Some(combat_phase),
                             )
-                            && can_afford_powered(state, player_idx, color)
+                            && can_afford_single_powered(state, player_idx, &card_def, color)
                         {
                             powered_actions.push(LegalAction::PlayCardPowered {
                                 hand_index,
@@ -299,7 +280,7 @@ pub(super) fn enumerate_combat_cards(
                             )
                         {
                             for &color in &ALL_BASIC_MANA_COLORS {
-                                if can_afford_powered(state, player_idx, color) {
+                                if can_afford_powered(state, player_idx, color, false) {
                                     powered_actions.push(LegalAction::PlayCardPowered {
                                         hand_index,
                                         card_id: card_id.clone(),
@@ -334,27 +315,7 @@ pub(super) fn enumerate_combat_cards(
         // Category 4: PlayCardSideways (combat: Attack and Block).
         // Wounds: only if WoundsPlayableSideways rule active + effective value > 0.
         {
-            let eff_value = if is_wound {
-                if is_rule_active(state, player_idx, RuleOverride::WoundsPlayableSideways) {
-                    get_effective_sideways_value(
-                        state,
-                        player_idx,
-                        true,
-                        card_def.card_type,
-                        card_def.powered_by.primary_color(),
-                    )
-                } else {
-                    0
-                }
-            } else {
-                get_effective_sideways_value(
-                    state,
-                    player_idx,
-                    false,
-                    card_def.card_type,
-                    card_def.powered_by.primary_color(),
-                )
-            };
+            let eff_value = effective_sideways_value(state, player_idx, card_id);
             if eff_value > 0 {
                 // Sideways Attack: Attack phase always, RangedSiege only when modifiers
                 // convert melee → useful type (ColdFire or Siege).
@@ -1112,20 +1073,54 @@ fn has_attack_useful_leaf(effect: &CardEffect) -> bool {
     }
 }
 
+/// Basic plays are free except for spells, which cost one mana of their color.
+fn can_afford_basic(state: &GameState, player_idx: usize, card_def: &CardDefinition) -> bool {
+    if card_def.card_type != DeedCardType::Spell {
+        return true;
+    }
+    card_def
+        .powered_by
+        .primary_color()
+        .is_some_and(|color| can_afford_powered(state, player_idx, color, true))
+}
+
+/// Powered play of a single-color card; spells also need black mana at night.
+fn can_afford_single_powered(
+    state: &GameState,
+    player_idx: usize,
+    card_def: &CardDefinition,
+    color: BasicManaColor,
+) -> bool {
+    if card_def.card_type == DeedCardType::Spell {
+        crate::card_play::spell_powered_sources(state, player_idx, color).is_some()
+    } else {
+        can_afford_powered(state, player_idx, color, false)
+    }
+}
+
 /// Check if player can afford to power a card requiring the given color.
 ///
 /// Mirrors `collect_mana_sources` logic but doesn't mutate state.
-fn can_afford_powered(state: &GameState, player_idx: usize, color: BasicManaColor) -> bool {
+fn can_afford_powered(
+    state: &GameState,
+    player_idx: usize,
+    color: BasicManaColor,
+    for_spell: bool,
+) -> bool {
     let player = &state.players[player_idx];
     let target = ManaColor::from(color);
+    let mut tokens = player
+        .pure_mana
+        .iter()
+        .filter(|t| !(for_spell && t.cannot_power_spells));
 
     // 1. Matching-color mana token.
-    if player.pure_mana.iter().any(|t| t.color == target) {
+    if tokens.clone().any(|t| t.color == target) {
         return true;
     }
 
     // 2. Gold mana token (wild).
-    if player.pure_mana.iter().any(|t| t.color == ManaColor::Gold) {
+    if tokens.any(|t| t.color == ManaColor::Gold) {
         return true;
     }
 
@@ -1140,11 +1135,8 @@ fn can_afford_powered(state: &GameState, player_idx: usize, color: BasicManaColo
         return true;
     }
 
-    // 4. Available mana source die (1 per turn limit).
-    if !player
-        .flags
-        .contains(PlayerFlags::USED_MANA_FROM_SOURCE)
-    {
+    // 4. Available mana source die (per-turn limit, see can_use_source).
+    if crate::card_play::can_use_source(state, player_idx) {
         let player_id = &player.id;
         let stolen_die_id = player
             .tactic_state
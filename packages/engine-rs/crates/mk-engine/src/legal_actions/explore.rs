@@ -104,7 +104,7 @@ fn enumerate_explores_open(
         }
     }
 
-    apply_adjacency_filter(state, next_tile_is_core, &candidates, actions);
+    apply_adjacency_filter(state, next_tile_is_core, candidates, actions);
 }
 
 /// Slot-based exploration (Wedge maps): iterate unfilled slots, check physical
@@ -153,7 +153,7 @@ fn enumerate_explores_slotted(
         candidates.push((target_center, adj));
     }
 
-    apply_adjacency_filter(state, next_tile_is_core, &candidates, actions);
+    apply_adjacency_filter(state, next_tile_is_core, candidates, actions);
 }
 
 /// Check if the player is near enough to any hex of a target tile to explore.
@@ -255,11 +255,15 @@ fn has_well_connected_neighbor(
 fn apply_adjacency_filter(
     state: &GameState,
     next_tile_is_core: bool,
-    candidates: &[(HexCoord, std::collections::BTreeSet<TileId>)],
+    mut candidates: Vec<(HexCoord, std::collections::BTreeSet<TileId>)>,
     actions: &mut Vec<LegalAction>,
 ) {
+    // Sort by (q, r) for determinism — candidate order otherwise follows tile
+    // placement order (open maps) or slot key order (slotted maps).
+    candidates.sort_by(|a, b| a.0.q.cmp(&b.0.q).then(a.0.r.cmp(&b.0.r)));
+
     let mut filtered_any = false;
-    for (target, adj_tiles) in candidates {
+    for (target, adj_tiles) in &candidates {
         let passes = if next_tile_is_core {
             adj_tiles.len() >= 2
         } else {
@@ -278,7 +282,7 @@ fn apply_adjacency_filter(
     // the first countryside placement can't satisfy the 2-tile rule.
     // Once 2+ tiles exist, enforce the adjacency rules strictly.
     if !filtered_any && !next_tile_is_core && state.map.tiles.len() == 1 {
-        for (target, _) in candidates {
+        for (target, _) in &candidates {
             actions.push(LegalAction::Explore {
                 target_center: *target,
             });
@@ -9,7 +9,7 @@
 //! 3. PlayCardPowered (by hand_index)
 //! 4. PlayCardSideways (by hand_index, then sideways_as discriminant)
 //! 5. Move (by (target.q, target.r) lexicographic)
-//! 6. Explore (by HexDirection discriminant — NE, E, SE, SW, W, NW)
+//! 6. Explore (by (target_center.q, target_center.r) lexicographic)
 //! 7. ResolveChoice (by choice_index)
 //! 8. EndCombatPhase
 //! 9. EndTurn
@@ -35,7 +35,7 @@ mod utils;
 mod tests;
 
 use mk_types::enums::*;
-use mk_types::legal_action::{LegalAction, LegalActionSet};
+use mk_types::legal_action::{EmptyReason, LegalAction, LegalActionSet};
 use mk_types::state::*;
 
 use crate::undo::UndoStack;
@@ -81,15 +81,25 @@ pub fn enumerate_legal_actions_with_undo(
             epoch,
             player_idx,
             actions,
+            empty_reason: Some(EmptyReason::UnknownPlayer),
         };
     }
 
     // Guard: game phase.
-    if state.phase != GamePhase::Round || state.game_ended {
+    if state.game_ended {
         return LegalActionSet {
             epoch,
             player_idx,
             actions,
+            empty_reason: Some(EmptyReason::GameEnded),
+        };
+    }
+    if state.phase != GamePhase::Round {
+        return LegalActionSet {
+            epoch,
+            player_idx,
+            actions,
+            empty_reason: Some(EmptyReason::WrongPhase),
         };
     }
 
@@ -100,18 +110,22 @@ pub fn enumerate_legal_actions_with_undo(
             epoch,
             player_idx,
             actions: response_actions,
+            empty_reason: None,
         };
     }
 
-    // Out-of-turn interactive skill returns: non-active players can return skills.
+    // Out-of-turn skills: non-active players can return center skills and use
+    // skills usable on any player's turn (Motivation).
     if !is_active_player(state, player_idx) {
         if state.round_phase == RoundPhase::PlayerTurns {
             returnable_skills::enumerate_returnable_skills(state, player_idx, &mut actions);
+            skills::enumerate_out_of_turn_skills(state, player_idx, &mut actions);
             if !actions.is_empty() {
                 return LegalActionSet {
                     epoch,
                     player_idx,
                     actions,
+                    empty_reason: None,
                 };
             }
         }
@@ -119,6 +133,7 @@ pub fn enumerate_legal_actions_with_undo(
             epoch,
             player_idx,
             actions,
+            empty_reason: Some(EmptyReason::NotYourTurn),
         };
     }
 
@@ -129,6 +144,7 @@ pub fn enumerate_legal_actions_with_undo(
             epoch,
             player_idx,
             actions,
+            empty_reason: None,
         };
     }
 
@@ -141,6 +157,7 @@ pub fn enumerate_legal_actions_with_undo(
             epoch,
             player_idx,
             actions,
+            empty_reason: None,
         };
     }
 
@@ -185,6 +202,7 @@ pub fn enumerate_legal_actions_with_undo(
             epoch,
             player_idx,
             actions,
+            empty_reason: None,
         };
     }
 
@@ -215,6 +233,7 @@ pub fn enumerate_legal_actions_with_undo(
             epoch,
             player_idx,
             actions,
+            empty_reason: None,
         };
     }
 
@@ -239,6 +258,7 @@ pub fn enumerate_legal_actions_with_undo(
             epoch,
             player_idx,
             actions,
+            empty_reason: None,
         };
     }
 
@@ -259,6 +279,7 @@ pub fn enumerate_legal_actions_with_undo(
         epoch,
         player_idx,
         actions,
+        empty_reason: None,
     }
 }
 
@@ -209,9 +209,16 @@ pub(super) fn enumerate_pending(
             }
         }
         ActivePending::SelectCombatEnemy { eligible_enemy_ids, .. } => {
-            // One ResolveChoice per eligible enemy.
-            for i in 0..eligible_enemy_ids.len() {
-                actions.push(LegalAction::ResolveChoice { choice_index: i });
+            // One ResolveChoice per eligible enemy that is still in the fight.
+            let pre_filter_len = actions.len();
+            for (i, id) in eligible_enemy_ids.iter().enumerate() {
+                if crate::action_pipeline::is_live_combat_enemy(state, id) {
+                    actions.push(LegalAction::ResolveChoice { choice_index: i });
+                }
+            }
+            // Safety: if every target is gone, let the effect fizzle (don't deadlock).
+            if actions.len() == pre_filter_len {
+                actions.push(LegalAction::ResolveChoice { choice_index: 0 });
             }
         }
         ActivePending::LevelUpReward(reward) => {
@@ -220,11 +227,7 @@ pub(super) fn enumerate_pending(
         ActivePending::CrystalJoyReclaim(ref pending) => {
             let player = &state.players[player_idx];
             for (i, card) in player.discard.iter().enumerate() {
-                let eligible = match pending.version {
-                    mk_types::pending::EffectMode::Basic => card.as_str() != WOUND_CARD_ID,
-                    mk_types::pending::EffectMode::Powered => true,
-                };
-                if eligible {
+                if crate::end_turn::crystal_joy_can_reclaim(pending.version, card) {
                     actions.push(LegalAction::ResolveCrystalJoyReclaim {
                         discard_index: Some(i),
                     });
@@ -347,7 +350,7 @@ pub(super) fn enumerate_pending(
                     if current_count < max_selections {
                         for (i, card_id) in player.discard.iter().enumerate() {
                             if card_id.as_str() != WOUND_CARD_ID
-                                && !med.selected_card_ids.contains(card_id)
+                                && !med.selected_discard_indices.contains(&i)
                             {
                                 actions.push(LegalAction::ResolveMeditation {
                                     selection_index: i,
@@ -525,18 +528,9 @@ fn enumerate_tactic_decision(
 ) {
     match td {
         PendingTacticDecision::ManaSteal => {
-            let current_player_id = &state.players[player_idx].id;
-            for (idx, die) in state.source.dice.iter().enumerate() {
-                if die.is_depleted || !die.color.is_basic() {
-                    continue;
-                }
-                // Include unclaimed dice AND dice claimed by other players (steal)
-                let is_available = die.taken_by_player_id.is_none();
-                let is_stealable = die
-                    .taken_by_player_id
-                    .as_ref()
-                    .is_some_and(|owner| owner != current_player_id);
-                if is_available || is_stealable {
+            // Unclaimed dice AND unspent dice claimed by other players (steal)
+            for idx in 0..state.source.dice.len() {
+                if crate::mana::check_mana_steal_target(state, player_idx, idx).is_ok() {
                     actions.push(LegalAction::ResolveTacticDecision {
                         data: TacticDecisionData::ManaSteal { die_index: idx },
                     });
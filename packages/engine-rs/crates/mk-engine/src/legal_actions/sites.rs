@@ -126,7 +126,7 @@ pub(super) fn enumerate_site_actions(
     }
 
     // === Inhabited site interactions below — blocked by X-space (rep -7) ===
-    if player.reputation == -7 {
+    if reputation_refuses_interaction(player.reputation) {
         return;
     }
 
@@ -317,6 +317,15 @@ pub(super) fn enumerate_site_actions(
 // Reputation influence bonus helpers
 // =============================================================================
 
+/// Whether the player's reputation sits on the track's "X" space (-7).
+///
+/// Inhabited sites refuse all interaction there — no healing, buying or
+/// recruiting — though adventure sites are unaffected. Every other space only
+/// adjusts influence via [`reputation_influence_bonus`].
+pub(crate) fn reputation_refuses_interaction(reputation: i8) -> bool {
+    reputation <= -7
+}
+
 /// Reputation influence bonus table (asymmetric, per rulebook).
 ///
 /// | Rep | Bonus |
@@ -296,6 +296,29 @@ pub(super) fn enumerate_card_drawing_skills(
     }
 }
 
+/// Enumerate skills a non-active player may use on another player's turn.
+/// Motivation is usable on any player's turn; the shared motivation cooldown
+/// still applies, so one use blocks every motivation until round end.
+pub(super) fn enumerate_out_of_turn_skills(
+    state: &GameState,
+    player_idx: usize,
+    actions: &mut Vec<LegalAction>,
+) {
+    if state.players[player_idx].pending.has_active() {
+        return;
+    }
+    // Reuse full skill enumeration, then filter to motivation skills only.
+    let mut all_skills = Vec::new();
+    enumerate_skill_activations(state, player_idx, &mut all_skills);
+    for action in all_skills {
+        if let LegalAction::UseSkill { ref skill_id } = action {
+            if is_motivation_skill(skill_id.as_str()) {
+                actions.push(action);
+            }
+        }
+    }
+}
+
 /// Check if the current game context allows a skill with the given phase restriction.
 fn phase_allows_skill(
     restriction: SkillPhaseRestriction,
@@ -77,6 +77,151 @@ fn march_powered_available_with_green_crystal() {
     );
 }
 
+/// Several green dice are offered as separate sources; take the first one.
+fn resolve_mana_source_choice(state: &mut GameState, undo: &mut UndoStack) {
+    if state.players[0].pending.has_active() {
+        let legal = enumerate_legal_actions_with_undo(state, 0, undo);
+        apply_legal_action(state, undo, 0, &legal.actions[0], legal.epoch).unwrap();
+    }
+    assert!(!state.players[0].pending.has_active());
+}
+
+/// Two marches, no tokens or crystals, and every Source die showing green.
+fn setup_two_marches_green_source() -> (GameState, UndoStack) {
+    let mut state = setup_game(vec!["march", "march"]);
+    for die in state.source.dice.iter_mut() {
+        die.color = ManaColor::Green;
+        die.is_depleted = false;
+        die.taken_by_player_id = None;
+    }
+    let mut undo = UndoStack::new();
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let powered = legal
+        .actions
+        .iter()
+        .find(|a| matches!(a, LegalAction::PlayCardPowered { .. }))
+        .expect("first powered march via a Source die")
+        .clone();
+    apply_legal_action(&mut state, &mut undo, 0, &powered, legal.epoch).unwrap();
+    resolve_mana_source_choice(&mut state, &mut undo);
+    assert!(state.players[0].flags.contains(PlayerFlags::USED_MANA_FROM_SOURCE));
+    assert_eq!(state.players[0].used_die_ids.len(), 1);
+    (state, undo)
+}
+
+#[test]
+fn second_source_powered_play_illegal_without_extra_die() {
+    let (state, undo) = setup_two_marches_green_source();
+    assert!(state.source.dice.iter().any(|d| d.taken_by_player_id.is_none()));
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(
+        !legal.actions.iter().any(|a| matches!(a, LegalAction::PlayCardPowered { .. })),
+        "only one Source die per turn"
+    );
+}
+
+#[test]
+fn extra_source_die_modifier_allows_second_source_powered_play() {
+    use mk_types::ids::ModifierId;
+    use mk_types::modifier::{ActiveModifier, ModifierDuration, ModifierSource, RuleOverride};
+
+    let (mut state, mut undo) = setup_two_marches_green_source();
+    let player_id = state.players[0].id.clone();
+    state.active_modifiers.push(ActiveModifier {
+        id: ModifierId::from("test_extra_die"),
+        source: ModifierSource::Card {
+            card_id: CardId::from("mana_storm"),
+            player_id: player_id.clone(),
+        },
+        duration: ModifierDuration::Turn,
+        scope: ModifierScope::SelfScope,
+        effect: ModifierEffect::RuleOverride {
+            rule: RuleOverride::ExtraSourceDie,
+        },
+        created_at_round: 1,
+        created_by_player_id: player_id,
+    });
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let powered = legal
+        .actions
+        .iter()
+        .find(|a| matches!(a, LegalAction::PlayCardPowered { .. }))
+        .expect("extra Source die allows a second powered play")
+        .clone();
+    apply_legal_action(&mut state, &mut undo, 0, &powered, legal.epoch).unwrap();
+    resolve_mana_source_choice(&mut state, &mut undo);
+    assert_eq!(state.players[0].used_die_ids.len(), 2);
+
+    // The single extra die is now spent as well.
+    assert!(!crate::card_play::can_use_source(&state, 0));
+}
+
+#[test]
+fn blue_spell_needs_blue_for_basic_and_black_at_night_for_powered() {
+    let offered = |time: TimeOfDay, tokens: &[ManaColor]| {
+        let (mut state, _undo) = setup_card_combat("chill", &["prowlers"]);
+        state.source.dice.clear(); // control mana sources explicitly
+        state.time_of_day = time;
+        for &color in tokens {
+            state.players[0].pure_mana.push(ManaToken {
+                color,
+                source: ManaTokenSource::Effect,
+                cannot_power_spells: false,
+            });
+        }
+        let legal = enumerate_legal_actions(&state, 0);
+        let basic = legal.actions.iter().any(|a| matches!(a, LegalAction::PlayCardBasic { .. }));
+        let powered = legal.actions.iter().any(|a| matches!(a, LegalAction::PlayCardPowered { .. }));
+        (basic, powered)
+    };
+
+    assert_eq!(offered(TimeOfDay::Day, &[]), (false, false));
+    assert_eq!(offered(TimeOfDay::Day, &[ManaColor::Blue]), (true, false));
+    assert_eq!(offered(TimeOfDay::Day, &[ManaColor::Blue, ManaColor::Black]), (true, false));
+    assert_eq!(offered(TimeOfDay::Night, &[ManaColor::Blue]), (true, false));
+    assert_eq!(offered(TimeOfDay::Night, &[ManaColor::Black]), (false, false));
+    assert_eq!(offered(TimeOfDay::Night, &[ManaColor::Red, ManaColor::Black]), (false, false));
+    assert_eq!(offered(TimeOfDay::Night, &[ManaColor::Blue, ManaColor::Black]), (true, true));
+}
+
+#[test]
+fn powered_spell_spends_black_and_spell_color() {
+    let (mut state, mut undo) = setup_card_combat("chill", &["prowlers"]);
+    state.source.dice.clear();
+    state.players[0].crystals.blue = 1;
+    add_night_black_mana(&mut state);
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let action = legal.actions.iter().find(|a| matches!(a, LegalAction::PlayCardPowered { .. }))
+        .expect("chill powered with black token + blue crystal at night");
+    apply_legal_action(&mut state, &mut undo, 0, action, legal.epoch).unwrap();
+
+    assert!(state.players[0].pure_mana.is_empty(), "black token spent");
+    assert_eq!(state.players[0].crystals.blue, 0, "blue crystal spent");
+}
+
+#[test]
+fn black_die_and_color_die_cannot_both_be_taken() {
+    use mk_types::ids::SourceDieId;
+
+    let (mut state, _undo) = setup_card_combat("chill", &["prowlers"]);
+    state.time_of_day = TimeOfDay::Night;
+    state.source.dice = vec![
+        SourceDie { id: SourceDieId::from("d0"), color: ManaColor::Black, is_depleted: false, taken_by_player_id: None },
+        SourceDie { id: SourceDieId::from("d1"), color: ManaColor::Blue, is_depleted: false, taken_by_player_id: None },
+    ];
+    let powered = |state: &GameState| {
+        enumerate_legal_actions(state, 0).actions.iter()
+            .any(|a| matches!(a, LegalAction::PlayCardPowered { .. }))
+    };
+
+    assert!(!powered(&state), "one Source die per turn");
+    state.players[0].crystals.blue = 1;
+    assert!(powered(&state), "black die + blue crystal");
+}
+
 #[test]
 fn rage_outside_combat_only_sideways() {
     let state = setup_game(vec!["rage"]);
@@ -179,6 +324,63 @@ fn no_sideways_when_resting() {
     assert!(!march_basic, "march basic (Move 2) should be pruned while resting");
 }
 
+#[test]
+fn sideways_options_follow_phase_for_every_non_wound_card() {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    let base = setup_game(vec![]);
+    let mut hand: Vec<CardId> = base.players[0].deck.iter().chain(&base.players[0].hand).cloned().collect();
+    hand.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    hand.dedup();
+    hand.push(CardId::from("wound"));
+
+    let sideways_by_card = |state: &GameState| {
+        let legal = enumerate_legal_actions(state, 0);
+        let mut by_card: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for action in &legal.actions {
+            if let LegalAction::PlayCardSideways { card_id, sideways_as, .. } = action {
+                by_card
+                    .entry(card_id.as_str().to_string())
+                    .or_default()
+                    .insert(format!("{sideways_as:?}"));
+            }
+        }
+        by_card
+    };
+    let assert_only = |state: &GameState, expected: &str, context: &str| {
+        let by_card = sideways_by_card(state);
+        assert!(!by_card.contains_key("wound"), "{context}: wound offered sideways");
+        for card in hand.iter().filter(|c| c.as_str() != "wound") {
+            let options = by_card.get(card.as_str()).cloned().unwrap_or_default();
+            assert_eq!(
+                options,
+                BTreeSet::from([expected.to_string()]),
+                "{context}: {} sideways options",
+                card.as_str()
+            );
+        }
+    };
+
+    let mut state = setup_game(vec![]);
+    state.players[0].hand = hand.clone();
+    state.source.dice.clear();
+    assert_only(&state, "Move", "out of combat");
+
+    state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+    assert_only(&state, "Influence", "interacting");
+
+    let mut state = setup_combat_game(&["prowlers"]);
+    state.players[0].hand = hand.clone();
+    state.combat.as_mut().unwrap().phase = CombatPhase::Block;
+    assert_only(&state, "Block", "block phase");
+
+    state.combat.as_mut().unwrap().phase = CombatPhase::Attack;
+    assert_only(&state, "Attack", "attack phase");
+
+    state.combat.as_mut().unwrap().phase = CombatPhase::RangedSiege;
+    assert!(sideways_by_card(&state).is_empty(), "no sideways in ranged/siege without modifiers");
+}
+
 #[test]
 fn concentration_powered_filtered_when_only_discard_target_unpayable() {
     let mut state = setup_game(vec!["concentration", "improvisation"]);
@@ -285,6 +487,7 @@ fn whirlwind_powered_defeats_single_enemy() {
         source: ManaTokenSource::Effect,
         cannot_power_spells: false,
     });
+    add_night_black_mana(&mut state);
     state.combat.as_mut().unwrap().phase = CombatPhase::Attack;
 
     let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
@@ -336,6 +539,7 @@ fn whirlwind_powered_playable_in_attack_phase() {
         source: ManaTokenSource::Effect,
         cannot_power_spells: false,
     });
+    add_night_black_mana(&mut state);
     state.combat.as_mut().unwrap().phase = CombatPhase::Attack;
 
     let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
@@ -505,6 +709,7 @@ fn chill_powered_reduces_armor() {
         source: ManaTokenSource::Effect,
         cannot_power_spells: false,
     });
+    add_night_black_mana(&mut state);
 
     let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
     let action = legal.actions.iter().find(|a| matches!(a,
@@ -524,6 +729,7 @@ fn chill_powered_reduces_armor() {
 fn tremor_basic_choice_single_enemy_or_all() {
     // Tremor basic: Choice between SelectCombatEnemy(-3) and AllEnemies(-2).
     let (mut state, mut undo) = setup_card_combat("tremor", &["prowlers"]);
+    state.source.dice.clear(); // control mana sources explicitly
     state.players[0].pure_mana.push(ManaToken {
         color: ManaColor::Red,
         source: ManaTokenSource::Effect,
@@ -721,6 +927,7 @@ fn expose_powered_all_enemies_fortification_nullified() {
         source: ManaTokenSource::Effect,
         cannot_power_spells: false,
     });
+    add_night_black_mana(&mut state);
 
     let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
     let action = legal.actions.iter().find(|a| matches!(a,
@@ -975,6 +1182,7 @@ fn disease_powered_sets_armor_to_1_for_blocked_enemies() {
         source: ManaTokenSource::Effect,
         cannot_power_spells: false,
     });
+    add_night_black_mana(&mut state);
     state.combat.as_mut().unwrap().enemies[0].is_blocked = true;
     state.combat.as_mut().unwrap().phase = CombatPhase::Block;
 
@@ -1004,6 +1212,7 @@ fn disease_skipped_when_no_enemies_blocked() {
         source: ManaTokenSource::Effect,
         cannot_power_spells: false,
     });
+    add_night_black_mana(&mut state);
 
     let undo = UndoStack::new();
     let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
@@ -1045,6 +1254,7 @@ fn disease_affects_multiple_blocked_enemies() {
         source: ManaTokenSource::Effect,
         cannot_power_spells: false,
     });
+    add_night_black_mana(&mut state);
 
     let combat = state.combat.as_mut().unwrap();
     combat.enemies[0].is_blocked = true;
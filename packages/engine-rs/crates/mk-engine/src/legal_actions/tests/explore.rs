@@ -55,7 +55,7 @@ fn explore_wedge_targets_valid() {
         .collect();
 
     // Valid wedge targets from origin: NE=(1,-3), E=(3,-2)
-    let valid_targets = vec![HexCoord::new(1, -3), HexCoord::new(3, -2)];
+    let valid_targets = [HexCoord::new(1, -3), HexCoord::new(3, -2)];
     for target in &explore_targets {
         assert!(
             valid_targets.contains(target),
@@ -474,3 +474,71 @@ fn explore_from_adjacent_tile_edge() {
          both countryside_1 and countryside_2"
     );
 }
+
+// =========================================================================
+// Enumeration order
+// =========================================================================
+
+/// Build an open map (no tile slots) from `tiles`, placed in the given order.
+fn open_map_state(tiles: &[(TileId, HexCoord)], position: HexCoord) -> GameState {
+    let mut state = setup_game(vec!["march"]);
+    state.players[0].move_points = 10;
+    state.scenario_config.map_shape = mk_types::enums::MapShape::Open;
+    state.map.hexes.clear();
+    state.map.tiles.clear();
+    state.map.tile_slots.clear();
+    for (tile_id, center) in tiles {
+        let tile_hexes = mk_data::tiles::get_tile_hexes(*tile_id).unwrap();
+        crate::movement::place_tile_on_map(&mut state.map, *tile_id, *center, tile_hexes);
+        state.map.tiles.push(mk_types::state::TilePlacement {
+            tile_id: *tile_id,
+            center_coord: *center,
+            revealed: true,
+        });
+    }
+    state.map.tile_deck.countryside = vec![TileId::Countryside3, TileId::Countryside4];
+    state.players[0].position = Some(position);
+    state
+}
+
+/// Move and Explore actions, in enumeration order.
+fn move_and_explore_actions(state: &GameState) -> Vec<LegalAction> {
+    enumerate_legal_actions(state, 0)
+        .actions
+        .into_iter()
+        .filter(|a| matches!(a, LegalAction::Move { .. } | LegalAction::Explore { .. }))
+        .collect()
+}
+
+#[test]
+fn move_and_explore_order_independent_of_tile_placement_order() {
+    let tiles = [
+        (TileId::StartingA, HexCoord::new(0, 0)),
+        (TileId::Countryside1, HexCoord::new(1, -3)),
+        (TileId::Countryside2, HexCoord::new(3, -2)),
+    ];
+    // Player on the far edge of countryside_1, in reach of two targets.
+    let position = HexCoord::new(2, -4);
+
+    let forward = move_and_explore_actions(&open_map_state(&tiles, position));
+    let explores: Vec<(i32, i32)> = forward
+        .iter()
+        .filter_map(|a| match a {
+            LegalAction::Explore { target_center } => Some((target_center.q, target_center.r)),
+            _ => None,
+        })
+        .collect();
+    assert!(explores.len() >= 2, "expected several explore targets, got {explores:?}");
+    let mut sorted = explores.clone();
+    sorted.sort();
+    assert_eq!(explores, sorted, "explore targets should be sorted by (q, r)");
+
+    // Every permutation of tile placement yields the identical action order.
+    let orders = [[0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+    for order in orders {
+        let permuted: Vec<_> = order.iter().map(|&i| tiles[i]).collect();
+        let actions = move_and_explore_actions(&open_map_state(&permuted, position));
+        assert_eq!(actions, forward, "order changed for tile order {order:?}");
+    }
+}
+
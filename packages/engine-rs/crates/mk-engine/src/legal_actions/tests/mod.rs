@@ -7,6 +7,7 @@ use mk_types::enums::ResistanceElement;
 use mk_types::hex::HexCoord;
 use mk_types::ids::{CardId, EnemyTokenId, PlayerId, UnitId, UnitInstanceId};
 use mk_types::modifier::{EnemyStat as ModEnemyStat, ModifierEffect, ModifierScope};
+use mk_types::legal_action::EmptyReason;
 use mk_types::pending::ActivePending;
 use mk_types::state::Site;
 
@@ -173,12 +174,8 @@ pub(super) fn setup_damage_assignment_combat(
     combat.phase = CombatPhase::AssignDamage;
     // Mark all attacks as NOT blocked and NOT assigned
     for enemy in &mut combat.enemies {
-        for blocked in &mut enemy.attacks_blocked {
-            *blocked = false;
-        }
-        for assigned in &mut enemy.attacks_damage_assigned {
-            *assigned = false;
-        }
+        enemy.attacks_blocked.fill(false);
+        enemy.attacks_damage_assigned.fill(false);
     }
 
     let undo = UndoStack::new();
@@ -259,6 +256,17 @@ pub(super) fn setup_altem_mages_combat(phase: CombatPhase) -> (GameState, UndoSt
     (state, undo)
 }
 
+/// Helper: make a spell's powered effect payable — switch to night and add a
+/// black mana token. The spell's own color must still be supplied separately.
+pub(super) fn add_night_black_mana(state: &mut GameState) {
+    state.time_of_day = TimeOfDay::Night;
+    state.players[0].pure_mana.push(ManaToken {
+        color: ManaColor::Black,
+        source: ManaTokenSource::Effect,
+        cannot_power_spells: false,
+    });
+}
+
 /// Helper: set up a combat game with given enemies (from combat.rs inline tests).
 pub(super) fn setup_combat_game(enemy_ids: &[&str]) -> GameState {
     let mut state = create_solo_game(42, Hero::Arythea);
@@ -47,6 +47,7 @@ fn pending_meditation_select_cards_enumeration() {
             version: mk_types::pending::EffectMode::Powered,
             phase: mk_types::pending::MeditationPhase::SelectCards,
             selected_card_ids: vec![],
+            selected_discard_indices: vec![],
         },
     ));
     let legal = enumerate_legal_actions(&state, 0);
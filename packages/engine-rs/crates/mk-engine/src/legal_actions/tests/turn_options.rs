@@ -256,6 +256,43 @@ fn move_blocked_while_resting() {
     assert!(!has_move, "Move should be blocked while resting");
 }
 
+#[test]
+fn completed_rest_blocks_movement_and_site_actions_even_with_move_points() {
+    let mut state = setup_village_recruit();
+    state.players[0].hand = vec![CardId::from("march"), CardId::from("rage")];
+    let mut undo = UndoStack::new();
+
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::DeclareRest, epoch).unwrap();
+    let epoch = state.action_epoch;
+    apply_legal_action(
+        &mut state,
+        &mut undo,
+        0,
+        &LegalAction::CompleteRest { discard_hand_index: Some(0) },
+        epoch,
+    )
+    .unwrap();
+    // Move points gained after resting (e.g. from a unit) still cannot be spent.
+    state.players[0].move_points = 5;
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert!(
+        !legal.actions.iter().any(|a| matches!(
+            a,
+            LegalAction::Move { .. }
+                | LegalAction::Explore { .. }
+                | LegalAction::ChallengeRampaging { .. }
+                | LegalAction::BeginInteraction
+                | LegalAction::RecruitUnit { .. }
+                | LegalAction::DeclareRest
+        )),
+        "resting is the turn's action: {:?}",
+        legal.actions
+    );
+    assert!(legal.actions.contains(&LegalAction::EndTurn));
+}
+
 #[test]
 fn no_sideways_after_rest_without_site_interaction() {
     // After HAS_RESTED_THIS_TURN but NOT interacting: no sideways at all.
@@ -149,6 +149,109 @@ fn no_recruit_at_burned_village() {
     assert!(recruits.is_empty(), "no recruitment at burned village");
 }
 
+#[test]
+fn recruit_affordability_follows_reputation_track() {
+    // Peasants cost 4. With 3 influence, +5 reputation (+2) makes them
+    // affordable while -3 reputation (-1) does not.
+    let recruitable_at = |reputation: i8| {
+        let mut state = setup_village_recruit();
+        state.players[0].influence_points = 3;
+        state.players[0].reputation = reputation;
+        state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+        let effective = crate::legal_actions::sites::compute_effective_influence(&state, 0);
+        let legal = enumerate_legal_actions(&state, 0);
+        let peasants = legal.actions.iter().any(|a| matches!(a,
+            LegalAction::RecruitUnit { unit_id, .. } if unit_id.as_str() == "peasants"
+        ));
+        (effective, peasants)
+    };
+
+    assert_eq!(recruitable_at(5), (5, true), "+5 reputation grants +2 influence");
+    assert_eq!(recruitable_at(-3), (2, false), "-3 reputation costs 1 influence");
+}
+
+#[test]
+fn unaffordable_recruit_appears_after_playing_influence_card() {
+    // Peasants cost 4: 2 influence is not enough until Promise adds 2 more.
+    let mut state = setup_village_recruit();
+    let mut undo = UndoStack::new();
+    state.players[0].hand = vec![CardId::from("promise")];
+    state.players[0].influence_points = 2;
+    state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+    let peasant_recruit = |legal: &LegalActionSet| {
+        legal.actions.iter().find_map(|a| match a {
+            LegalAction::RecruitUnit { unit_id, influence_cost, .. } if unit_id.as_str() == "peasants" => {
+                Some(*influence_cost)
+            }
+            _ => None,
+        })
+    };
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert_eq!(peasant_recruit(&legal), None, "peasants unaffordable with 2 influence");
+
+    let play = legal.actions.iter().find(|a| matches!(a, LegalAction::PlayCardBasic { .. }))
+        .expect("promise basic should be playable while interacting")
+        .clone();
+    apply_legal_action(&mut state, &mut undo, 0, &play, legal.epoch).unwrap();
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    assert_eq!(peasant_recruit(&legal), Some(4));
+}
+
+#[test]
+fn recruit_at_high_reputation_spends_bonus_influence() {
+    let mut state = setup_village_recruit();
+    let mut undo = UndoStack::new();
+    state.players[0].influence_points = 3;
+    state.players[0].reputation = 5;
+    state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let action = legal.actions.iter().find(|a| matches!(a,
+        LegalAction::RecruitUnit { unit_id, .. } if unit_id.as_str() == "peasants"
+    )).expect("peasants should be recruitable at +5 reputation");
+    apply_legal_action(&mut state, &mut undo, 0, action, legal.epoch).unwrap();
+
+    assert_eq!(state.players[0].units.len(), 1);
+    assert_eq!(
+        state.players[0].influence_points, 1,
+        "3 influence + 2 reputation bonus - 4 cost"
+    );
+}
+
+#[test]
+fn no_recruit_at_x_reputation() {
+    let mut state = setup_village_recruit();
+    state.players[0].reputation = -7;
+    state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+
+    let legal = enumerate_legal_actions(&state, 0);
+    assert!(
+        !legal.actions.iter().any(|a| matches!(a, LegalAction::RecruitUnit { .. })),
+        "reputation on the X space blocks all interaction"
+    );
+}
+
+#[test]
+fn only_x_reputation_refuses_recruitment() {
+    // Low reputation makes units dearer; only the X space refuses outright.
+    let recruits_at = |reputation: i8| {
+        let mut state = setup_village_recruit();
+        state.players[0].reputation = reputation;
+        state.players[0].flags.insert(PlayerFlags::IS_INTERACTING);
+        let legal = enumerate_legal_actions(&state, 0);
+        legal.actions.iter().filter(|a| matches!(a, LegalAction::RecruitUnit { .. })).count()
+    };
+
+    assert!(recruits_at(-2) > 0, "-2 reputation still recruits");
+    assert!(recruits_at(-5) > 0, "-5 reputation only costs influence");
+    assert!(recruits_at(-6) > 0, "-6 reputation only costs influence");
+    assert_eq!(recruits_at(-7), 0, "X space refuses recruitment");
+    assert!(!crate::legal_actions::sites::reputation_refuses_interaction(-6));
+    assert!(crate::legal_actions::sites::reputation_refuses_interaction(-7));
+}
+
 // =========================================================================
 // Unit Activation
 // =========================================================================
@@ -928,6 +1031,54 @@ fn herbalist_ready_unit_not_enumerated_when_no_eligible() {
     assert!(ready_action.is_none(), "ReadyUnit should not be enumerated when no eligible units");
 }
 
+#[test]
+fn herbalist_ready_unit_offers_only_units_within_max_level() {
+    let (mut state, mut undo) = setup_complex_unit("herbalist", "unit_herb");
+    for (iid, uid, level) in [
+        ("unit_peas", "peasants", 1),
+        ("unit_shock", "shocktroops", 2),
+        ("unit_fm", "fire_mages", 3),
+    ] {
+        state.players[0].units.push(mk_types::state::PlayerUnit {
+            instance_id: mk_types::ids::UnitInstanceId::from(iid),
+            unit_id: mk_types::ids::UnitId::from(uid),
+            level,
+            state: UnitState::Spent,
+            wounded: false,
+            used_resistance_this_combat: false,
+            used_ability_indices: Vec::new(),
+            mana_token: None,
+        });
+    }
+
+    let legal = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let action = legal.actions.iter().find(|a| matches!(a,
+        LegalAction::ActivateUnit { unit_instance_id, ability_index: 1, .. }
+        if unit_instance_id.as_str() == "unit_herb"
+    )).expect("ReadyUnit should be available");
+    let _ = apply_legal_action(&mut state, &mut undo, 0, action, legal.epoch);
+
+    // Peasants (index 1) and Shocktroops (index 2) are eligible; Fire Mages (level 3) is not
+    match &state.players[0].pending.active {
+        Some(ActivePending::Choice(choice)) => {
+            assert!(matches!(&choice.resolution,
+                mk_types::pending::ChoiceResolution::ReadyUnitTarget { eligible_unit_indices }
+                if *eligible_unit_indices == vec![1, 2]));
+            assert_eq!(choice.options.len(), 2);
+        }
+        other => panic!("expected ReadyUnitTarget choice, got {:?}", other),
+    }
+
+    let legal2 = enumerate_legal_actions_with_undo(&state, 0, &undo);
+    let resolve = legal2.actions.iter().find(|a| matches!(a, LegalAction::ResolveChoice { choice_index: 1 })).unwrap();
+    let _ = apply_legal_action(&mut state, &mut undo, 0, resolve, legal2.epoch);
+
+    let unit_state = |iid: &str| state.players[0].units.iter().find(|u| u.instance_id.as_str() == iid).unwrap().state;
+    assert_eq!(unit_state("unit_shock"), UnitState::Ready);
+    assert_eq!(unit_state("unit_peas"), UnitState::Spent);
+    assert_eq!(unit_state("unit_fm"), UnitState::Spent);
+}
+
 #[test]
 fn illusionists_gain_white_crystal() {
     let (mut state, mut undo) = setup_complex_unit("illusionists", "unit_ill");
@@ -1394,6 +1545,41 @@ fn illusionists_cancel_attack_multi_enemy_creates_pending() {
     ));
 }
 
+#[test]
+fn select_enemy_pending_skips_defeated_targets_and_fizzles_when_none_remain() {
+    let (mut state, mut undo) = setup_select_enemy_combat("illusionists", "unit_ill", &["prowlers", "orc_tracker"]);
+    state.combat.as_mut().unwrap().phase = CombatPhase::Block;
+    state.players[0].pending.active = Some(ActivePending::SelectCombatEnemy {
+        unit_instance_id: None,
+        eligible_enemy_ids: vec!["enemy_0".into(), "enemy_1".into()],
+        template: mk_types::pending::SelectEnemyTemplate {
+            skip_attack: true,
+            ..mk_types::pending::SelectEnemyTemplate::new()
+        },
+        continuation: Vec::new(),
+    });
+    let resolve_choices = |state: &GameState, undo: &UndoStack| -> Vec<usize> {
+        enumerate_legal_actions_with_undo(state, 0, undo).actions.iter().filter_map(|a| match a {
+            LegalAction::ResolveChoice { choice_index } => Some(*choice_index),
+            _ => None,
+        }).collect()
+    };
+
+    // Only the surviving target is offered
+    state.combat.as_mut().unwrap().enemies[0].is_defeated = true;
+    assert_eq!(resolve_choices(&state, &undo), vec![1]);
+
+    // With every target gone the pending still resolves, as a no-op
+    state.combat.as_mut().unwrap().enemies[1].is_defeated = true;
+    assert_eq!(resolve_choices(&state, &undo), vec![0]);
+    let epoch = state.action_epoch;
+    apply_legal_action(&mut state, &mut undo, 0, &LegalAction::ResolveChoice { choice_index: 0 }, epoch).unwrap();
+    assert!(!state.players[0].pending.has_active());
+    assert!(!state.active_modifiers.iter().any(|m|
+        matches!(m.effect, mk_types::modifier::ModifierEffect::EnemySkipAttack)
+    ));
+}
+
 #[test]
 fn shocktroops_weaken_applies_armor_and_attack_modifiers() {
     let (mut state, mut undo) = setup_select_enemy_combat("shocktroops", "unit_st", &["prowlers"]);
@@ -2885,7 +3071,7 @@ fn scouts_fame_bonus_modifier_matches_correct_enemy() {
     use mk_types::modifier::*;
     let pid = PlayerId::from("p0");
 
-    let modifiers = vec![ActiveModifier {
+    let modifiers = [ActiveModifier {
         id: mk_types::ids::ModifierId::from("scout_bonus_1"),
         source: ModifierSource::Unit { unit_index: 0, player_id: pid.clone() },
         duration: ModifierDuration::Turn,
@@ -2899,7 +3085,7 @@ fn scouts_fame_bonus_modifier_matches_correct_enemy() {
     }];
 
     // Matching enemy: should find the modifier
-    let defeated_matching = vec!["prowlers".to_string()];
+    let defeated_matching = ["prowlers".to_string()];
     let bonus_match = modifiers.iter().find(|m| {
         if let ModifierEffect::ScoutFameBonus { revealed_enemy_ids, .. } = &m.effect {
             defeated_matching.iter().any(|eid| revealed_enemy_ids.contains(eid))
@@ -2916,9 +3102,9 @@ fn scouts_fame_bonus_not_on_non_matching_enemy() {
     use mk_types::modifier::*;
     let pid = PlayerId::from("p0");
 
-    let defeated_enemy_ids = vec!["orc_swordsmen".to_string()];
+    let defeated_enemy_ids = ["orc_swordsmen".to_string()];
 
-    let modifiers = vec![ActiveModifier {
+    let modifiers = [ActiveModifier {
         id: mk_types::ids::ModifierId::from("scout_bonus_1"),
         source: ModifierSource::Unit { unit_index: 0, player_id: pid.clone() },
         duration: ModifierDuration::Turn,
@@ -30,6 +30,7 @@ fn wrong_player_empty() {
     let state = create_solo_game(42, Hero::Arythea);
     let legal = enumerate_legal_actions(&state, 1);
     assert!(legal.actions.is_empty());
+    assert_eq!(legal.empty_reason, Some(EmptyReason::UnknownPlayer));
 }
 
 #[test]
@@ -38,6 +39,33 @@ fn game_ended_empty() {
     state.game_ended = true;
     let legal = enumerate_legal_actions(&state, 0);
     assert!(legal.actions.is_empty());
+    assert_eq!(legal.empty_reason, Some(EmptyReason::GameEnded));
+}
+
+#[test]
+fn non_active_player_reports_not_your_turn() {
+    let mut state = crate::setup::create_two_player_game(42, Hero::Arythea, Hero::Tovak);
+    state.round_phase = RoundPhase::PlayerTurns;
+    state.current_player_index = 0;
+    let active = state.players.iter().position(|p| p.id == state.turn_order[0]).unwrap();
+    let waiting = 1 - active;
+
+    let legal = enumerate_legal_actions(&state, waiting);
+    assert!(legal.actions.is_empty());
+    assert_eq!(legal.empty_reason, Some(EmptyReason::NotYourTurn));
+
+    let legal = enumerate_legal_actions(&state, active);
+    assert!(!legal.actions.is_empty());
+    assert_eq!(legal.empty_reason, None);
+}
+
+#[test]
+fn non_round_phase_reports_wrong_phase() {
+    let mut state = setup_game(vec!["march"]);
+    state.phase = GamePhase::End;
+    let legal = enumerate_legal_actions(&state, 0);
+    assert!(legal.actions.is_empty());
+    assert_eq!(legal.empty_reason, Some(EmptyReason::WrongPhase));
 }
 
 // =========================================================================
@@ -325,6 +353,32 @@ fn contract_random_walk_different_seed() {
     }
 }
 
+#[test]
+fn contract_random_walk_all_heroes_many_seeds() {
+    for hero in mk_data::heroes::all_heroes().iter().map(|info| info.hero) {
+        for seed in 0..8u32 {
+            let mut state = create_solo_game(seed, hero);
+            let mut playout = crate::branching::RandomPlayout::new(1000 + seed);
+
+            for step in 0..300 {
+                let Some(choice) = playout.choose(&state) else {
+                    break;
+                };
+                let result = playout.apply(&mut state, &choice);
+                assert!(
+                    result.is_ok(),
+                    "{hero:?} seed {seed} step {step}: action {:?} failed: {:?}",
+                    choice.action,
+                    result.unwrap_err()
+                );
+                if result.unwrap().game_ended {
+                    break;
+                }
+            }
+        }
+    }
+}
+
 // =========================================================================
 // Scenario flow
 // =========================================================================
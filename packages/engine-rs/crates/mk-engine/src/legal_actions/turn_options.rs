@@ -71,12 +71,20 @@ pub(super) fn enumerate_turn_options(
     }
 
     // AnnounceEndOfRound — multiplayer only, once per round, during normal turn, deck must be empty.
+    // Announcing replaces the turn, so it's only offered before the player has acted.
     if !is_resting
         && !player.pending.has_active()
         && state.players.len() > 1
         && state.end_of_round_announced_by.is_none()
         && state.combat.is_none()
         && player.deck.is_empty()
+        && !player
+            .flags
+            .contains(PlayerFlags::PLAYED_CARD_FROM_HAND_THIS_TURN)
+        && !player
+            .flags
+            .contains(PlayerFlags::HAS_TAKEN_ACTION_THIS_TURN)
+        && !player.flags.contains(PlayerFlags::HAS_MOVED_THIS_TURN)
     {
         actions.push(LegalAction::AnnounceEndOfRound);
     }
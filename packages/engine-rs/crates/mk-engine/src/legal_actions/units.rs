@@ -125,7 +125,7 @@ pub(super) fn enumerate_unit_actions(
     }
 
     // Guard: X-space (rep -7) blocks all inhabited site interaction including recruitment
-    if player.reputation == -7 {
+    if super::sites::reputation_refuses_interaction(player.reputation) {
         return;
     }
 
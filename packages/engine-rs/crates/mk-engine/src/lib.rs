@@ -3,6 +3,7 @@
 //! All mutable game logic lives here. No Python dependency.
 
 pub mod action_pipeline;
+pub mod branching;
 pub mod card_play;
 pub mod client_state;
 pub mod combat;
@@ -16,10 +17,15 @@ pub mod end_turn;
 pub mod legal_actions;
 pub mod mana;
 pub mod movement;
+pub mod offers;
 pub mod scoring;
 pub mod setup;
 pub mod undo;
 pub mod valid_actions;
 
+#[cfg(test)]
+mod full_game_tests;
+#[cfg(test)]
+mod fuzz_tests;
 #[cfg(test)]
 mod parity_tests;
@@ -65,6 +65,44 @@ pub fn return_die_without_reroll(
     }
 }
 
+/// Check whether Mana Steal may take the die at `die_index` for `player_idx`.
+///
+/// The die must be an undepleted basic die that is either unclaimed or held by
+/// another player who has not spent it yet (used from the Source this turn, or
+/// a stored Mana Steal die already used).
+pub fn check_mana_steal_target(
+    state: &GameState,
+    player_idx: usize,
+    die_index: usize,
+) -> Result<(), &'static str> {
+    let die = state.source.dice.get(die_index).ok_or("invalid die index")?;
+    if die.is_depleted || !die.color.is_basic() {
+        return Err("die is depleted or not a basic color");
+    }
+    let Some(owner_id) = die.taken_by_player_id.as_ref() else {
+        return Ok(());
+    };
+    if *owner_id == state.players[player_idx].id {
+        return Err("die already held by this player");
+    }
+    let owner = state
+        .players
+        .iter()
+        .find(|p| p.id == *owner_id)
+        .ok_or("die held by unknown player")?;
+    let spent_from_source = owner.used_die_ids.contains(&die.id);
+    let spent_stolen = owner.tactic_state.mana_steal_used_this_turn
+        && owner
+            .tactic_state
+            .stored_mana_die
+            .as_ref()
+            .is_some_and(|s| s.die_id == die.id);
+    if spent_from_source || spent_stolen {
+        return Err("die already spent by its holder");
+    }
+    Ok(())
+}
+
 // =============================================================================
 // End-of-turn dice return
 // =============================================================================
@@ -267,6 +305,41 @@ mod tests {
         assert!(!is_depleted_for_time(ManaColor::Red, TimeOfDay::Night));
     }
 
+    #[test]
+    fn reroll_color_frequencies_match_die_faces_day_and_night() {
+        // Six equally likely faces regardless of time of day; time only decides
+        // which face comes up depleted (Black by day, Gold by night).
+        const ROLLS_PER_SEED: usize = 2_000;
+        const SEEDS: u32 = 30;
+        let expected = (ROLLS_PER_SEED * SEEDS as usize) as f64 / 6.0;
+
+        for time_of_day in [TimeOfDay::Day, TimeOfDay::Night] {
+            let mut counts = [0usize; 6];
+            for seed in 0..SEEDS {
+                let mut state = create_solo_game(seed, Hero::Arythea);
+                let die_id = state.source.dice[0].id.clone();
+                for _ in 0..ROLLS_PER_SEED {
+                    reroll_die(&mut state.source, &die_id, time_of_day, &mut state.rng);
+                    let die = &state.source.dice[0];
+                    assert_eq!(die.is_depleted, is_depleted_for_time(die.color, time_of_day));
+                    let face = ALL_MANA_COLORS_ARRAY
+                        .iter()
+                        .position(|&c| c == die.color)
+                        .unwrap();
+                    counts[face] += 1;
+                }
+            }
+            for (face, &count) in counts.iter().enumerate() {
+                let deviation = (count as f64 - expected).abs() / expected;
+                assert!(
+                    deviation < 0.05,
+                    "{time_of_day:?}: {:?} rolled {count} times, expected ~{expected}",
+                    ALL_MANA_COLORS_ARRAY[face]
+                );
+            }
+        }
+    }
+
     #[test]
     fn gain_crystal_basic() {
         let mut state = create_solo_game(42, Hero::Arythea);
@@ -327,6 +327,8 @@ pub fn find_provoked_rampaging_enemies(
 /// Info about a detected assault on a fortified site.
 struct AssaultInfo {
     /// Whether city defenders need to be drawn (cities don't get enemies at tile reveal).
+    /// False once a garrison is on the hex, so survivors of a failed assault are not
+    /// joined by a fresh draw.
     needs_city_draw: bool,
     /// City color for garrison lookup (only set for cities).
     city_color: Option<BasicManaColor>,
@@ -368,7 +370,7 @@ fn detect_assault(state: &GameState, player_idx: usize, target: HexCoord) -> Opt
     }
 
     Some(AssaultInfo {
-        needs_city_draw: site.site_type == SiteType::City,
+        needs_city_draw: site.site_type == SiteType::City && hex.enemies.is_empty(),
         city_color: site.city_color,
         city_level: state.scenario_config.default_city_level,
         is_other_player_keep: false,
@@ -461,6 +463,7 @@ pub(crate) fn enter_assault_combat(
         wounds_added_to_hand_this_combat: false,
         attacks_this_phase: 0,
         fame_gained: 0,
+        fame_by_enemy: BTreeMap::new(),
         is_at_fortified_site: true,
         units_allowed: true,
         night_mana_rules: false,
@@ -486,8 +489,9 @@ pub(crate) fn enter_assault_combat(
         declared_block_target: None,
         declared_block_attack_index: None,
         has_paralyze_damage_to_hero: false,
-        ranged_siege_defeats: 0,
+        ranged_siege_defeated: Vec::new(),
         city_color,
+        combat_log: Vec::new(),
     };
 
     // Clear healing points
@@ -1979,6 +1983,40 @@ mod tests {
         assert!(combat.enemies[1].is_required_for_conquest);
     }
 
+    #[test]
+    fn city_reassault_fights_survivors_without_redrawing() {
+        let mut state = setup_game_with_move_points(10);
+        state.scenario_config.cities_can_be_entered = true;
+        state.scenario_config.default_city_level = 1;
+
+        // A previous failed assault left one defender on the city.
+        let hex = state.map.hexes.get_mut("1,0").unwrap();
+        hex.site = Some(Site {
+            site_type: SiteType::City,
+            owner: None,
+            is_conquered: false,
+            is_burned: false,
+            city_color: Some(BasicManaColor::Blue),
+            mine_color: None,
+            deep_mine_colors: None,
+        });
+        hex.enemies.push(HexEnemy {
+            token_id: EnemyTokenId::from("monks_1"),
+            color: EnemyColor::Violet,
+            is_revealed: true,
+        });
+        let gray_before = state.enemy_tokens.gray_draw.len();
+        let violet_before = state.enemy_tokens.violet_draw.len();
+
+        let result = execute_move(&mut state, 0, HexCoord::new(1, 0)).unwrap();
+        assert!(result.assault);
+
+        let combat = state.combat.as_ref().unwrap();
+        assert_eq!(combat.enemies.len(), 1, "Only the surviving defender should be fought");
+        assert_eq!(state.enemy_tokens.gray_draw.len(), gray_before);
+        assert_eq!(state.enemy_tokens.violet_draw.len(), violet_before);
+    }
+
     #[test]
     fn assault_reputation_floor_at_negative_seven() {
         let mut state = setup_game_with_move_points(10);
@@ -0,0 +1,87 @@
+//! Offer replenishment — refill the advanced action, spell and unit offers.
+//!
+//! Every mid-round "take from the offer" path (AA/spell rewards, Learning,
+//! Blood of Ancients, Magic Talent, unit site rewards) refills through one of
+//! these functions. Each draws the top of the matching deck and appends it to
+//! the offer; when the deck is exhausted the offer simply stays one smaller.
+//! Round-end refresh lives in `mk_data::offers::refresh_offer` and
+//! `mk_data::unit_offers::refresh_unit_offer`.
+
+use mk_data::offers::replenish_offer;
+use mk_data::unit_offers;
+use mk_types::ids::{CardId, UnitId};
+use mk_types::state::GameState;
+
+/// Refill the advanced action offer after one was taken.
+pub fn replenish_aa_offer(state: &mut GameState) -> Option<CardId> {
+    replenish_offer(&mut state.offers.advanced_actions, &mut state.decks.advanced_action_deck)
+}
+
+/// Refill the spell offer after one was taken.
+pub fn replenish_spell_offer(state: &mut GameState) -> Option<CardId> {
+    replenish_offer(&mut state.offers.spells, &mut state.decks.spell_deck)
+}
+
+/// Refill the unit offer after a unit was taken as a site reward.
+///
+/// Recruiting does not refill the offer; only reward-driven takes do.
+pub fn replenish_unit_offer(state: &mut GameState) -> Option<UnitId> {
+    unit_offers::replenish_unit_offer(&mut state.offers.units, &mut state.decks.unit_deck)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::setup::create_solo_game;
+    use mk_types::enums::Hero;
+
+    #[test]
+    fn taking_from_each_offer_refills_from_deck_top() {
+        let mut state = create_solo_game(42, Hero::Arythea);
+
+        let aa_len = state.offers.advanced_actions.len();
+        let aa_top = state.decks.advanced_action_deck[0].clone();
+        state.offers.advanced_actions.remove(0);
+        assert_eq!(replenish_aa_offer(&mut state), Some(aa_top.clone()));
+        assert_eq!(state.offers.advanced_actions.len(), aa_len);
+        assert_eq!(state.offers.advanced_actions.last(), Some(&aa_top));
+
+        let spell_len = state.offers.spells.len();
+        let spell_top = state.decks.spell_deck[0].clone();
+        state.offers.spells.remove(0);
+        assert_eq!(replenish_spell_offer(&mut state), Some(spell_top.clone()));
+        assert_eq!(state.offers.spells.len(), spell_len);
+        assert_eq!(state.offers.spells.last(), Some(&spell_top));
+
+        let unit_len = state.offers.units.len();
+        let unit_top = state.decks.unit_deck[0].clone();
+        let unit_deck_len = state.decks.unit_deck.len();
+        state.offers.units.remove(0);
+        assert_eq!(replenish_unit_offer(&mut state), Some(unit_top.clone()));
+        assert_eq!(state.offers.units.len(), unit_len);
+        assert_eq!(state.offers.units.last(), Some(&unit_top));
+        assert_eq!(state.decks.unit_deck.len(), unit_deck_len - 1);
+    }
+
+    #[test]
+    fn exhausted_decks_leave_smaller_offers() {
+        let mut state = create_solo_game(42, Hero::Arythea);
+        state.decks.advanced_action_deck.clear();
+        state.decks.spell_deck.clear();
+        state.decks.unit_deck.clear();
+
+        let aa_len = state.offers.advanced_actions.len();
+        let spell_len = state.offers.spells.len();
+        let unit_len = state.offers.units.len();
+        state.offers.advanced_actions.remove(0);
+        state.offers.spells.remove(0);
+        state.offers.units.remove(0);
+
+        assert_eq!(replenish_aa_offer(&mut state), None);
+        assert_eq!(replenish_spell_offer(&mut state), None);
+        assert_eq!(replenish_unit_offer(&mut state), None);
+        assert_eq!(state.offers.advanced_actions.len(), aa_len - 1);
+        assert_eq!(state.offers.spells.len(), spell_len - 1);
+        assert_eq!(state.offers.units.len(), unit_len - 1);
+    }
+}
@@ -9,14 +9,14 @@ use mk_data::heroes::{
     build_starting_deck, LEVEL_1_ARMOR, LEVEL_1_COMMAND_TOKENS, LEVEL_1_HAND_LIMIT,
     STARTING_HAND_SIZE,
 };
-use mk_data::enemy_piles::create_enemy_token_piles;
+use mk_data::enemy_piles::{create_enemy_token_piles, take_enemy_token_from_draw};
 use mk_data::offers::{create_aa_deck_and_offer, create_spell_deck_and_offer};
 use mk_data::unit_offers::create_unit_deck_and_offer;
 use mk_data::tiles::{find_portal, get_tile_hexes, starting_tile_hexes};
 use mk_types::enums::*;
 use mk_types::hex::{HexCoord, HexDirection, TILE_PLACEMENT_OFFSETS};
 use mk_types::ids::*;
-use mk_types::rng::RngState;
+use mk_types::rng::{RngAlgorithm, RngState};
 use mk_types::state::*;
 
 use crate::dummy_player;
@@ -118,25 +118,110 @@ pub fn create_mana_source(
     ManaSource { dice }
 }
 
+// =============================================================================
+// Setup options
+// =============================================================================
+
+/// Optional overrides for game setup (testing and variants).
+#[derive(Debug, Clone, Default)]
+pub struct SetupOptions {
+    /// Replaces the hero's starting deck. Used verbatim (not shuffled): the
+    /// first cards form the opening hand, the rest stay in deck order. The
+    /// hero deck's shuffle is still drawn from the RNG and discarded, so offers,
+    /// tiles and every later random draw match the default game with the same seed.
+    pub starting_deck: Option<Vec<CardId>>,
+    /// Deal every hero's starting deck in `build_starting_deck` order instead
+    /// of shuffling it, so tests get known draws with each hero's real cards.
+    /// Like `starting_deck`, the shuffle is drawn and discarded; unlike it, each
+    /// player keeps their own hero's cards. Ignored when `starting_deck` is set.
+    pub unshuffled_decks: bool,
+    /// RNG algorithm for the game. Recorded in `GameState::rng`, so replays
+    /// from a saved state keep using it.
+    pub rng_algorithm: RngAlgorithm,
+}
+
+/// Full description of a game to create with `create_game()`.
+#[derive(Debug, Clone)]
+pub struct SetupConfig {
+    pub seed: u32,
+    /// One hero for a solo game (with dummy player), or 2-4 for multiplayer.
+    pub heroes: Vec<Hero>,
+    /// Scenario ID (see `mk_data::scenarios::get_scenario`). `None` picks
+    /// First Reconnaissance for the player count.
+    pub scenario: Option<String>,
+    /// Overrides the scenario's map shape.
+    pub map_shape: Option<MapShape>,
+    /// Rule variants, added to the scenario's own (duplicates are ignored).
+    pub variants: Vec<Variant>,
+    /// Applied to every player.
+    pub setup_options: SetupOptions,
+}
+
+impl SetupConfig {
+    /// Solo First Reconnaissance with no overrides — what `create_solo_game` builds.
+    pub fn solo(seed: u32, hero: Hero) -> Self {
+        Self {
+            seed,
+            heroes: vec![hero],
+            scenario: None,
+            map_shape: None,
+            variants: Vec::new(),
+            setup_options: SetupOptions::default(),
+        }
+    }
+}
+
+/// Errors from game setup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetupError {
+    /// A starting deck override contains a card ID with no definition.
+    UnknownCardId(CardId),
+    /// The scenario ID doesn't name a known scenario.
+    UnknownScenario(String),
+    /// The hero count isn't supported by the scenario (or by any default scenario).
+    InvalidPlayerCount(usize),
+    /// A variant can't be used with this many players (e.g. Envy and Pity in solo).
+    UnsupportedVariant { variant: Variant, player_count: usize },
+    /// A map spec names an enemy with no definition or no token left to draw.
+    UnknownEnemy(String),
+    /// A map spec lists the same hex coordinate more than once.
+    DuplicateHex(HexCoord),
+    /// A map spec puts more enemies on one hex than a hex can hold.
+    TooManyHexEnemies(HexCoord),
+    /// A map spec starts the players on a hex that isn't part of the board.
+    PlayerPositionOffMap(HexCoord),
+}
+
 // =============================================================================
 // Player creation
 // =============================================================================
 
 /// Create a player with shuffled deck and drawn hand.
+///
+/// A `starting_deck` override is used as-is instead of the shuffled hero deck;
+/// `unshuffled_decks` keeps the hero deck in its built order. Either way the
+/// shuffle is drawn from the RNG so the stream doesn't shift.
 fn create_player(
     id: &str,
     hero: Hero,
     position: HexCoord,
     starting_fame: u32,
     starting_reputation: i8,
+    options: &SetupOptions,
     rng: &mut RngState,
 ) -> PlayerState {
-    // Build and shuffle the 16-card starting deck
-    let mut deck = build_starting_deck(hero);
-    rng.shuffle(&mut deck);
+    // Build and shuffle the 16-card starting deck (drawn even when overridden)
+    let mut shuffled = build_starting_deck(hero);
+    rng.shuffle(&mut shuffled);
+    let mut deck = match &options.starting_deck {
+        Some(cards) => cards.clone(),
+        None if options.unshuffled_decks => build_starting_deck(hero),
+        None => shuffled,
+    };
 
     // Draw starting hand (first 5 cards)
-    let hand: Vec<CardId> = deck.drain(..STARTING_HAND_SIZE).collect();
+    let hand_size = STARTING_HAND_SIZE.min(deck.len());
+    let hand: Vec<CardId> = deck.drain(..hand_size).collect();
 
     PlayerState {
         id: PlayerId::from(id),
@@ -276,10 +361,81 @@ pub(crate) fn place_starting_tile(tile_id: TileId) -> MapState {
 /// Note: Does NOT place initial countryside tiles. Call `place_initial_tiles()`
 /// after this to place the scenario's starting map tiles (e.g., NE + E for Wedge).
 pub fn create_solo_game(seed: u32, hero: Hero) -> GameState {
-    let mut rng = RngState::new(seed);
+    create_solo_game_with_options(seed, hero, &SetupOptions::default())
+        .expect("default setup options are always valid")
+}
 
-    let scenario_config = mk_data::scenarios::first_reconnaissance();
+/// Create a solo game with setup overrides (see `SetupOptions`).
+///
+/// Returns `SetupError::UnknownCardId` if a starting deck override names a card
+/// that doesn't exist. Without overrides this is identical to `create_solo_game`.
+pub fn create_solo_game_with_options(
+    seed: u32,
+    hero: Hero,
+    options: &SetupOptions,
+) -> Result<GameState, SetupError> {
+    create_game(&SetupConfig {
+        setup_options: options.clone(),
+        ..SetupConfig::solo(seed, hero)
+    })
+}
 
+/// Create a game from a full `SetupConfig`.
+///
+/// One hero builds a solo game with a dummy player; 2-4 heroes build a
+/// multiplayer game. The scenario is resolved first, then the map shape
+/// override and variants are applied to its configuration. Variants that
+/// don't support the player count are rejected.
+pub fn create_game(config: &SetupConfig) -> Result<GameState, SetupError> {
+    let player_count = config.heroes.len();
+    let scenario_id = match &config.scenario {
+        Some(id) => id.clone(),
+        None => match player_count {
+            1 => "first_reconnaissance".to_string(),
+            2..=4 => format!("first_reconnaissance_{}p", player_count),
+            _ => return Err(SetupError::InvalidPlayerCount(player_count)),
+        },
+    };
+    let mut scenario_config = mk_data::scenarios::get_scenario(&scenario_id)
+        .ok_or_else(|| SetupError::UnknownScenario(scenario_id.clone()))?;
+    if !(scenario_config.min_players..=scenario_config.max_players).contains(&(player_count as u32)) {
+        return Err(SetupError::InvalidPlayerCount(player_count));
+    }
+    if let Some(map_shape) = config.map_shape {
+        scenario_config.map_shape = map_shape;
+    }
+    for &variant in &config.variants {
+        if !scenario_config.has_variant(variant) {
+            scenario_config.variants.push(variant);
+        }
+    }
+    if let Some(&variant) =
+        scenario_config.variants.iter().find(|v| !v.supports_player_count(player_count))
+    {
+        return Err(SetupError::UnsupportedVariant { variant, player_count });
+    }
+
+    if let Some(deck) = &config.setup_options.starting_deck {
+        if let Some(unknown) = deck.iter().find(|c| mk_data::cards::get_card(c.as_str()).is_none()) {
+            return Err(SetupError::UnknownCardId(unknown.clone()));
+        }
+    }
+
+    let rng = RngState::with_algorithm(config.seed, config.setup_options.rng_algorithm);
+    Ok(if player_count == 1 {
+        build_solo_game(rng, config.heroes[0], scenario_config, &scenario_id, &config.setup_options)
+    } else {
+        build_multiplayer_game(rng, &config.heroes, scenario_config, &scenario_id, &config.setup_options)
+    })
+}
+
+fn build_solo_game(
+    mut rng: RngState,
+    hero: Hero,
+    scenario_config: ScenarioConfig,
+    scenario_id: &str,
+    options: &SetupOptions,
+) -> GameState {
     // Place starting tile
     let mut map = place_starting_tile(TileId::StartingA);
 
@@ -303,6 +459,7 @@ pub fn create_solo_game(seed: u32, hero: Hero) -> GameState {
         player_pos,
         scenario_config.starting_fame,
         scenario_config.starting_reputation,
+        options,
         &mut rng,
     );
 
@@ -376,7 +533,7 @@ pub fn create_solo_game(seed: u32, hero: Hero) -> GameState {
 
         wound_pile_count: None, // unlimited
 
-        scenario_id: ScenarioId::from("first_reconnaissance"),
+        scenario_id: ScenarioId::from(scenario_id),
         scenario_config,
         scenario_end_triggered: false,
         final_turns_remaining: None,
@@ -419,8 +576,17 @@ pub fn create_multiplayer_game(
         "Multiplayer requires 2-4 players, got {}",
         player_count
     );
+    build_multiplayer_game(RngState::new(seed), heroes, scenario_config, scenario_id, &SetupOptions::default())
+}
 
-    let mut rng = RngState::new(seed);
+fn build_multiplayer_game(
+    mut rng: RngState,
+    heroes: &[Hero],
+    scenario_config: ScenarioConfig,
+    scenario_id: &str,
+    options: &SetupOptions,
+) -> GameState {
+    let player_count = heroes.len();
 
     // Place starting tile
     let mut map = place_starting_tile(TileId::StartingA);
@@ -448,6 +614,7 @@ pub fn create_multiplayer_game(
             player_pos,
             scenario_config.starting_fame,
             scenario_config.starting_reputation,
+            options,
             &mut rng,
         );
         player_ids.push(PlayerId::from(pid.as_str()));
@@ -608,6 +775,118 @@ pub fn place_initial_tiles(state: &mut GameState) {
     }
 }
 
+// =============================================================================
+// Explicit map specs (scenario tests)
+// =============================================================================
+
+/// One hex of a `MapSpec` board.
+#[derive(Debug, Clone)]
+pub struct HexSpec {
+    pub coord: HexCoord,
+    pub terrain: Terrain,
+    pub site: Option<Site>,
+    /// Enemy IDs (e.g. `"guardsmen"`); each takes one token out of the draw piles.
+    pub enemies: Vec<String>,
+    /// Whether the enemy tokens are placed face up.
+    pub enemies_revealed: bool,
+}
+
+impl HexSpec {
+    /// An empty hex with no site or enemies.
+    pub fn new(coord: HexCoord, terrain: Terrain) -> Self {
+        Self { coord, terrain, site: None, enemies: Vec::new(), enemies_revealed: false }
+    }
+
+    /// Add an unconquered, unowned site of the given type.
+    pub fn with_site(mut self, site_type: SiteType) -> Self {
+        self.site = Some(Site {
+            site_type,
+            owner: None,
+            is_conquered: false,
+            is_burned: false,
+            city_color: None,
+            mine_color: None,
+            deep_mine_colors: None,
+        });
+        self
+    }
+
+    /// Add a face-down token of the given enemy.
+    pub fn with_enemy(mut self, enemy_id: &str) -> Self {
+        self.enemies.push(enemy_id.to_string());
+        self
+    }
+}
+
+/// An explicit board for `build_from_spec()`.
+#[derive(Debug, Clone)]
+pub struct MapSpec {
+    /// The game to build: heroes, seed, scenario and options.
+    pub config: SetupConfig,
+    /// Every revealed hex. Replaces the generated map entirely.
+    pub hexes: Vec<HexSpec>,
+    /// Where all players start.
+    pub player_position: HexCoord,
+}
+
+/// Create a game whose map is exactly the hexes of `spec`, with no exploration.
+///
+/// Decks, offers and the mana source come from `spec.config` as in
+/// `create_game()`; only the board and player positions differ. Each enemy
+/// named by the spec takes a token out of its color's draw pile, so it can't
+/// be drawn again. Returns `SetupError::UnknownEnemy` for an enemy that
+/// doesn't exist or has no token left to draw, and rejects duplicate hexes,
+/// overfull hexes and a player position off the board.
+pub fn build_from_spec(spec: MapSpec) -> Result<GameState, SetupError> {
+    let mut coords = std::collections::BTreeSet::new();
+    for hex in &spec.hexes {
+        if !coords.insert(hex.coord.key()) {
+            return Err(SetupError::DuplicateHex(hex.coord));
+        }
+    }
+    if !coords.contains(&spec.player_position.key()) {
+        return Err(SetupError::PlayerPositionOffMap(spec.player_position));
+    }
+
+    let mut state = create_game(&spec.config)?;
+    state.map.hexes.clear();
+    state.map.tiles.clear();
+
+    for hex in spec.hexes {
+        let mut enemies = ArrayVec::new();
+        for enemy_id in hex.enemies {
+            let token_id = mk_data::enemies::get_enemy(&enemy_id).and_then(|def| {
+                take_enemy_token_from_draw(&mut state.enemy_tokens, &enemy_id, def.color)
+                    .map(|token_id| (token_id, def.color))
+            });
+            let Some((token_id, color)) = token_id else {
+                return Err(SetupError::UnknownEnemy(enemy_id));
+            };
+            enemies
+                .try_push(HexEnemy { token_id, color, is_revealed: hex.enemies_revealed })
+                .map_err(|_| SetupError::TooManyHexEnemies(hex.coord))?;
+        }
+        state.map.hexes.insert(
+            hex.coord.key(),
+            HexState {
+                coord: hex.coord,
+                terrain: hex.terrain,
+                tile_id: TileId::StartingA,
+                site: hex.site,
+                rampaging_enemies: ArrayVec::new(),
+                enemies,
+                ruins_token: None,
+                shield_tokens: Vec::new(),
+            },
+        );
+    }
+
+    for player in &mut state.players {
+        player.position = Some(spec.player_position);
+    }
+    Ok(state)
+}
+
 /// Generate tile slots for a Wedge map shape.
 ///
 /// The Wedge is a triangular grid expanding NE and E from origin.
@@ -746,6 +1025,146 @@ mod tests {
         assert!(player.discard.is_empty());
     }
 
+    #[test]
+    fn starting_deck_override_used_verbatim() {
+        let deck: Vec<CardId> = ["fireball", "march", "rage", "stamina", "wound", "swiftness", "promise"]
+            .into_iter()
+            .map(CardId::from)
+            .collect();
+        let options = SetupOptions { starting_deck: Some(deck.clone()), ..SetupOptions::default() };
+        let state = create_solo_game_with_options(42, Hero::Arythea, &options).unwrap();
+        let player = &state.players[0];
+
+        assert_eq!(player.hand.as_slice(), &deck[..5]);
+        assert_eq!(player.deck.as_slice(), &deck[5..]);
+
+        // Everything drawn after the decks matches the default game.
+        let shuffled = create_solo_game_with_options(42, Hero::Arythea, &SetupOptions::default()).unwrap();
+        assert_eq!(state.rng.counter, shuffled.rng.counter);
+        assert_eq!(state.offers.units, shuffled.offers.units);
+        assert_eq!(state.offers.spells, shuffled.offers.spells);
+    }
+
+    #[test]
+    fn starting_deck_override_rejects_unknown_card() {
+        let options = SetupOptions {
+            starting_deck: Some(vec![CardId::from("march"), CardId::from("not_a_card")]),
+            ..SetupOptions::default()
+        };
+        let err = create_solo_game_with_options(42, Hero::Arythea, &options).unwrap_err();
+        assert_eq!(err, SetupError::UnknownCardId(CardId::from("not_a_card")));
+    }
+
+    #[test]
+    fn unshuffled_decks_deal_opening_hand_in_built_order() {
+        let options = SetupOptions { unshuffled_decks: true, ..SetupOptions::default() };
+        let config = SetupConfig {
+            heroes: vec![Hero::Goldyx, Hero::Krang],
+            setup_options: options.clone(),
+            ..SetupConfig::solo(42, Hero::Goldyx)
+        };
+        let state = create_game(&config).unwrap();
+
+        for player in &state.players {
+            let built = build_starting_deck(player.hero);
+            assert_eq!(player.hand.as_slice(), &built[..5], "{:?}", player.hero);
+            assert_eq!(player.deck.as_slice(), &built[5..], "{:?}", player.hero);
+        }
+
+        let other_seed = create_solo_game_with_options(7, Hero::Goldyx, &options).unwrap();
+        assert_eq!(other_seed.players[0].hand, state.players[0].hand, "order doesn't depend on the seed");
+
+        // Everything drawn after the decks matches the shuffled game.
+        let shuffled = create_game(&SetupConfig {
+            setup_options: SetupOptions::default(),
+            ..config
+        })
+        .unwrap();
+        assert_eq!(state.rng.counter, shuffled.rng.counter);
+        assert_eq!(state.offers.units, shuffled.offers.units);
+        assert_eq!(state.offers.spells, shuffled.offers.spells);
+    }
+
+    #[test]
+    fn default_setup_options_match_create_solo_game() {
+        let with_options =
+            create_solo_game_with_options(42, Hero::Arythea, &SetupOptions::default()).unwrap();
+        let plain = create_solo_game(42, Hero::Arythea);
+        assert_eq!(with_options.players[0].hand, plain.players[0].hand);
+        assert_eq!(with_options.players[0].deck, plain.players[0].deck);
+    }
+
+    #[test]
+    fn create_game_applies_variant_and_map_shape_override() {
+        let config = SetupConfig {
+            heroes: vec![Hero::Tovak, Hero::Krang],
+            map_shape: Some(MapShape::Open),
+            variants: vec![Variant::EnvyAndPity, Variant::EnvyAndPity],
+            ..SetupConfig::solo(42, Hero::Tovak)
+        };
+        let state = create_game(&config).unwrap();
+
+        assert_eq!(state.scenario_config.variants, vec![Variant::EnvyAndPity]);
+        assert_eq!(state.scenario_config.map_shape, MapShape::Open);
+        assert_eq!(state.scenario_id.as_str(), "first_reconnaissance_2p");
+        assert!(create_solo_game(42, Hero::Tovak).scenario_config.variants.is_empty());
+    }
+
+    #[test]
+    fn create_game_rejects_multiplayer_variant_in_solo() {
+        let config = SetupConfig {
+            variants: vec![Variant::EnvyAndPity],
+            ..SetupConfig::solo(42, Hero::Tovak)
+        };
+        assert_eq!(
+            create_game(&config).unwrap_err(),
+            SetupError::UnsupportedVariant { variant: Variant::EnvyAndPity, player_count: 1 }
+        );
+    }
+
+    #[test]
+    fn create_game_solo_matches_create_solo_game() {
+        let via_config = create_game(&SetupConfig::solo(7, Hero::Goldyx)).unwrap();
+        let plain = create_solo_game(7, Hero::Goldyx);
+        assert_eq!(
+            serde_json::to_value(&via_config).unwrap(),
+            serde_json::to_value(&plain).unwrap()
+        );
+    }
+
+    #[test]
+    fn create_game_picks_scenario_for_player_count() {
+        let config = SetupConfig {
+            heroes: vec![Hero::Arythea, Hero::Krang, Hero::Norowas],
+            ..SetupConfig::solo(42, Hero::Arythea)
+        };
+        let state = create_game(&config).unwrap();
+        assert_eq!(state.players.len(), 3);
+        assert_eq!(state.scenario_id.as_str(), "first_reconnaissance_3p");
+        assert!(state.dummy_player.is_none());
+    }
+
+    #[test]
+    fn create_game_rejects_bad_scenario_or_player_count() {
+        let unknown = SetupConfig {
+            scenario: Some("no_such_scenario".to_string()),
+            ..SetupConfig::solo(42, Hero::Arythea)
+        };
+        assert_eq!(
+            create_game(&unknown).unwrap_err(),
+            SetupError::UnknownScenario("no_such_scenario".to_string())
+        );
+
+        let mismatched = SetupConfig {
+            scenario: Some("full_conquest_2p".to_string()),
+            ..SetupConfig::solo(42, Hero::Arythea)
+        };
+        assert_eq!(create_game(&mismatched).unwrap_err(), SetupError::InvalidPlayerCount(1));
+
+        let empty = SetupConfig { heroes: Vec::new(), ..SetupConfig::solo(42, Hero::Arythea) };
+        assert_eq!(create_game(&empty).unwrap_err(), SetupError::InvalidPlayerCount(0));
+    }
+
     #[test]
     fn player_starts_at_portal() {
         let state = create_solo_game(42, Hero::Arythea);
@@ -901,6 +1320,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rng_algorithm_option_is_recorded_in_state() {
+        assert_eq!(create_solo_game(42, Hero::Arythea).rng.algorithm, RngAlgorithm::Mulberry32);
+
+        let options = SetupOptions { rng_algorithm: RngAlgorithm::SplitMix32, ..SetupOptions::default() };
+        let state = create_solo_game_with_options(42, Hero::Arythea, &options).unwrap();
+        assert_eq!(state.rng.algorithm, RngAlgorithm::SplitMix32);
+        assert_eq!(state.rng.seed, 42);
+
+        let again = create_solo_game_with_options(42, Hero::Arythea, &options).unwrap();
+        assert_eq!(again.players[0].deck, state.players[0].deck);
+        assert_eq!(again.rng, state.rng);
+    }
+
     #[test]
     fn solo_game_has_aa_offer_and_deck() {
         let state = create_solo_game(42, Hero::Arythea);
@@ -1145,4 +1578,84 @@ mod tests {
         assert_eq!(mid.row, 2);
         assert_eq!(mid.column, 1);
     }
+
+    #[test]
+    fn spec_board_with_adjacent_keep_can_be_assaulted() {
+        let spec = MapSpec {
+            config: SetupConfig::solo(42, Hero::Tovak),
+            hexes: vec![
+                HexSpec::new(HexCoord::new(0, 0), Terrain::Plains),
+                HexSpec::new(HexCoord::new(1, 0), Terrain::Plains)
+                    .with_site(SiteType::Keep)
+                    .with_enemy("guardsmen"),
+            ],
+            player_position: HexCoord::new(0, 0),
+        };
+        let mut state = build_from_spec(spec).unwrap();
+
+        assert_eq!(state.map.hexes.len(), 2);
+        assert_eq!(state.players[0].position, Some(HexCoord::new(0, 0)));
+        let keep = &state.map.hexes["1,0"];
+        assert_eq!(keep.site.as_ref().unwrap().site_type, SiteType::Keep);
+        let token = &keep.enemies[0].token_id;
+        assert!(!state.enemy_tokens.gray_draw.contains(token), "spec tokens leave the draw pile");
+
+        state.round_phase = RoundPhase::PlayerTurns;
+        state.players[0].move_points = 2;
+        let mut undo = crate::undo::UndoStack::new();
+        let legal = crate::legal_actions::enumerate_legal_actions_with_undo(&state, 0, &undo);
+        let assault = legal
+            .actions
+            .iter()
+            .find(|a| matches!(a, mk_types::legal_action::LegalAction::Move { target, .. } if *target == HexCoord::new(1, 0)))
+            .expect("keep should be enterable")
+            .clone();
+        crate::action_pipeline::apply_legal_action(&mut state, &mut undo, 0, &assault, legal.epoch).unwrap();
+
+        let combat = state.combat.as_ref().expect("moving onto the keep assaults it");
+        assert!(combat.is_at_fortified_site);
+        assert_eq!(combat.enemies[0].enemy_id.as_str(), "guardsmen");
+    }
+
+    #[test]
+    fn spec_with_unknown_enemy_is_rejected() {
+        let spec = MapSpec {
+            config: SetupConfig::solo(42, Hero::Tovak),
+            hexes: vec![HexSpec::new(HexCoord::new(0, 0), Terrain::Plains).with_enemy("dragon_lord")],
+            player_position: HexCoord::new(0, 0),
+        };
+        assert_eq!(build_from_spec(spec).unwrap_err(), SetupError::UnknownEnemy("dragon_lord".to_string()));
+    }
+
+    #[test]
+    fn spec_with_invalid_board_is_rejected() {
+        let origin = HexCoord::new(0, 0);
+        let spec = |hexes: Vec<HexSpec>, player_position| MapSpec {
+            config: SetupConfig::solo(42, Hero::Tovak),
+            hexes,
+            player_position,
+        };
+
+        let duplicate = vec![HexSpec::new(origin, Terrain::Plains), HexSpec::new(origin, Terrain::Hills)];
+        assert_eq!(build_from_spec(spec(duplicate, origin)).unwrap_err(), SetupError::DuplicateHex(origin));
+
+        let off_map = HexCoord::new(5, 5);
+        let board = vec![HexSpec::new(origin, Terrain::Plains)];
+        assert_eq!(
+            build_from_spec(spec(board, off_map)).unwrap_err(),
+            SetupError::PlayerPositionOffMap(off_map)
+        );
+
+        let crowded = [
+            "diggers", "prowlers", "cursed_hags", "wolf_riders", "ironclads",
+            "orc_summoners", "crossbowmen", "guardsmen", "swordsmen",
+        ]
+        .iter()
+        .fold(HexSpec::new(origin, Terrain::Plains), |hex, id| hex.with_enemy(id));
+        assert!(crowded.enemies.len() > MAX_HEX_ENEMIES);
+        assert_eq!(
+            build_from_spec(spec(vec![crowded], origin)).unwrap_err(),
+            SetupError::TooManyHexEnemies(origin)
+        );
+    }
 }
@@ -47,6 +47,21 @@ impl UndoStack {
         self.snapshots.pop()
     }
 
+    /// Pop every snapshot and return the oldest — the state right after the
+    /// last checkpoint (or the start of the stack). Returns `None` if the
+    /// stack is empty.
+    ///
+    /// The snapshot carries its old `action_epoch`; callers must re-stamp it
+    /// (see `action_pipeline::undo_to_checkpoint`) so epochs never repeat.
+    pub fn undo_to_checkpoint(&mut self) -> Option<GameState> {
+        if self.snapshots.is_empty() {
+            return None;
+        }
+        let oldest = self.snapshots.swap_remove(0);
+        self.snapshots.clear();
+        Some(oldest)
+    }
+
     /// Set a checkpoint — clears the entire stack.
     /// Used after irreversible actions (tile reveal, combat entry, etc.).
     pub fn set_checkpoint(&mut self) {
@@ -161,6 +176,22 @@ mod tests {
     fn undo_empty_returns_none() {
         let mut stack = UndoStack::new();
         assert!(stack.undo().is_none());
+        assert!(stack.undo_to_checkpoint().is_none());
+    }
+
+    #[test]
+    fn undo_to_checkpoint_returns_oldest_snapshot_after_checkpoint() {
+        let mut stack = UndoStack::new();
+        stack.save(&create_solo_game(1, Hero::Goldyx));
+        stack.set_checkpoint();
+        stack.save(&create_solo_game(42, Hero::Arythea));
+        stack.save(&create_solo_game(99, Hero::Tovak));
+        stack.save(&create_solo_game(7, Hero::Norowas));
+
+        let restored = stack.undo_to_checkpoint().unwrap();
+        assert_eq!(restored.players[0].hero, Hero::Arythea);
+        assert!(!stack.can_undo());
+        assert!(stack.is_checkpoint_active());
     }
 
     #[test]
@@ -19,7 +19,7 @@ use mk_engine::scoring::{calculate_category_base_points, calculate_final_scores}
 use mk_engine::undo::UndoStack;
 use mk_features::EncodedStep;
 use mk_types::enums::Hero;
-use mk_types::legal_action::{LegalAction, LegalActionSet};
+use mk_types::legal_action::{EmptyReason, LegalAction, LegalActionSet};
 use mk_types::scoring::AchievementCategory;
 use mk_types::state::{GameState, PlayerFlags};
 
@@ -433,6 +433,7 @@ impl SingleEnv {
                     actions: vec![],
                     epoch: self.action_set.epoch + 1,
                     player_idx: 0,
+                    empty_reason: Some(EmptyReason::GameEnded),
                 };
                 (true, true, idx)
             }
@@ -454,6 +455,7 @@ impl SingleEnv {
                     actions: vec![],
                     epoch: self.action_set.epoch + 1,
                     player_idx: 0,
+                    empty_reason: Some(EmptyReason::GameEnded),
                 };
                 (true, true, idx)
             }
@@ -1078,11 +1080,7 @@ mod tests {
 
         for _ in 0..10 {
             let batch = env.encode_batch();
-            let actions: Vec<i32> = batch
-                .action_counts
-                .iter()
-                .map(|&c| if c > 0 { 0 } else { 0 })
-                .collect();
+            let actions: Vec<i32> = vec![0; batch.action_counts.len()];
             let result = env.step_batch(&actions);
             assert_eq!(result.dones.len(), 4);
             assert_eq!(result.fame_deltas.len(), 4);
@@ -13,7 +13,9 @@ use std::panic::{catch_unwind, AssertUnwindSafe};
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
 
-use mk_engine::action_pipeline::{apply_legal_action, initial_events, ApplyError};
+use mk_engine::action_pipeline::{
+    apply_legal_action, initial_events, restore_undo_snapshot, ApplyError,
+};
 use mk_engine::client_state::to_client_state;
 use mk_engine::combat_search::{search_combat, CombatSearchConfig};
 use mk_engine::commerce_search::{search_commerce, CommerceSearchConfig};
@@ -25,7 +27,7 @@ use mk_env::{TrainingScenario, VecEnv};
 use mk_features::EncodedStep;
 use mk_types::enums::Hero;
 use mk_types::events::GameEvent;
-use mk_types::legal_action::{LegalAction, LegalActionSet};
+use mk_types::legal_action::{EmptyReason, LegalAction, LegalActionSet};
 use mk_types::state::GameState;
 
 // =============================================================================
@@ -33,16 +35,8 @@ use mk_types::state::GameState;
 // =============================================================================
 
 fn parse_hero(name: &str) -> PyResult<Hero> {
-    match name.to_lowercase().as_str() {
-        "arythea" => Ok(Hero::Arythea),
-        "tovak" => Ok(Hero::Tovak),
-        "goldyx" => Ok(Hero::Goldyx),
-        "norowas" => Ok(Hero::Norowas),
-        "wolfhawk" => Ok(Hero::Wolfhawk),
-        "krang" => Ok(Hero::Krang),
-        "braevalar" => Ok(Hero::Braevalar),
-        _ => Err(PyValueError::new_err(format!("Unknown hero: {name}"))),
-    }
+    mk_data::heroes::hero_by_id(name)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown hero: {name}")))
 }
 
 fn parse_scenario(scenario: Option<&str>) -> PyResult<TrainingScenario> {
@@ -303,6 +297,19 @@ impl GameEngine {
         self.action_set.epoch
     }
 
+    /// Why there are no legal actions, if enumeration stopped at a guard.
+    ///
+    /// One of "unknown_player", "game_ended", "wrong_phase" or
+    /// "not_your_turn"; None while the player has actions (or is stuck on turn).
+    fn empty_reason(&self) -> Option<&'static str> {
+        self.action_set.empty_reason.map(|reason| match reason {
+            EmptyReason::UnknownPlayer => "unknown_player",
+            EmptyReason::GameEnded => "game_ended",
+            EmptyReason::WrongPhase => "wrong_phase",
+            EmptyReason::NotYourTurn => "not_your_turn",
+        })
+    }
+
     /// Apply the legal action at the given index.
     ///
     /// Args:
@@ -446,19 +453,25 @@ impl GameEngine {
     ///
     /// Returns True if undo succeeded, False if nothing to undo.
     fn undo(&mut self) -> bool {
-        if let Some(restored) = self.undo_stack.undo() {
-            self.state = restored;
-            self.last_events = vec![GameEvent::Undone {
-                player_id: self.state.players[self.player_idx].id.clone(),
-            }];
-            self.action_set = enumerate_legal_actions_with_undo(
-                &self.state,
-                self.player_idx,
-                &self.undo_stack,
-            );
-            true
-        } else {
-            false
+        match self.undo_stack.undo() {
+            Some(restored) => {
+                self.restore_snapshot(restored);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Undo every reversible action back to the last checkpoint.
+    ///
+    /// Returns True if anything was undone, False if nothing to undo.
+    fn undo_to_checkpoint(&mut self) -> bool {
+        match self.undo_stack.undo_to_checkpoint() {
+            Some(restored) => {
+                self.restore_snapshot(restored);
+                true
+            }
+            None => false,
         }
     }
 
@@ -774,6 +787,28 @@ impl GameEngine {
             .map_err(|e| PyValueError::new_err(format!("Serialization error: {e}")))
     }
 
+    /// Legal action count distribution over a random playout from the current state.
+    ///
+    /// The engine's own state is not advanced. Returns a dict with
+    /// `histogram` (action count → steps), `steps`, `mean`, `max` and
+    /// `game_ended` (whether the playout finished before `max_steps`).
+    #[pyo3(signature = (action_seed=0, max_steps=2000))]
+    fn branching_histogram<'py>(
+        &self,
+        py: Python<'py>,
+        action_seed: u32,
+        max_steps: usize,
+    ) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+        let histogram = mk_engine::branching::branching_histogram(&self.state, action_seed, max_steps);
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("histogram", histogram.counts.clone())?;
+        dict.set_item("steps", histogram.steps)?;
+        dict.set_item("mean", histogram.mean())?;
+        dict.set_item("max", histogram.max())?;
+        dict.set_item("game_ended", histogram.game_ended)?;
+        Ok(dict)
+    }
+
     /// Encode the current state + legal actions into RL features.
     ///
     /// Returns a PyEncodedStep containing:
@@ -840,6 +875,19 @@ impl GameEngine {
 }
 
 impl GameEngine {
+    /// Replace the state with an undo snapshot and re-enumerate.
+    fn restore_snapshot(&mut self, restored: GameState) {
+        restore_undo_snapshot(&mut self.state, restored);
+        self.last_events = vec![GameEvent::Undone {
+            player_id: self.state.players[self.player_idx].id.clone(),
+        }];
+        self.action_set = enumerate_legal_actions_with_undo(
+            &self.state,
+            self.player_idx,
+            &self.undo_stack,
+        );
+    }
+
     /// Diagnostic string for empty-action-set errors.
     fn debug_empty_actions(&self) -> String {
         let s = &self.state;
@@ -1082,6 +1130,26 @@ impl PyVecEnv {
 // Module registration
 // =============================================================================
 
+/// Return the playable heroes as a list of dicts.
+///
+/// Each dict has `id` (the name accepted by `GameEngine(hero=...)`), `name`,
+/// `starting_deck` (16 card ids) and `unique_cards` (hero-specific cards).
+#[pyfunction]
+fn available_heroes(py: Python<'_>) -> PyResult<Vec<Bound<'_, pyo3::types::PyDict>>> {
+    mk_data::heroes::all_heroes()
+        .iter()
+        .map(|info| {
+            let dict = pyo3::types::PyDict::new(py);
+            dict.set_item("id", info.id)?;
+            dict.set_item("name", info.name)?;
+            let deck: Vec<String> = info.starting_deck().iter().map(|c| c.as_str().to_string()).collect();
+            dict.set_item("starting_deck", deck)?;
+            dict.set_item("unique_cards", info.unique_cards())?;
+            Ok(dict)
+        })
+        .collect()
+}
+
 /// Return a dict of vocab name → size for all 9 vocabularies.
 ///
 /// Used by Python tests to verify vocab sync without hardcoding sizes.
@@ -1111,5 +1179,6 @@ fn mk_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyEncodedStep>()?;
     m.add_class::<PyVecEnv>()?;
     m.add_function(wrap_pyfunction!(get_vocab_sizes, m)?)?;
+    m.add_function(wrap_pyfunction!(available_heroes, m)?)?;
     Ok(())
 }
@@ -12,7 +12,9 @@ use serde::{Deserialize, Serialize};
 use crate::enums::*;
 use crate::hex::HexCoord;
 use crate::ids::*;
-use crate::state::{AccumulatedAttack, BannerAttachment, Crystals, ElementalValues, KeptEnemyToken};
+use crate::state::{
+    AccumulatedAttack, BannerAttachment, CombatLogEntry, Crystals, ElementalValues, KeptEnemyToken,
+};
 
 // =============================================================================
 // Top-level client state
@@ -26,10 +28,16 @@ pub struct ClientGameState {
     pub round_phase: RoundPhase,
     pub time_of_day: TimeOfDay,
     pub round: u32,
+    /// Epoch of the state this snapshot was taken from; matches the `epoch` a
+    /// legal action set must carry. Strictly increases with every applied action.
+    pub action_epoch: u64,
     pub current_player_id: PlayerId,
     pub turn_order: Vec<PlayerId>,
     pub end_of_round_announced_by: Option<PlayerId>,
 
+    /// Authoritative summary of what the current player is doing.
+    pub turn_context: TurnContext,
+
     pub players: Vec<ClientPlayer>,
     pub map: ClientMapState,
     pub source: ClientManaSource,
@@ -44,6 +52,24 @@ pub struct ClientGameState {
     pub dummy_player: Option<ClientDummyPlayer>,
 }
 
+/// What the current player is doing — mirrors the `ValidActions` projection
+/// so clients don't have to infer it from the action list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TurnContext {
+    /// The game is over; nobody can act.
+    GameEnded,
+    /// Players are choosing tactics for the round.
+    TacticsSelection,
+    /// The current player must resolve a pending choice first.
+    PendingChoice,
+    /// The current player is in combat.
+    #[serde(rename_all = "camelCase")]
+    CombatTurn { combat_phase: CombatPhase },
+    /// Regular turn: move, play cards, interact.
+    NormalTurn,
+}
+
 // =============================================================================
 // Player
 // =============================================================================
@@ -64,12 +90,15 @@ pub struct ClientPlayer {
     pub hand_limit: u32,
     pub command_tokens: u32,
 
-    // Cards — hand is visible for self, hidden for others
+    // Cards — hand and discard are visible for self, hidden for others
     /// Full card list for self, empty for other players.
     pub hand: Vec<CardId>,
     /// Always set — card count (use this for other players).
     pub hand_count: usize,
+    /// Deck order is never revealed, not even to its owner.
     pub deck_count: usize,
+    /// Discard pile for self, empty for other players.
+    pub discard: Vec<CardId>,
     pub discard_count: usize,
     pub play_area: Vec<CardId>,
 
@@ -286,6 +315,8 @@ pub struct ClientCombatState {
     pub declared_attack_type: Option<CombatType>,
     /// Total armor the player needs to overcome for the declared targets.
     pub declared_attack_armor_needed: Option<u32>,
+    /// Ordered narration of this combat (blocks, attacks, defeats, summons, damage).
+    pub combat_log: Vec<CombatLogEntry>,
 }
 
 /// Combat enemy with hydrated definition fields.
@@ -669,6 +669,29 @@ impl MapShape {
     }
 }
 
+// =============================================================================
+// Rule Variants
+// =============================================================================
+
+/// Optional rule variant layered on top of a scenario.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Variant {
+    /// Envy and Pity catch-up rule: at Day round end the Fame leader shuffles a
+    /// Wound into their deck; at Night round end last place may remove a Wound.
+    EnvyAndPity,
+}
+
+impl Variant {
+    /// Whether the variant can be used with `player_count` human players.
+    pub fn supports_player_count(self, player_count: usize) -> bool {
+        match self {
+            // Compares Fame between players.
+            Self::EnvyAndPity => player_count >= 2,
+        }
+    }
+}
+
 // =============================================================================
 // Scenario End Trigger
 // =============================================================================
@@ -166,7 +166,7 @@ mod tests {
         // All should be within distance 2
         for h in &hexes {
             let d = origin.distance(*h);
-            assert!(d >= 1 && d <= 2, "Hex {:?} has distance {}", h, d);
+            assert!((1..=2).contains(&d), "Hex {:?} has distance {}", h, d);
         }
         // Self should not be included
         assert!(!hexes.contains(&origin));
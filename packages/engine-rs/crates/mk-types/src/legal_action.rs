@@ -39,6 +39,8 @@ pub enum LegalAction {
         hand_index: usize,
         card_id: CardId,
     },
+    /// Powered play. For `destroy_on_powered` artifacts this is the throw:
+    /// the stronger effect resolves and the card goes to `removed_cards`.
     PlayCardPowered {
         hand_index: usize,
         card_id: CardId,
@@ -106,9 +108,12 @@ pub enum LegalAction {
     ResolveGladeWound {
         choice: GladeWoundChoice,
     },
+    /// Only enumerated when the player can pay `influence_cost` from their
+    /// current influence (reputation bonus and shields included).
     RecruitUnit {
         unit_id: UnitId,
         offer_index: usize,
+        /// Net cost after reputation, site and skill modifiers.
         influence_cost: u32,
     },
     ActivateUnit {
@@ -335,4 +340,22 @@ pub struct LegalActionSet {
     pub player_idx: usize,
     /// The legal actions, in deterministic order.
     pub actions: Vec<LegalAction>,
+    /// Why the player can't act at all, when enumeration stopped at a guard.
+    /// `None` with no actions means the player is on turn but stuck.
+    #[serde(default)]
+    pub empty_reason: Option<EmptyReason>,
+}
+
+/// Why a `LegalActionSet` is empty before any action was considered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmptyReason {
+    /// The player index doesn't name a player.
+    UnknownPlayer,
+    /// The game is over.
+    GameEnded,
+    /// The game isn't in a phase where players act (setup or end).
+    WrongPhase,
+    /// Another player is acting and nothing can be done out of turn.
+    NotYourTurn,
 }
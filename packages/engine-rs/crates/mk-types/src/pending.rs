@@ -191,7 +191,7 @@ pub struct PolarizationOption {
 }
 
 /// Option for Know Your Prey: what to strip from the target enemy.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum KnowYourPreyApplyOption {
     NullifyAbility { ability: EnemyAbilityType },
@@ -311,6 +311,11 @@ pub enum ChoiceResolution {
         block_value: u32,
         block_element: Element,
     },
+    /// Puppet Master keep: after combat, keep one defeated enemy token.
+    /// Option 0 is skip; option `i` keeps `enemy_ids[i - 1]`.
+    PuppetMasterKeepToken {
+        enemy_ids: Vec<EnemyId>,
+    },
     /// Shapeshift step 1: select which basic action card to transform.
     ShapeshiftCardSelect {
         options: Vec<ShapeshiftCardOption>,
@@ -486,6 +491,12 @@ pub enum ChoiceResolution {
     MysteriousBoxUse {
         revealed_artifact_id: CardId,
     },
+
+    // === Round-end ChoiceResolutions ===
+
+    /// Envy and Pity (Night round end): last-place player may remove a Wound.
+    /// Choice 0 = skip (Noop), choice 1 = remove one Wound from hand, else deck.
+    PityWoundRemoval,
 }
 
 /// Pending choice — when a card, skill, or unit ability requires player selection.
@@ -498,6 +509,11 @@ pub struct PendingChoice {
     pub options: Vec<CardEffect>,
     /// Effects remaining in the queue after this choice resolves.
     pub continuation: Vec<ContinuationEntry>,
+    /// Reserved for per-choice movement bonuses; currently always false. A chosen
+    /// Move is applied once when the choice resolves, and card movement bonuses
+    /// are only consumed by sideways Move plays. The field stays because pending
+    /// choices are serialized with the game state, and readers built before it
+    /// became unused still require it (it has no serde default).
     pub movement_bonus_applied: bool,
     /// How to resolve this choice beyond just enqueueing the chosen effect.
     #[serde(default = "default_choice_resolution")]
@@ -751,6 +767,11 @@ pub struct PendingMeditation {
     pub version: EffectMode,
     pub phase: MeditationPhase,
     pub selected_card_ids: Vec<CardId>,
+    /// Discard indices picked while selecting. Picks are distinct by index, so
+    /// two copies of the same card can both be chosen. Cleared once placement
+    /// starts moving cards out of the discard.
+    #[serde(default)]
+    pub selected_discard_indices: Vec<usize>,
 }
 
 /// Meditation phase.
@@ -868,6 +889,10 @@ pub enum DeferredPending {
     LevelUps(ArrayVec<u8, MAX_PENDING_LEVEL_UPS>),
     LevelUpRewards(Vec<PendingLevelUpReward>),
     AttackDefeatFame(ArrayVec<AttackDefeatFameTracker, MAX_ATTACK_DEFEAT_FAME>),
+    /// Puppet Master keep offer waiting for site rewards to resolve first.
+    PuppetMasterKeep { enemy_ids: Vec<EnemyId> },
+    /// Envy and Pity wound-removal offer, held from round end until the player's first turn.
+    PityWoundRemoval,
 }
 
 // =============================================================================
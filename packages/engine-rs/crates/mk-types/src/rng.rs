@@ -3,27 +3,52 @@
 //! All randomness in the game engine must go through `RngState` to ensure
 //! games are reproducible for testing, replays, RL training, and debugging.
 //!
-//! The algorithm exactly matches `packages/core/src/utils/rng.ts`.
+//! The default algorithm exactly matches `packages/core/src/utils/rng.ts`.
+//! The algorithm is stored in the state, so a saved game keeps drawing from
+//! the same generator even if the default changes.
 
 use serde::{Deserialize, Serialize};
 
-/// RNG state tracked in game state. Matches TS `RngState`.
+/// Counter-based generator used by an `RngState`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RngAlgorithm {
+    /// Mulberry32 — parity with the TS engine.
+    #[default]
+    Mulberry32,
+    /// SplitMix32-style finalizer hash of `seed + counter`.
+    SplitMix32,
+}
+
+/// RNG state tracked in game state. Matches TS `RngState` for Mulberry32.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RngState {
     pub seed: u32,
     pub counter: u32,
+    /// Missing in states saved before the choice existed, which all used Mulberry32.
+    #[serde(default)]
+    pub algorithm: RngAlgorithm,
 }
 
 impl RngState {
-    /// Create RNG with the given seed (counter starts at 0).
+    /// Create a Mulberry32 RNG with the given seed (counter starts at 0).
     pub fn new(seed: u32) -> Self {
-        Self { seed, counter: 0 }
+        Self::with_algorithm(seed, RngAlgorithm::Mulberry32)
+    }
+
+    /// Create an RNG with the given seed and algorithm (counter starts at 0).
+    pub fn with_algorithm(seed: u32, algorithm: RngAlgorithm) -> Self {
+        Self { seed, counter: 0, algorithm }
     }
 
     /// Get next random f64 in [0, 1). Advances counter by 1.
     pub fn next_f64(&mut self) -> f64 {
         self.counter = self.counter.wrapping_add(1);
-        mulberry32(self.seed.wrapping_add(self.counter))
+        let input = self.seed.wrapping_add(self.counter);
+        match self.algorithm {
+            RngAlgorithm::Mulberry32 => mulberry32(input),
+            RngAlgorithm::SplitMix32 => splitmix32(input),
+        }
     }
 
     /// Get random u32 in [min, max] inclusive.
@@ -76,6 +101,16 @@ fn mulberry32(input: u32) -> f64 {
     result as f64 / 4294967296.0
 }
 
+/// SplitMix32 — golden-ratio increment followed by a 32-bit avalanche
+/// finalizer. Takes a u32 input (seed + counter), returns a value in [0, 1).
+fn splitmix32(input: u32) -> f64 {
+    let mut z = input.wrapping_add(0x9E37_79B9);
+    z = (z ^ (z >> 16)).wrapping_mul(0x21F0_AAAD);
+    z = (z ^ (z >> 15)).wrapping_mul(0x735A_2D97);
+    z ^= z >> 15;
+    z as f64 / 4294967296.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +212,34 @@ mod tests {
         assert_eq!(rng.counter, 9);
     }
 
+    #[test]
+    fn same_seed_and_algorithm_give_same_index_and_shuffle_sequences() {
+        for algorithm in [RngAlgorithm::Mulberry32, RngAlgorithm::SplitMix32] {
+            let mut rng1 = RngState::with_algorithm(7, algorithm);
+            let mut rng2 = RngState::with_algorithm(7, algorithm);
+            for len in 1..50 {
+                assert_eq!(rng1.random_index(len), rng2.random_index(len), "{algorithm:?}");
+            }
+            let mut a: Vec<u32> = (0..20).collect();
+            let mut b = a.clone();
+            rng1.shuffle(&mut a);
+            rng2.shuffle(&mut b);
+            assert_eq!(a, b, "{algorithm:?}");
+            assert_eq!(rng1, rng2);
+        }
+
+        let mut mulberry = RngState::new(7);
+        let mut splitmix = RngState::with_algorithm(7, RngAlgorithm::SplitMix32);
+        let draws = |rng: &mut RngState| (0..10).map(|_| rng.next_f64().to_bits()).collect::<Vec<_>>();
+        assert_ne!(draws(&mut mulberry), draws(&mut splitmix));
+    }
+
+    #[test]
+    fn state_without_algorithm_deserializes_as_mulberry32() {
+        let rng: RngState = serde_json::from_str(r#"{"seed":42,"counter":3}"#).unwrap();
+        assert_eq!(rng.algorithm, RngAlgorithm::Mulberry32);
+    }
+
     #[test]
     fn shuffle_empty_and_single() {
         let mut rng = RngState::new(42);
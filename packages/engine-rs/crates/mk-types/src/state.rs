@@ -400,6 +400,59 @@ pub struct CombatEnemy {
     pub attacks_cancelled: Vec<bool>,
 }
 
+/// Who absorbed an enemy attack's damage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DamageRecipient {
+    Hero {
+        wounds: u32,
+    },
+    Unit {
+        unit_instance_id: UnitInstanceId,
+        wounded: bool,
+        destroyed: bool,
+    },
+}
+
+/// Append-only combat log entry — narrates combat for client animation.
+///
+/// Output-only: the engine never reads the log back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum CombatLogEntry {
+    /// A block was declared against one enemy attack.
+    BlockDeclared {
+        enemy_instance_id: CombatInstanceId,
+        attack_index: u32,
+        success: bool,
+    },
+    /// An attack was resolved against the declared targets.
+    AttackDeclared {
+        target_instance_ids: Vec<CombatInstanceId>,
+        attack_type: CombatType,
+        success: bool,
+    },
+    /// An enemy was defeated. `fame` is 0 for summoned enemies.
+    EnemyDefeated {
+        enemy_instance_id: CombatInstanceId,
+        enemy_id: EnemyId,
+        fame: u32,
+    },
+    /// A summoner drew a new enemy at the start of the Block phase.
+    EnemySummoned {
+        summoner_instance_id: CombatInstanceId,
+        enemy_instance_id: CombatInstanceId,
+        enemy_id: EnemyId,
+    },
+    /// An unblocked enemy attack was assigned to the hero or a unit.
+    DamageAssigned {
+        enemy_instance_id: CombatInstanceId,
+        attack_index: u32,
+        damage: u32,
+        recipient: DamageRecipient,
+    },
+}
+
 /// Full combat state.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CombatState {
@@ -409,6 +462,8 @@ pub struct CombatState {
     pub wounds_added_to_hand_this_combat: bool,
     pub attacks_this_phase: u32,
     pub fame_gained: u32,
+    /// Fame credited per defeated enemy instance, including per-enemy bonuses.
+    pub fame_by_enemy: BTreeMap<String, u32>,
     pub is_at_fortified_site: bool,
     pub units_allowed: bool,
     pub night_mana_rules: bool,
@@ -446,11 +501,14 @@ pub struct CombatState {
     // Damage assignment tracking
     pub has_paralyze_damage_to_hero: bool,
 
-    // Phase tracking for BowPhaseFameTracking
-    pub ranged_siege_defeats: u32,
+    // Phase tracking for BowPhaseFameTracking: enemies defeated by ranged/siege attacks
+    pub ranged_siege_defeated: Vec<CombatInstanceId>,
 
     // City defender bonuses (based on city color)
     pub city_color: Option<BasicManaColor>,
+
+    // Narration of blocks, attacks, defeats, summons and damage (append-only)
+    pub combat_log: Vec<CombatLogEntry>,
 }
 
 impl Default for CombatState {
@@ -462,6 +520,7 @@ impl Default for CombatState {
             wounds_added_to_hand_this_combat: false,
             attacks_this_phase: 0,
             fame_gained: 0,
+            fame_by_enemy: BTreeMap::new(),
             is_at_fortified_site: false,
             units_allowed: true,
             night_mana_rules: false,
@@ -487,8 +546,9 @@ impl Default for CombatState {
             declared_block_target: None,
             declared_block_attack_index: None,
             has_paralyze_damage_to_hero: false,
-            ranged_siege_defeats: 0,
+            ranged_siege_defeated: Vec::new(),
             city_color: None,
+            combat_log: Vec::new(),
         }
     }
 }
@@ -748,6 +808,9 @@ pub struct ScenarioConfig {
     pub extra_source_dice: u32,
     /// Extra slots added to the Unit offer beyond the standard player_count + 2 (Blitz scenarios).
     pub extra_unit_offer_slots: u32,
+    /// Active rule variants (validated at setup).
+    #[serde(default)]
+    pub variants: Vec<Variant>,
     // Tactic handling
     pub tactic_removal_mode: TacticRemovalMode,
     pub dummy_tactic_order: DummyTacticOrder,
@@ -759,6 +822,13 @@ pub struct ScenarioConfig {
     pub scoring_config: Option<crate::scoring::ScenarioScoringConfig>,
 }
 
+impl ScenarioConfig {
+    /// Whether `variant` is active for this game.
+    pub fn has_variant(&self, variant: Variant) -> bool {
+        self.variants.contains(&variant)
+    }
+}
+
 // =============================================================================
 // GameState — the root
 // =============================================================================
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use mk_data::cards::get_card;
 use mk_data::enemies::get_enemy;
+use mk_data::heroes::{all_heroes, hero_by_id, hero_info};
 use mk_data::units::get_unit;
 use mk_engine::action_pipeline::{apply_legal_action, initial_events};
 use mk_engine::client_state::to_client_state;
@@ -19,16 +20,6 @@ use mk_types::legal_action::{LegalAction, TacticDecisionData};
 use mk_types::pending::{ActivePending, ChoiceResolution, PendingTacticDecision, SubsetSelectionKind};
 use mk_types::state::*;
 
-const HEROES: [(&str, Hero); 7] = [
-    ("Arythea", Hero::Arythea),
-    ("Tovak", Hero::Tovak),
-    ("Goldyx", Hero::Goldyx),
-    ("Norowas", Hero::Norowas),
-    ("Wolfhawk", Hero::Wolfhawk),
-    ("Krang", Hero::Krang),
-    ("Braevalar", Hero::Braevalar),
-];
-
 // =============================================================================
 // CLI args
 // =============================================================================
@@ -58,12 +49,8 @@ fn parse_args() -> CliArgs {
             "--hero" | "-h" => {
                 i += 1;
                 if i < args.len() {
-                    let name = args[i].to_lowercase();
-                    for &(hname, h) in &HEROES {
-                        if hname.to_lowercase() == name {
-                            hero = Some((h, hname));
-                            break;
-                        }
+                    if let Some(h) = hero_by_id(&args[i]) {
+                        hero = Some((h, hero_info(h).name));
                     }
                 }
             }
@@ -127,28 +114,10 @@ struct ReplayFile {
 }
 
 fn parse_replay_hero(name: &str) -> Hero {
-    match name.to_lowercase().as_str() {
-        "arythea" => Hero::Arythea,
-        "tovak" => Hero::Tovak,
-        "goldyx" => Hero::Goldyx,
-        "norowas" => Hero::Norowas,
-        "wolfhawk" => Hero::Wolfhawk,
-        "krang" => Hero::Krang,
-        "braevalar" => Hero::Braevalar,
-        _ => {
-            eprintln!("  Unknown hero '{}', defaulting to Arythea", name);
-            Hero::Arythea
-        }
-    }
-}
-
-fn hero_display_name(hero: Hero) -> &'static str {
-    for &(name, h) in &HEROES {
-        if h == hero {
-            return name;
-        }
-    }
-    "Unknown"
+    hero_by_id(name).unwrap_or_else(|| {
+        eprintln!("  Unknown hero '{}', defaulting to Arythea", name);
+        Hero::Arythea
+    })
 }
 
 fn run_replay(replay_path: PathBuf, step_mode: bool, from_step: Option<usize>) {
@@ -169,7 +138,7 @@ fn run_replay(replay_path: PathBuf, step_mode: bool, from_step: Option<usize>) {
     };
 
     let hero = parse_replay_hero(&replay.hero);
-    let hero_name = hero_display_name(hero);
+    let hero_name = hero_info(hero).name;
 
     println!(
         "  Replaying: seed={} hero={} actions={}",
@@ -335,7 +304,7 @@ fn run_replay_to_artifact(replay_path: PathBuf, artifact_path: PathBuf) {
     };
 
     let hero = parse_replay_hero(&replay.hero);
-    let hero_name = hero_display_name(hero);
+    let hero_name = hero_info(hero).name;
     let player_id_str = "player_0";
 
     println!(
@@ -478,14 +447,14 @@ fn main() {
     let is_tty = is_terminal();
 
     let (hero, hero_name, seed) = if is_tty {
-        let hero_names: Vec<&str> = HEROES.iter().map(|(n, _)| *n).collect();
+        let hero_names: Vec<&str> = all_heroes().iter().map(|h| h.name).collect();
         let hero_idx = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Choose your hero")
             .items(&hero_names)
             .default(0)
             .interact()
             .unwrap();
-        let (hname, h) = HEROES[hero_idx];
+        let (hname, h) = (all_heroes()[hero_idx].name, all_heroes()[hero_idx].hero);
 
         print!("Enter seed (or Enter for 42): ");
         io::stdout().flush().unwrap();
@@ -1437,14 +1406,6 @@ trait HeroName {
 
 impl HeroName for PlayerState {
     fn hero_name(&self) -> &'static str {
-        match self.hero {
-            Hero::Arythea => "Arythea",
-            Hero::Tovak => "Tovak",
-            Hero::Goldyx => "Goldyx",
-            Hero::Norowas => "Norowas",
-            Hero::Wolfhawk => "Wolfhawk",
-            Hero::Krang => "Krang",
-            Hero::Braevalar => "Braevalar",
-        }
+        hero_info(self.hero).name
     }
 }
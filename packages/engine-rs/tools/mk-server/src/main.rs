@@ -12,6 +12,7 @@
 //!
 //! Server → Client messages:
 //!   { "type": "state_update", "state": <ClientGameState>, "events": [...], "legal_actions": [...], "epoch": 5 }
+//!     (plus "empty_reason": "not_your_turn" etc. when legal_actions is empty because of a guard)
 //!   { "type": "error", "message": "..." }
 
 use axum::{
@@ -36,7 +37,7 @@ use mk_types::client_state::ClientGameState;
 use mk_types::enums::{Hero, RoundPhase};
 use mk_types::events::GameEvent;
 use mk_types::ids::PlayerId;
-use mk_types::legal_action::LegalAction;
+use mk_types::legal_action::{EmptyReason, LegalAction};
 use mk_types::state::GameState;
 
 // =============================================================================
@@ -142,6 +143,8 @@ enum ServerMessage {
         events: Vec<GameEvent>,
         legal_actions: Vec<LegalAction>,
         epoch: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        empty_reason: Option<EmptyReason>,
     },
     Error {
         message: String,
@@ -258,6 +261,7 @@ impl GameSession {
         ServerMessage::StateUpdate {
             epoch: action_set.epoch,
             legal_actions: action_set.actions,
+            empty_reason: action_set.empty_reason,
             state: Box::new(client_state),
             events,
         }
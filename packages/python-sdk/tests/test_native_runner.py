@@ -48,6 +48,29 @@ class TestGameEngine(unittest.TestCase):
             self.assertFalse(engine.is_game_ended())
             self.assertGreater(engine.legal_action_count(), 0)
 
+    def test_available_heroes(self) -> None:
+        from mk_python import GameEngine, available_heroes
+        from mage_knight_sdk.sim.hero_selection import ALL_HEROES
+        heroes = available_heroes()
+        self.assertEqual(len(heroes), 7)
+        self.assertEqual(len({h["name"] for h in heroes}), 7)
+        self.assertEqual(sorted(h["id"] for h in heroes), sorted(ALL_HEROES))
+        for hero in heroes:
+            self.assertEqual(len(hero["starting_deck"]), 16)
+            for card in hero["unique_cards"]:
+                self.assertIn(card, hero["starting_deck"])
+            GameEngine(seed=1, hero=hero["id"])
+
+    def test_branching_histogram(self) -> None:
+        from mk_python import GameEngine
+        engine = GameEngine(seed=42, hero="arythea")
+        stats = engine.branching_histogram(action_seed=7, max_steps=300)
+        self.assertGreater(stats["steps"], 0)
+        self.assertEqual(sum(stats["histogram"].values()), stats["steps"])
+        self.assertGreaterEqual(stats["mean"], 1.0)
+        self.assertGreaterEqual(stats["max"], stats["mean"])
+        self.assertEqual(engine.step_count(), 0)
+
     def test_invalid_hero_raises(self) -> None:
         from mk_python import GameEngine
         with self.assertRaises(ValueError):
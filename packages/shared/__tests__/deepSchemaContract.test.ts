@@ -139,6 +139,7 @@ const minimalClientPlayer = {
   commandTokens: 1,
   hand: ["march", "rage", "stamina", "determination", "swiftness"],
   deckCount: 11,
+  discard: [],
   discardCount: 0,
   playArea: [],
   units: [],
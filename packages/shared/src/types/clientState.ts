@@ -176,6 +176,7 @@ export interface ClientPlayer {
   // Cards - your own hand = full info, other players = just count
   readonly hand: readonly CardId[] | number;
   readonly deckCount: number; // never reveal deck contents
+  readonly discard: readonly CardId[]; // your own discard; empty for other players
   readonly discardCount: number;
   readonly playArea: readonly CardId[];
 